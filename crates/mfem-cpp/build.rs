@@ -6,6 +6,8 @@ fn main() {
         .define("BUILD_LIBRARY_TYPE", "Static")
         .define("INSTALL_DIR_LIB", LIB_DIR)
         .define("INSTALL_DIR_INCLUDE", INCLUDE_DIR)
+        // Lets `mfem-sys` turn MFEM errors into exceptions instead of aborts
+        .define("MFEM_USE_EXCEPTIONS", "YES")
         .build();
 
     println!("cargo:rustc-env=MFEM_PATH={}", dst.display());
@@ -56,7 +56,7 @@ fn main() {
     //    quadrilateral, tetrahedral, hexahedral, surface and volume meshes with
     //    the same code.
     let_cxx_string!(mesh_file = args.mesh_file);
-    let mut mesh = Mesh_ctor_file(&mesh_file, 1, 1, true);
+    let mut mesh = Mesh_ctor_file(&mesh_file, 1, 1, true).expect("Mesh file loaded");
     let dim = mesh.Dimension();
 
     dbg!(mesh.GetNE());
@@ -69,7 +69,7 @@ fn main() {
         let ref_levels = f64::floor(f64::log2(50000.0 / mesh.GetNE() as f64) / dim as f64) as u32;
 
         for _ in 0..ref_levels {
-            mesh.pin_mut().UniformRefinement(0);
+            mesh.pin_mut().UniformRefinement(0).expect("Mesh refined");
         }
     }
 
@@ -79,13 +79,12 @@ fn main() {
     //    Lagrange finite elements of the specified order. If order < 1, we
     //    instead use an isoparametric/isogeometric space.
     let owned_fec: Option<UniquePtr<H1_FECollection>> = if args.order > 0 {
-        Some(H1_FECollection_ctor(
-            args.order,
-            dim,
-            BasisType::GaussLobatto.repr,
-        ))
+        Some(
+            H1_FECollection_ctor(args.order, dim, BasisType::GaussLobatto.repr)
+                .expect("Valid order"),
+        )
     } else if Mesh_GetNodes(&mesh).is_err() {
-        Some(H1_FECollection_ctor(1, dim, BasisType::GaussLobatto.repr))
+        Some(H1_FECollection_ctor(1, dim, BasisType::GaussLobatto.repr).expect("Valid order"))
     } else {
         None
     };
@@ -107,7 +106,8 @@ fn main() {
         dbg!(fec_name);
     }
 
-    let fespace = FiniteElementSpace_ctor(&mesh, fec, 1, OrderingType::byNODES);
+    let fespace = FiniteElementSpace_ctor(&mesh, fec, 1, OrderingType::byNODES)
+        .expect("FiniteElementSpace created");
     println!(
         "Number of finite element unknowns: {}",
         fespace.GetTrueVSize(),
@@ -119,41 +119,43 @@ fn main() {
     //    converting them to a list of true dofs.
     let mut ess_tdof_list = ArrayInt_ctor();
     if Mesh_bdr_attributes(&mesh).Size() > 0 {
-        let mut ess_bdr = ArrayInt_ctor_size(Mesh_bdr_attributes(&mesh).Max());
+        let mut ess_bdr =
+            ArrayInt_ctor_size(Mesh_bdr_attributes(&mesh).Max()).expect("ArrayInt created");
         ArrayInt_SetAll(ess_bdr.pin_mut(), 1);
-        FiniteElementSpace_GetEssentialTrueDofs(&fespace, &ess_bdr, ess_tdof_list.pin_mut(), -1);
+        FiniteElementSpace_GetEssentialTrueDofs(&fespace, &ess_bdr, ess_tdof_list.pin_mut(), -1)
+            .expect("Essential true dofs found");
     }
 
     // 7. Set up the linear form b(.) which corresponds to the right-hand side of
     //    the FEM linear system, which in this case is (1,phi_i) where phi_i are
     //    the basis functions in the finite element fespace.
-    let mut b = LinearForm_ctor_fes(&fespace);
+    let mut b = LinearForm_ctor_fes(&fespace).expect("LinearForm created");
     let one = ConstantCoefficient_ctor(1.0);
     let one_coeff = ConstantCoefficient_as_Coeff(&one);
     let integrator = DomainLFIntegrator_ctor_ab(one_coeff, 2, 0);
     let lfi = DomainLFIntegrator_into_LFI(integrator);
-    LinearForm_AddDomainIntegrator(b.pin_mut(), lfi);
-    b.pin_mut().Assemble();
+    LinearForm_AddDomainIntegrator(b.pin_mut(), lfi).expect("Domain integrator added");
+    b.pin_mut().Assemble().expect("LinearForm assembled");
 
     // 8. Define the solution vector x as a finite element grid function
     //    corresponding to fespace. Initialize x with initial guess of zero,
     //    which satisfies the boundary conditions.
-    let mut x = GridFunction_ctor_fes(&fespace);
+    let mut x = GridFunction_ctor_fes(&fespace).expect("GridFunction created");
     GridFunction_SetAll(x.pin_mut(), 0.0);
 
     // 9. Set up the bilinear form a(.,.) on the finite element space
     //    corresponding to the Laplacian operator -Delta, by adding the Diffusion
     //    domain integrator.
-    let mut a = BilinearForm_ctor_fes(&fespace);
+    let mut a = BilinearForm_ctor_fes(&fespace).expect("BilinearForm created");
     let bf_integrator = DiffusionIntegrator_ctor(one_coeff);
     let bfi = DiffusionIntegrator_into_BFI(bf_integrator);
-    BilinearForm_AddDomainIntegrator(a.pin_mut(), bfi);
+    BilinearForm_AddDomainIntegrator(a.pin_mut(), bfi).expect("Domain integrator added");
 
     // 10. Assemble the bilinear form and the corresponding linear system,
     //     applying any necessary transformations such as: eliminating boundary
     //     conditions, applying conforming constraints for non-conforming AMR,
     //     static condensation, etc.
-    a.pin_mut().Assemble(0);
+    a.pin_mut().Assemble(0).expect("BilinearForm assembled");
 
     let mut a_mat = OperatorHandle_ctor();
     let mut b_vec = Vector_ctor();
@@ -166,7 +168,8 @@ fn main() {
         a_mat.pin_mut(),
         x_vec.pin_mut(),
         b_vec.pin_mut(),
-    );
+    )
+    .expect("Linear system formed");
 
     println!(
         "Size of linear system: {}",
@@ -178,7 +181,7 @@ fn main() {
     // 11. Solve the linear system A X = B.
     // Use a simple symmetric Gauss-Seidel preconditioner with PCG.
     let a_sparse = OperatorHandle_try_as_SparseMatrix(&a_mat).expect("Operator is a SparseMatrix");
-    let mut m_mat = GSSmoother_ctor(a_sparse, 0, 1);
+    let mut m_mat = GSSmoother_ctor(a_sparse, 0, 1).expect("GSSmoother created");
     let solver = GSSmoother_as_mut_Solver(m_mat.pin_mut());
     PCG(
         OperatorHandle_as_ref(&a_mat),
@@ -189,19 +192,22 @@ fn main() {
         200,
         1e-12,
         0.0,
-    );
+    )
+    .expect("PCG finished");
 
     // 12. Recover the solution as a finite element grid function.
-    a.pin_mut().RecoverFEMSolution(
-        &x_vec,
-        LinearForm_as_Vector(&b),
-        GridFunction_as_mut_Vector(x.pin_mut()),
-    );
+    a.pin_mut()
+        .RecoverFEMSolution(
+            &x_vec,
+            LinearForm_as_Vector(&b),
+            GridFunction_as_mut_Vector(x.pin_mut()),
+        )
+        .expect("FEM solution recovered");
 
     // 13. Save the refined mesh and the solution. This output can be viewed later
     //     using GLVis: "glvis -m refined.mesh -g sol.gf".
     let_cxx_string!(mesh_filename = "refined.mesh");
-    mesh.Save(&mesh_filename, 8);
+    mesh.Save(&mesh_filename, 8).expect("Mesh saved");
     let_cxx_string!(sol_filename = "sol.gf");
-    GridFunction_Save(&x, &sol_filename, 8);
+    GridFunction_Save(&x, &sol_filename, 8).expect("GridFunction saved");
 }
@@ -9,18 +9,47 @@ class mfem_exception : public std::exception {
 
 public:
     explicit mfem_exception(char const* msg) : msg_(msg) {}
+    explicit mfem_exception(std::string msg) : msg_(std::move(msg)) {}
     auto what() const noexcept -> char const* override {
         return msg_.c_str();
     }
 };
 
+// The following exceptions are tagged by trycatch() below,
+// so that the `mfem` crate can turn them into the matching `MfemError` variant.
+
+class mfem_io_error : public mfem_exception {
+    using mfem_exception::mfem_exception;
+};
+
+class mfem_invalid_argument : public mfem_exception {
+    using mfem_exception::mfem_exception;
+};
+
+class mfem_size_mismatch : public mfem_exception {
+    using mfem_exception::mfem_exception;
+};
+
+class mfem_cancelled : public mfem_exception {
+    using mfem_exception::mfem_exception;
+};
+
 namespace rust {
 namespace behavior {
 
 template <typename Try, typename Fail>
 static void trycatch(Try &&func, Fail &&fail) noexcept try {
   func();
+} catch (const mfem_io_error &e) {
+  fail((std::string("IoError: ") + e.what()).c_str());
+} catch (const mfem_invalid_argument &e) {
+  fail((std::string("InvalidArgument: ") + e.what()).c_str());
+} catch (const mfem_size_mismatch &e) {
+  fail((std::string("SizeMismatch: ") + e.what()).c_str());
+} catch (const mfem_cancelled &e) {
+  fail((std::string("Cancelled: ") + e.what()).c_str());
 } catch (const std::exception &e) {
+  // This includes mfem::ErrorException, see set_error_action() below
   fail(e.what());
 }
 
@@ -29,14 +58,154 @@ static void trycatch(Try &&func, Fail &&fail) noexcept try {
 
 #include "mfem.hpp"
 
+// Make MFEM throw mfem::ErrorException instead of aborting, so that errors end up in Rust as `Err`.
+// This needs MFEM to be built with MFEM_USE_EXCEPTIONS=YES, otherwise MFEM keeps aborting.
+#ifdef MFEM_USE_EXCEPTIONS
+static const bool mfem_error_action_is_throw = (mfem::set_error_action(mfem::MFEM_ERROR_THROW), true);
+#endif
+
 // Generic template constructor
 template <typename T, typename... Args>
 auto construct_unique(Args... args) -> std::unique_ptr<T> {
     return std::make_unique<T>(args...);
 }
 
+// MFEM takes many arguments by non-const pointer or reference, even where it only reads them.
+// The Rust side borrows these for as long as MFEM keeps them, so passing shared references
+// on by casting away const is sound as long as MFEM doesn't modify them.
+template <typename T>
+auto as_mut(T const& x) -> T& {
+    return const_cast<T&>(x);
+}
+
 using namespace mfem;
 
+///////////////////////
+// Rust I/O Adapters //
+///////////////////////
+
+// Defined on the Rust side, see `RustRead` and `RustWrite` in `src/lib.rs`.
+struct RustRead;
+struct RustWrite;
+auto RustRead_read(RustRead& reader, rust::Slice<std::uint8_t> buf) noexcept -> std::size_t;
+auto RustWrite_write_all(RustWrite& writer, rust::Slice<const std::uint8_t> buf) noexcept -> bool;
+auto RustWrite_flush(RustWrite& writer) noexcept -> bool;
+
+// Defined on the Rust side, see `RustFunction` in `src/lib.rs`.
+// Throws rust::Error if the Rust function panicked.
+struct RustFunction;
+auto RustFunction_eval(RustFunction const& func, rust::Slice<const double> x, double t) -> double;
+
+// Defined on the Rust side, see `RustVectorFunction` and `RustMatrixFunction` in `src/lib.rs`.
+// Throw rust::Error if the Rust function panicked.
+struct RustVectorFunction;
+struct RustMatrixFunction;
+auto RustVectorFunction_eval(RustVectorFunction const& func, rust::Slice<const double> x, double t, rust::Slice<double> v) -> void;
+auto RustMatrixFunction_eval(RustMatrixFunction const& func, rust::Slice<const double> x, double t, rust::Slice<double> k) -> void;
+
+// Defined on the Rust side, see `RustTransform` in `src/lib.rs`.
+// Throws rust::Error if the Rust function panicked.
+struct RustTransform;
+auto RustTransform_eval(RustTransform const& func, double v1, double v2) -> double;
+
+// Defined on the Rust side, see `RustBilinearIntegrator` in `src/lib.rs`.
+// The Assemble functions throw rust::Error if the Rust code panicked.
+struct RustBilinearIntegrator;
+auto RustBilinearIntegrator_AssembleElementMatrix(RustBilinearIntegrator const& integ, FiniteElement const& el, ElementTransformation& trans, DenseMatrix& elmat) -> void;
+auto RustBilinearIntegrator_AssembleFaceMatrix(RustBilinearIntegrator const& integ, FiniteElement const& el1, FiniteElement const& el2, FaceElementTransformations& trans, DenseMatrix& elmat) -> void;
+auto RustBilinearIntegrator_drop(RustBilinearIntegrator* integ) noexcept -> void;
+
+// Defined on the Rust side, see `RustLinearIntegrator` in `src/lib.rs`.
+// Throws rust::Error if the Rust code panicked.
+struct RustLinearIntegrator;
+auto RustLinearIntegrator_AssembleRHSElementVect(RustLinearIntegrator const& integ, FiniteElement const& el, ElementTransformation& trans, rust::Slice<double> elvect) -> void;
+auto RustLinearIntegrator_drop(RustLinearIntegrator* integ) noexcept -> void;
+
+// Defined on the Rust side, see `RustSolverMonitor` in `src/lib.rs`.
+// Throws rust::Error if the Rust code panicked.
+struct RustSolverMonitor;
+auto RustSolverMonitor_MonitorResidual(RustSolverMonitor& monitor, int it, double norm, Vector const& r, bool is_final) -> bool;
+auto RustSolverMonitor_drop(RustSolverMonitor* monitor) noexcept -> void;
+
+// Defined on the Rust side, see `RustMult` in `src/lib.rs`.
+// Throw rust::Error if the Rust code panicked.
+struct RustMult;
+auto RustMult_Mult(RustMult const& mult, rust::Slice<const double> x, rust::Slice<double> y) -> void;
+auto RustMult_MultTranspose(RustMult const& mult, rust::Slice<const double> x, rust::Slice<double> y) -> void;
+auto RustMult_drop(RustMult* mult) noexcept -> void;
+
+// Defined on the Rust side, see `RustSolve` in `src/lib.rs`.
+// Throw rust::Error if the Rust code panicked.
+struct RustSolve;
+auto RustSolve_SetOperator(RustSolve const& solve, Operator const& op) -> void;
+auto RustSolve_Mult(RustSolve const& solve, rust::Slice<const double> x, rust::Slice<double> y) -> void;
+auto RustSolve_drop(RustSolve* solve) noexcept -> void;
+
+// Input stream buffer that pulls bytes from a Rust `std::io::Read`
+class RustReadStreambuf : public std::streambuf {
+    RustRead& reader_;
+    char buffer_[4096];
+
+public:
+    explicit RustReadStreambuf(RustRead& reader) : reader_(reader) {
+        setg(buffer_, buffer_, buffer_);
+    }
+
+protected:
+    auto underflow() -> int_type override {
+        if (gptr() < egptr()) {
+            return traits_type::to_int_type(*gptr());
+        }
+        auto buf = rust::Slice<std::uint8_t>(reinterpret_cast<std::uint8_t*>(buffer_), sizeof(buffer_));
+        auto n = RustRead_read(reader_, buf);
+        if (n == 0) {
+            return traits_type::eof();
+        }
+        setg(buffer_, buffer_, buffer_ + n);
+        return traits_type::to_int_type(*gptr());
+    }
+};
+
+// Output stream buffer that pushes bytes to a Rust `std::io::Write`
+class RustWriteStreambuf : public std::streambuf {
+    RustWrite& writer_;
+    char buffer_[4096];
+
+    auto flush_buffer() -> bool {
+        auto n = pptr() - pbase();
+        if (n > 0) {
+            auto buf = rust::Slice<const std::uint8_t>(reinterpret_cast<std::uint8_t const*>(pbase()), n);
+            if (!RustWrite_write_all(writer_, buf)) {
+                return false;
+            }
+            pbump(static_cast<int>(-n));
+        }
+        return true;
+    }
+
+public:
+    explicit RustWriteStreambuf(RustWrite& writer) : writer_(writer) {
+        // Leave room for the character passed to overflow()
+        setp(buffer_, buffer_ + sizeof(buffer_) - 1);
+    }
+
+protected:
+    auto overflow(int_type ch) -> int_type override {
+        if (!traits_type::eq_int_type(ch, traits_type::eof())) {
+            *pptr() = traits_type::to_char_type(ch);
+            pbump(1);
+        }
+        if (!flush_buffer()) {
+            return traits_type::eof();
+        }
+        return traits_type::not_eof(ch);
+    }
+
+    auto sync() -> int override {
+        return flush_buffer() && RustWrite_flush(writer_) ? 0 : -1;
+    }
+};
+
 //////////////
 // ArrayInt //
 //////////////
@@ -47,6 +216,106 @@ auto ArrayInt_SetAll(ArrayInt& array, int value) -> void {
     array = value;
 }
 
+auto ArrayInt_ctor_slice(rust::Slice<const int> values) -> std::unique_ptr<ArrayInt> {
+    auto array = std::make_unique<ArrayInt>(static_cast<int>(values.size()));
+    for (std::size_t i = 0; i < values.size(); ++i) {
+        (*array)[i] = values[i];
+    }
+    return array;
+}
+
+////////////
+// Vector //
+////////////
+
+auto Vector_check_sizes(Vector const& x, Vector const& y, char const* what) -> void {
+    if (x.Size() != y.Size()) {
+        throw mfem_size_mismatch(
+            std::string(what) + ": " + std::to_string(x.Size()) + " != " + std::to_string(y.Size())
+        );
+    }
+}
+
+auto Vector_SetAll(Vector& vector, double value) -> void {
+    vector = value;
+}
+
+auto Vector_Dot(Vector const& x, Vector const& y) -> double {
+    Vector_check_sizes(x, y, "Vector_Dot");
+    return x * y;
+}
+
+auto Vector_AddAssign(Vector& x, Vector const& y) -> void {
+    Vector_check_sizes(x, y, "Vector_AddAssign");
+    x += y;
+}
+
+auto Vector_SubAssign(Vector& x, Vector const& y) -> void {
+    Vector_check_sizes(x, y, "Vector_SubAssign");
+    x -= y;
+}
+
+auto Vector_MulAssign(Vector& x, double c) -> void {
+    x *= c;
+}
+
+/////////////////
+// BlockVector //
+/////////////////
+
+// BlockVector keeps a pointer to the offsets, so they need to outlive it.
+auto BlockVector_ctor_offsets(ArrayInt const& offsets) -> std::unique_ptr<BlockVector> {
+    return std::make_unique<BlockVector>(offsets);
+}
+
+auto BlockVector_GetBlock(BlockVector const& bv, int i) -> Vector const& {
+    return bv.GetBlock(i);
+}
+
+auto BlockVector_GetBlock_mut(BlockVector& bv, int i) -> Vector& {
+    return bv.GetBlock(i);
+}
+
+auto BlockVector_as_Vector(BlockVector const& bv) -> Vector const& {
+    return bv;
+}
+
+auto BlockVector_as_mut_Vector(BlockVector& bv) -> Vector& {
+    return bv;
+}
+
+/////////////////
+// DenseMatrix //
+/////////////////
+
+auto DenseMatrix_SetSize(DenseMatrix& mat, int h, int w) -> void {
+    mat.SetSize(h, w);
+}
+
+auto DenseMatrix_SetAll(DenseMatrix& mat, double value) -> void {
+    mat = value;
+}
+
+auto DenseMatrix_AddMult_a_AAt(double a, DenseMatrix const& mat, DenseMatrix& aat) -> void {
+    if (aat.Height() != mat.Height() || aat.Width() != mat.Height()) {
+        throw mfem_size_mismatch(
+            "DenseMatrix_AddMult_a_AAt: " + std::to_string(aat.Height()) + "x" + std::to_string(aat.Width())
+            + " != " + std::to_string(mat.Height()) + "x" + std::to_string(mat.Height())
+        );
+    }
+    AddMult_a_AAt(a, mat, aat);
+}
+
+auto DenseMatrix_AddMult_a_VVt(double a, Vector const& v, DenseMatrix& vvt) -> void {
+    if (vvt.Height() != v.Size() || vvt.Width() != v.Size()) {
+        throw mfem_size_mismatch(
+            "DenseMatrix_AddMult_a_VVt: " + std::to_string(vvt.Height()) + "x" + std::to_string(vvt.Width())
+            + " != " + std::to_string(v.Size()) + "x" + std::to_string(v.Size())
+        );
+    }
+    AddMult_a_VVt(a, v, vvt);
+}
+
 /////////////////////
 // H1_FECollection //
 /////////////////////
@@ -55,10 +324,285 @@ auto H1_FECollection_as_FEC(H1_FECollection const& h1_fec) -> FiniteElementColle
     return h1_fec;
 }
 
+/////////////////////
+// L2_FECollection //
+/////////////////////
+
+auto L2_FECollection_as_FEC(L2_FECollection const& l2_fec) -> FiniteElementCollection const& {
+    return l2_fec;
+}
+
+/////////////////////
+// ND_FECollection //
+/////////////////////
+
+auto ND_FECollection_as_FEC(ND_FECollection const& nd_fec) -> FiniteElementCollection const& {
+    return nd_fec;
+}
+
+/////////////////////
+// RT_FECollection //
+/////////////////////
+
+auto RT_FECollection_as_FEC(RT_FECollection const& rt_fec) -> FiniteElementCollection const& {
+    return rt_fec;
+}
+
+///////////////////
+// FiniteElement //
+///////////////////
+
+auto FiniteElement_GetGeomType(FiniteElement const& fe) -> int {
+    return fe.GetGeomType();
+}
+
+// The output sizes are set here, MFEM expects them to be set by the caller.
+
+auto FiniteElement_CalcShape(FiniteElement const& fe, IntegrationPoint const& ip, Vector& shape) -> void {
+    shape.SetSize(fe.GetDof());
+    fe.CalcShape(ip, shape);
+}
+
+auto FiniteElement_CalcDShape(FiniteElement const& fe, IntegrationPoint const& ip, DenseMatrix& dshape) -> void {
+    dshape.SetSize(fe.GetDof(), fe.GetDim());
+    fe.CalcDShape(ip, dshape);
+}
+
+auto FiniteElement_CalcPhysShape(FiniteElement const& fe, ElementTransformation& trans, Vector& shape) -> void {
+    shape.SetSize(fe.GetDof());
+    fe.CalcPhysShape(trans, shape);
+}
+
+auto FiniteElement_CalcPhysDShape(FiniteElement const& fe, ElementTransformation& trans, DenseMatrix& dshape) -> void {
+    dshape.SetSize(fe.GetDof(), trans.GetSpaceDim());
+    fe.CalcPhysDShape(trans, dshape);
+}
+
+//////////////////////
+// IntegrationPoint //
+//////////////////////
+
+auto IntegrationPoint_weight(IntegrationPoint const& ip) -> double {
+    return ip.weight;
+}
+
+auto IntegrationPoint_Get(IntegrationPoint const& ip, rust::Slice<double> p) -> void {
+    ip.Get(p.data(), static_cast<int>(std::min<std::size_t>(p.size(), 3)));
+}
+
+/////////////////////
+// IntegrationRule //
+/////////////////////
+
+auto IntegrationRule_IntPoint(IntegrationRule const& ir, int i) -> IntegrationPoint const& {
+    return ir.IntPoint(i);
+}
+
+auto IntRules_Get(int geom, int order) -> IntegrationRule const& {
+    if (order < 0) {
+        throw mfem_invalid_argument("IntRules_Get: negative order " + std::to_string(order));
+    }
+    return IntRules.Get(geom, order);
+}
+
+///////////////////////////
+// ElementTransformation //
+///////////////////////////
+
+auto ElementTransformation_ElementNo(ElementTransformation const& trans) -> int {
+    return trans.ElementNo;
+}
+
+auto ElementTransformation_Attribute(ElementTransformation const& trans) -> int {
+    return trans.Attribute;
+}
+
+auto ElementTransformation_GetGeometryType(ElementTransformation const& trans) -> int {
+    return trans.GetGeometryType();
+}
+
+// NOTE: `trans` keeps a pointer to `ip`.
+auto ElementTransformation_SetIntPoint(ElementTransformation& trans, IntegrationPoint const& ip) -> void {
+    trans.SetIntPoint(&ip);
+}
+
+auto ElementTransformation_Weight(ElementTransformation& trans) -> double {
+    return trans.Weight();
+}
+
+auto ElementTransformation_Transform(ElementTransformation& trans, IntegrationPoint const& ip, Vector& x) -> void {
+    x.SetSize(trans.GetSpaceDim());
+    trans.Transform(ip, x);
+}
+
+////////////////////////////////
+// FaceElementTransformations //
+////////////////////////////////
+
+auto FaceElementTransformations_Elem1No(FaceElementTransformations const& trans) -> int {
+    return trans.Elem1No;
+}
+
+auto FaceElementTransformations_Elem2No(FaceElementTransformations const& trans) -> int {
+    return trans.Elem2No;
+}
+
+auto FaceElementTransformations_GetGeometryType(FaceElementTransformations const& trans) -> int {
+    return trans.GetGeometryType();
+}
+
+// NOTE: `trans` keeps a pointer to `face_ip`.
+auto FaceElementTransformations_SetAllIntPoints(FaceElementTransformations& trans, IntegrationPoint const& face_ip) -> void {
+    trans.SetAllIntPoints(&face_ip);
+}
+
+auto FaceElementTransformations_GetElement1IntPoint(FaceElementTransformations const& trans) -> IntegrationPoint const& {
+    return as_mut(trans).GetElement1IntPoint();
+}
+
+auto FaceElementTransformations_GetElement2IntPoint(FaceElementTransformations const& trans) -> IntegrationPoint const& {
+    return as_mut(trans).GetElement2IntPoint();
+}
+
+auto FaceElementTransformations_as_mut_ElementTransformation(FaceElementTransformations& trans) -> ElementTransformation& {
+    return trans;
+}
+
+// Scaled by the face area element, pointing from element 1 to element 2, as in MFEM's DG integrators.
+auto FaceElementTransformations_Normal(FaceElementTransformations& trans, Vector& nor) -> void {
+    nor.SetSize(trans.GetSpaceDim());
+    if (nor.Size() == 1) {
+        nor(0) = 2.0 * trans.GetElement1IntPoint().x - 1.0;
+    } else {
+        CalcOrtho(trans.Jacobian(), nor);
+    }
+}
+
 //////////
 // Mesh //
 //////////
 
+using ElementType = Element::Type;
+
+auto Mesh_MakeCartesian1D(int n, double sx) -> std::unique_ptr<Mesh> {
+    if (n < 1) {
+        throw mfem_invalid_argument("Mesh::MakeCartesian1D(): n must be positive");
+    }
+    return std::make_unique<Mesh>(Mesh::MakeCartesian1D(n, sx));
+}
+
+auto Mesh_MakeCartesian2D(
+    int nx,
+    int ny,
+    ElementType type,
+    bool generate_edges,
+    double sx,
+    double sy,
+    bool sfc_ordering
+) -> std::unique_ptr<Mesh> {
+    if (nx < 1 || ny < 1) {
+        throw mfem_invalid_argument("Mesh::MakeCartesian2D(): nx and ny must be positive");
+    }
+    if (type != Element::TRIANGLE && type != Element::QUADRILATERAL) {
+        throw mfem_invalid_argument("Mesh::MakeCartesian2D(): type must be TRIANGLE or QUADRILATERAL");
+    }
+    return std::make_unique<Mesh>(Mesh::MakeCartesian2D(nx, ny, type, generate_edges, sx, sy, sfc_ordering));
+}
+
+auto Mesh_MakeCartesian3D(
+    int nx,
+    int ny,
+    int nz,
+    ElementType type,
+    double sx,
+    double sy,
+    double sz,
+    bool sfc_ordering
+) -> std::unique_ptr<Mesh> {
+    if (nx < 1 || ny < 1 || nz < 1) {
+        throw mfem_invalid_argument("Mesh::MakeCartesian3D(): nx, ny and nz must be positive");
+    }
+    if (type != Element::HEXAHEDRON && type != Element::TETRAHEDRON && type != Element::WEDGE) {
+        throw mfem_invalid_argument("Mesh::MakeCartesian3D(): type must be HEXAHEDRON, TETRAHEDRON or WEDGE");
+    }
+    return std::make_unique<Mesh>(Mesh::MakeCartesian3D(nx, ny, nz, type, sx, sy, sz, sfc_ordering));
+}
+
+auto Mesh_AddVertex(Mesh& mesh, rust::Slice<const double> coords) -> int {
+    if (coords.size() != static_cast<size_t>(mesh.SpaceDimension())) {
+        throw mfem_exception("Mesh::AddVertex(): wrong number of coordinates");
+    }
+    return mesh.AddVertex(coords.data());
+}
+
+auto Mesh_check_vertices(Mesh const& mesh, rust::Slice<const int> vi, size_t count, char const* what) -> void {
+    if (vi.size() != count) {
+        throw mfem_exception(what);
+    }
+    for (auto v : vi) {
+        if (v < 0 || v >= mesh.GetNV()) {
+            throw mfem_exception(what);
+        }
+    }
+}
+
+auto Mesh_AddSegment(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 2, "Mesh::AddSegment(): bad connectivity");
+    return mesh.AddSegment(vi.data(), attr);
+}
+
+auto Mesh_AddTriangle(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 3, "Mesh::AddTriangle(): bad connectivity");
+    return mesh.AddTriangle(vi.data(), attr);
+}
+
+auto Mesh_AddQuad(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 4, "Mesh::AddQuad(): bad connectivity");
+    return mesh.AddQuad(vi.data(), attr);
+}
+
+auto Mesh_AddTet(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 4, "Mesh::AddTet(): bad connectivity");
+    return mesh.AddTet(vi.data(), attr);
+}
+
+auto Mesh_AddWedge(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 6, "Mesh::AddWedge(): bad connectivity");
+    return mesh.AddWedge(vi.data(), attr);
+}
+
+auto Mesh_AddHex(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 8, "Mesh::AddHex(): bad connectivity");
+    return mesh.AddHex(vi.data(), attr);
+}
+
+auto Mesh_AddBdrPoint(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 1, "Mesh::AddBdrPoint(): bad connectivity");
+    return mesh.AddBdrPoint(vi[0], attr);
+}
+
+auto Mesh_AddBdrSegment(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 2, "Mesh::AddBdrSegment(): bad connectivity");
+    return mesh.AddBdrSegment(vi.data(), attr);
+}
+
+auto Mesh_AddBdrTriangle(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 3, "Mesh::AddBdrTriangle(): bad connectivity");
+    return mesh.AddBdrTriangle(vi.data(), attr);
+}
+
+auto Mesh_AddBdrQuad(Mesh& mesh, rust::Slice<const int> vi, int attr) -> int {
+    Mesh_check_vertices(mesh, vi, 4, "Mesh::AddBdrQuad(): bad connectivity");
+    return mesh.AddBdrQuad(vi.data(), attr);
+}
+
+auto Mesh_ctor_file(std::string const& filename, int generate_edges, int refine, bool fix_orientation) -> std::unique_ptr<Mesh> {
+    if (!std::ifstream(filename)) {
+        throw mfem_io_error("unable to open mesh file: " + filename);
+    }
+    return std::make_unique<Mesh>(filename, generate_edges, refine, fix_orientation);
+}
+
 auto Mesh_GetNodes(Mesh const& mesh) -> GridFunction const& {
     auto ptr = mesh.GetNodes();
     if (!ptr) {
@@ -67,10 +611,47 @@ auto Mesh_GetNodes(Mesh const& mesh) -> GridFunction const& {
     return *ptr;
 }
 
+auto Mesh_attributes(Mesh const& mesh) -> ArrayInt const& {
+    return mesh.attributes;
+}
+
 auto Mesh_bdr_attributes(Mesh const& mesh) -> ArrayInt const& {
     return mesh.bdr_attributes;
 }
 
+auto Mesh_GetBoundingBox(Mesh const& mesh, Vector& min, Vector& max, int ref) -> void {
+    // GetBoundingBox() is not const, but it doesn't modify the mesh
+    as_mut(mesh).GetBoundingBox(min, max, ref);
+}
+
+// Turns a list of boundary attributes into a 0/1 marker array, as used by MFEM's forms
+auto Mesh_bdr_marker(Mesh const& mesh, rust::Slice<const int> bdr_attrs) -> std::unique_ptr<ArrayInt> {
+    int max_attr = mesh.bdr_attributes.Size() > 0 ? mesh.bdr_attributes.Max() : 0;
+    auto marker = std::make_unique<ArrayInt>(max_attr);
+    *marker = 0;
+    for (int attr : bdr_attrs) {
+        if (attr < 1 || attr > max_attr) {
+            throw mfem_invalid_argument("boundary attribute " + std::to_string(attr) + " is not in the mesh");
+        }
+        (*marker)[attr - 1] = 1;
+    }
+    return marker;
+}
+
+auto Mesh_ctor_reader(RustRead& reader, int generate_edges, int refine, bool fix_orientation) -> std::unique_ptr<Mesh> {
+    RustReadStreambuf buf(reader);
+    std::istream input(&buf);
+    return std::make_unique<Mesh>(input, generate_edges, refine, fix_orientation);
+}
+
+auto Mesh_Print(Mesh const& mesh, RustWrite& writer, int precision) -> void {
+    RustWriteStreambuf buf(writer);
+    std::ostream output(&buf);
+    output.precision(precision);
+    mesh.Print(output);
+    output.flush();
+}
+
 ////////////////////////
 // FiniteElementSpace //
 ////////////////////////
@@ -126,6 +707,21 @@ auto GridFunction_ProjectCoefficient(GridFunction& grid_func, Coefficient const&
     grid_func.ProjectCoefficient(mut_coeff);
 }
 
+auto GridFunction_ProjectVectorCoefficient(GridFunction& grid_func, VectorCoefficient const& coeff) {
+    auto& mut_coeff = as_mut(coeff);
+    grid_func.ProjectCoefficient(mut_coeff);
+}
+
+auto GridFunction_ComputeL2Error(GridFunction const& grid_func, Coefficient const& exsol) -> double {
+    auto& mut_exsol = as_mut(exsol);
+    return grid_func.ComputeL2Error(mut_exsol);
+}
+
+auto GridFunction_ComputeL2Error_vector(GridFunction const& grid_func, VectorCoefficient const& exsol) -> double {
+    auto& mut_exsol = as_mut(exsol);
+    return grid_func.ComputeL2Error(mut_exsol);
+}
+
 auto GridFunction_SetAll(GridFunction& grid_func, double value) {
     grid_func = value;
 }
@@ -134,10 +730,21 @@ auto GridFunction_Save(GridFunction const& grid_func, std::string const& fname,
     grid_func.Save(fname.c_str(), precision);
 }
 
+auto GridFunction_Print(GridFunction const& grid_func, RustWrite& writer, int precision) {
+    RustWriteStreambuf buf(writer);
+    std::ostream output(&buf);
+    output.precision(precision);
+    grid_func.Save(output);
+    output.flush();
+}
+
 ////////////////
 // LinearForm //
 ////////////////
 
+// Defined after RustLinearFormIntegrator. Throws if `lfi` doesn't fit the space of `lf`.
+auto LinearForm_check_integrator(LinearForm const& lf, LinearFormIntegrator const& lfi) -> void;
+
 auto LinearForm_as_Vector(LinearForm const& lf) -> Vector const& {
     return lf;
 }
@@ -152,68 +759,917 @@ auto LinearForm_ctor_fes(FiniteElementSpace const& fespace) -> std::unique_ptr<L
     return std::make_unique<LinearForm>(&mut_fespace);
 }
 
-auto LinearForm_AddDomainIntegrator(LinearForm& lf, std::unique_ptr<LinearFormIntegrator> lfi) {
+auto LinearForm_AddDomainIntegrator(LinearForm& lf, std::unique_ptr<LinearFormIntegrator> lfi) -> void {
+    LinearForm_check_integrator(lf, *lfi);
     lf.AddDomainIntegrator(lfi.release());
 }
 
-/////////////////////////
-// ConstantCoefficient //
-/////////////////////////
-
-auto ConstantCoefficient_as_Coeff(ConstantCoefficient const& coeff) -> Coefficient const& {
-    return coeff;
+auto LinearForm_AddBoundaryIntegrator(LinearForm& lf, std::unique_ptr<LinearFormIntegrator> lfi) -> void {
+    LinearForm_check_integrator(lf, *lfi);
+    lf.AddBoundaryIntegrator(lfi.release());
 }
 
-////////////////////////
-// DomainLFIntegrator //
-////////////////////////
+auto LinearForm_AddBoundaryIntegrator_attrs(
+    LinearForm& lf,
+    std::unique_ptr<LinearFormIntegrator> lfi,
+    rust::Slice<const int> bdr_attrs
+) -> std::unique_ptr<ArrayInt> {
+    LinearForm_check_integrator(lf, *lfi);
+    auto marker = Mesh_bdr_marker(*lf.FESpace()->GetMesh(), bdr_attrs);
+    lf.AddBoundaryIntegrator(lfi.release(), *marker);
+    return marker;
+}
 
-auto DomainLFIntegrator_ctor_ab(Coefficient const& coeff, int a, int b) -> std::unique_ptr<DomainLFIntegrator> {
-    // HACK(mkovaxx): This might come back to bite me...
-    auto& mut_coeff = const_cast<Coefficient&>(coeff);
-    return std::make_unique<DomainLFIntegrator>(mut_coeff, a, b);
+auto LinearForm_AddInteriorFaceIntegrator(LinearForm& lf, std::unique_ptr<LinearFormIntegrator> lfi) -> void {
+    LinearForm_check_integrator(lf, *lfi);
+    lf.AddInteriorFaceIntegrator(lfi.release());
 }
 
-auto DomainLFIntegrator_as_LFI(DomainLFIntegrator const& domain_lfi) -> LinearFormIntegrator const& {
-    return domain_lfi;
+auto LinearForm_AddBdrFaceIntegrator(LinearForm& lf, std::unique_ptr<LinearFormIntegrator> lfi) -> void {
+    LinearForm_check_integrator(lf, *lfi);
+    lf.AddBdrFaceIntegrator(lfi.release());
 }
 
-auto DomainLFIntegrator_into_LFI(std::unique_ptr<DomainLFIntegrator> domain_lfi) -> std::unique_ptr<LinearFormIntegrator> {
-    return std::move(domain_lfi);
+auto LinearForm_AddBdrFaceIntegrator_attrs(
+    LinearForm& lf,
+    std::unique_ptr<LinearFormIntegrator> lfi,
+    rust::Slice<const int> bdr_attrs
+) -> std::unique_ptr<ArrayInt> {
+    LinearForm_check_integrator(lf, *lfi);
+    auto marker = Mesh_bdr_marker(*lf.FESpace()->GetMesh(), bdr_attrs);
+    lf.AddBdrFaceIntegrator(lfi.release(), *marker);
+    return marker;
 }
 
-//////////////////
-// BilinearForm //
-//////////////////
+/////////////////
+// Coefficient //
+/////////////////
 
-auto BilinearForm_ctor_fes(FiniteElementSpace const& fespace) -> std::unique_ptr<BilinearForm> {
-    // HACK(mkovaxx): This might come back to bite me...
-    auto& mut_fespace = const_cast<FiniteElementSpace&>(fespace);
-    return std::make_unique<BilinearForm>(&mut_fespace);
-}
+// NOTE: The time is mutable through a const reference,
+// so that it can be changed while integrators hold on to the coefficient.
 
-auto BilinearForm_AddDomainIntegrator(BilinearForm& bf, std::unique_ptr<BilinearFormIntegrator> bfi) {
-    bf.AddDomainIntegrator(bfi.release());
+auto Coefficient_SetTime(Coefficient const& coeff, double t) -> void {
+    as_mut(coeff).SetTime(t);
 }
 
-auto BilinearForm_FormLinearSystem(
-    BilinearForm const& a,
-    ArrayInt const& ess_tdof_list,
-    Vector const& x,
-    Vector const& b,
-    OperatorHandle& a_mat,
-    Vector& x_vec,
-    Vector& b_vec
-) {
-    // HACK(mkovaxx): This might come back to bite me...
-    auto& mut_a = const_cast<BilinearForm&>(a);
-    auto& mut_x = const_cast<Vector&>(x);
-    auto& mut_b = const_cast<Vector&>(b);
-    mut_a.FormLinearSystem(ess_tdof_list, mut_x, mut_b, a_mat, x_vec, b_vec);
+auto Coefficient_GetTime(Coefficient const& coeff) -> double {
+    return as_mut(coeff).GetTime();
 }
 
 /////////////////////////
-// DiffusionIntegrator //
+// ConstantCoefficient //
+/////////////////////////
+
+auto ConstantCoefficient_as_Coeff(ConstantCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+/////////////////////////////
+// RustFunctionCoefficient //
+/////////////////////////////
+
+// Like FunctionCoefficient, but calls back into Rust
+class RustFunctionCoefficient : public Coefficient {
+    RustFunction const& func_;
+
+public:
+    explicit RustFunctionCoefficient(RustFunction const& func) : func_(func) {}
+
+    auto Eval(ElementTransformation& T, IntegrationPoint const& ip) -> real_t override {
+        real_t x[3];
+        Vector transip(x, 3);
+        T.Transform(ip, transip);
+        auto x_slice = rust::Slice<const double>(transip.GetData(), transip.Size());
+        return RustFunction_eval(func_, x_slice, GetTime());
+    }
+};
+
+auto RustFunctionCoefficient_ctor(RustFunction const& func) -> std::unique_ptr<RustFunctionCoefficient> {
+    return std::make_unique<RustFunctionCoefficient>(func);
+}
+
+auto RustFunctionCoefficient_as_Coeff(RustFunctionCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+////////////////////////
+// PWConstCoefficient //
+////////////////////////
+
+auto PWConstCoefficient_ctor(rust::Slice<const double> c) -> std::unique_ptr<PWConstCoefficient> {
+    // PWConstCoefficient makes a copy of the Vector
+    Vector constants(const_cast<double*>(c.data()), c.size());
+    return std::make_unique<PWConstCoefficient>(constants);
+}
+
+auto PWConstCoefficient_as_Coeff(PWConstCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+///////////////////
+// PWCoefficient //
+///////////////////
+
+auto PWCoefficient_UpdateCoefficient(PWCoefficient& pw_coeff, int attr, Coefficient const& coeff) -> void {
+    auto& mut_coeff = as_mut(coeff);
+    pw_coeff.UpdateCoefficient(attr, mut_coeff);
+}
+
+auto PWCoefficient_as_Coeff(PWCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+////////////////////
+// SumCoefficient //
+////////////////////
+
+auto SumCoefficient_ctor(Coefficient const& a, Coefficient const& b, double alpha, double beta) -> std::unique_ptr<SumCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    return std::make_unique<SumCoefficient>(mut_a, mut_b, alpha, beta);
+}
+
+auto SumCoefficient_ctor_const(double a, Coefficient const& b, double alpha, double beta) -> std::unique_ptr<SumCoefficient> {
+    auto& mut_b = as_mut(b);
+    return std::make_unique<SumCoefficient>(a, mut_b, alpha, beta);
+}
+
+auto SumCoefficient_as_Coeff(SumCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+////////////////////////
+// ProductCoefficient //
+////////////////////////
+
+auto ProductCoefficient_ctor(Coefficient const& a, Coefficient const& b) -> std::unique_ptr<ProductCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    return std::make_unique<ProductCoefficient>(mut_a, mut_b);
+}
+
+auto ProductCoefficient_ctor_const(double a, Coefficient const& b) -> std::unique_ptr<ProductCoefficient> {
+    auto& mut_b = as_mut(b);
+    return std::make_unique<ProductCoefficient>(a, mut_b);
+}
+
+auto ProductCoefficient_as_Coeff(ProductCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+//////////////////////
+// RatioCoefficient //
+//////////////////////
+
+auto RatioCoefficient_ctor(Coefficient const& a, Coefficient const& b) -> std::unique_ptr<RatioCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    return std::make_unique<RatioCoefficient>(mut_a, mut_b);
+}
+
+auto RatioCoefficient_ctor_const(double a, Coefficient const& b) -> std::unique_ptr<RatioCoefficient> {
+    auto& mut_b = as_mut(b);
+    return std::make_unique<RatioCoefficient>(a, mut_b);
+}
+
+auto RatioCoefficient_as_Coeff(RatioCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+//////////////////////
+// PowerCoefficient //
+//////////////////////
+
+auto PowerCoefficient_ctor(Coefficient const& a, double p) -> std::unique_ptr<PowerCoefficient> {
+    auto& mut_a = as_mut(a);
+    return std::make_unique<PowerCoefficient>(mut_a, p);
+}
+
+auto PowerCoefficient_as_Coeff(PowerCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+////////////////////////////////
+// RustTransformedCoefficient //
+////////////////////////////////
+
+// Like TransformedCoefficient, but calls a Rust fn instead of a C function pointer
+class RustTransformedCoefficient : public Coefficient {
+    Coefficient* q1_;
+    Coefficient* q2_;
+    RustTransform const& func_;
+
+public:
+    RustTransformedCoefficient(Coefficient* q1, Coefficient* q2, RustTransform const& func)
+        : q1_(q1), q2_(q2), func_(func) {}
+
+    auto SetTime(real_t t) -> void override {
+        q1_->SetTime(t);
+        if (q2_) { q2_->SetTime(t); }
+        Coefficient::SetTime(t);
+    }
+
+    auto Eval(ElementTransformation& T, IntegrationPoint const& ip) -> real_t override {
+        real_t v1 = q1_->Eval(T, ip, GetTime());
+        real_t v2 = q2_ ? q2_->Eval(T, ip, GetTime()) : 0.0;
+        return RustTransform_eval(func_, v1, v2);
+    }
+};
+
+auto RustTransformedCoefficient_ctor(Coefficient const& q, RustTransform const& func) -> std::unique_ptr<RustTransformedCoefficient> {
+    auto& mut_q = as_mut(q);
+    return std::make_unique<RustTransformedCoefficient>(&mut_q, nullptr, func);
+}
+
+auto RustTransformedCoefficient_ctor2(Coefficient const& q1, Coefficient const& q2, RustTransform const& func) -> std::unique_ptr<RustTransformedCoefficient> {
+    auto& mut_q1 = as_mut(q1);
+    auto& mut_q2 = as_mut(q2);
+    return std::make_unique<RustTransformedCoefficient>(&mut_q1, &mut_q2, func);
+}
+
+auto RustTransformedCoefficient_as_Coeff(RustTransformedCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+///////////////////////
+// VectorCoefficient //
+///////////////////////
+
+auto VectorCoefficient_GetVDim(VectorCoefficient const& coeff) -> int {
+    return as_mut(coeff).GetVDim();
+}
+
+auto VectorCoefficient_SetTime(VectorCoefficient const& coeff, double t) -> void {
+    as_mut(coeff).SetTime(t);
+}
+
+auto VectorCoefficient_GetTime(VectorCoefficient const& coeff) -> double {
+    return as_mut(coeff).GetTime();
+}
+
+///////////////////////////////
+// VectorConstantCoefficient //
+///////////////////////////////
+
+auto VectorConstantCoefficient_ctor(rust::Slice<const double> v) -> std::unique_ptr<VectorConstantCoefficient> {
+    // VectorConstantCoefficient makes a copy of the Vector
+    Vector vec(const_cast<double*>(v.data()), v.size());
+    return std::make_unique<VectorConstantCoefficient>(vec);
+}
+
+auto VectorConstantCoefficient_as_VectorCoeff(VectorConstantCoefficient const& coeff) -> VectorCoefficient const& {
+    return coeff;
+}
+
+///////////////////////////////////
+// RustVectorFunctionCoefficient //
+///////////////////////////////////
+
+// Like VectorFunctionCoefficient, but calls back into Rust
+class RustVectorFunctionCoefficient : public VectorCoefficient {
+    RustVectorFunction const& func_;
+
+public:
+    RustVectorFunctionCoefficient(int vdim, RustVectorFunction const& func) : VectorCoefficient(vdim), func_(func) {}
+
+    using VectorCoefficient::Eval;
+    auto Eval(Vector& V, ElementTransformation& T, IntegrationPoint const& ip) -> void override {
+        real_t x[3];
+        Vector transip(x, 3);
+        T.Transform(ip, transip);
+        V.SetSize(vdim);
+        auto x_slice = rust::Slice<const double>(transip.GetData(), transip.Size());
+        auto v_slice = rust::Slice<double>(V.GetData(), V.Size());
+        RustVectorFunction_eval(func_, x_slice, GetTime(), v_slice);
+    }
+};
+
+auto RustVectorFunctionCoefficient_ctor(int vdim, RustVectorFunction const& func) -> std::unique_ptr<RustVectorFunctionCoefficient> {
+    return std::make_unique<RustVectorFunctionCoefficient>(vdim, func);
+}
+
+auto RustVectorFunctionCoefficient_as_VectorCoeff(RustVectorFunctionCoefficient const& coeff) -> VectorCoefficient const& {
+    return coeff;
+}
+
+//////////////////////////
+// VectorSumCoefficient //
+//////////////////////////
+
+auto VectorSumCoefficient_ctor(VectorCoefficient const& a, VectorCoefficient const& b, double alpha, double beta) -> std::unique_ptr<VectorSumCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    if (mut_a.GetVDim() != mut_b.GetVDim()) {
+        throw mfem_size_mismatch("VectorSumCoefficient: operands have different vdims");
+    }
+    return std::make_unique<VectorSumCoefficient>(mut_a, mut_b, alpha, beta);
+}
+
+auto VectorSumCoefficient_as_VectorCoeff(VectorSumCoefficient const& coeff) -> VectorCoefficient const& {
+    return coeff;
+}
+
+////////////////////////////////////
+// ScalarVectorProductCoefficient //
+////////////////////////////////////
+
+auto ScalarVectorProductCoefficient_ctor(Coefficient const& a, VectorCoefficient const& b) -> std::unique_ptr<ScalarVectorProductCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    return std::make_unique<ScalarVectorProductCoefficient>(mut_a, mut_b);
+}
+
+auto ScalarVectorProductCoefficient_ctor_const(double a, VectorCoefficient const& b) -> std::unique_ptr<ScalarVectorProductCoefficient> {
+    auto& mut_b = as_mut(b);
+    return std::make_unique<ScalarVectorProductCoefficient>(a, mut_b);
+}
+
+auto ScalarVectorProductCoefficient_as_VectorCoeff(ScalarVectorProductCoefficient const& coeff) -> VectorCoefficient const& {
+    return coeff;
+}
+
+/////////////////////////////
+// InnerProductCoefficient //
+/////////////////////////////
+
+auto InnerProductCoefficient_ctor(VectorCoefficient const& a, VectorCoefficient const& b) -> std::unique_ptr<InnerProductCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    if (mut_a.GetVDim() != mut_b.GetVDim()) {
+        throw mfem_size_mismatch("InnerProductCoefficient: operands have different vdims");
+    }
+    return std::make_unique<InnerProductCoefficient>(mut_a, mut_b);
+}
+
+auto InnerProductCoefficient_as_Coeff(InnerProductCoefficient const& coeff) -> Coefficient const& {
+    return coeff;
+}
+
+///////////////////////
+// MatrixCoefficient //
+///////////////////////
+
+auto MatrixCoefficient_GetHeight(MatrixCoefficient const& coeff) -> int {
+    return coeff.GetHeight();
+}
+
+auto MatrixCoefficient_GetWidth(MatrixCoefficient const& coeff) -> int {
+    return coeff.GetWidth();
+}
+
+auto MatrixCoefficient_SetTime(MatrixCoefficient const& coeff, double t) -> void {
+    as_mut(coeff).SetTime(t);
+}
+
+auto MatrixCoefficient_GetTime(MatrixCoefficient const& coeff) -> double {
+    return as_mut(coeff).GetTime();
+}
+
+///////////////////////////////
+// MatrixConstantCoefficient //
+///////////////////////////////
+
+auto MatrixConstantCoefficient_ctor(int height, int width, rust::Slice<const double> data) -> std::unique_ptr<MatrixConstantCoefficient> {
+    if (height < 0 || width < 0 || data.size() != static_cast<size_t>(height * width)) {
+        throw mfem_size_mismatch("MatrixConstantCoefficient: data doesn't match height * width");
+    }
+    // MatrixConstantCoefficient makes a copy of the DenseMatrix
+    DenseMatrix mat(const_cast<double*>(data.data()), height, width);
+    return std::make_unique<MatrixConstantCoefficient>(mat);
+}
+
+auto MatrixConstantCoefficient_as_MatrixCoeff(MatrixConstantCoefficient const& coeff) -> MatrixCoefficient const& {
+    return coeff;
+}
+
+///////////////////////////////////
+// RustMatrixFunctionCoefficient //
+///////////////////////////////////
+
+// Like MatrixFunctionCoefficient, but calls back into Rust
+class RustMatrixFunctionCoefficient : public MatrixCoefficient {
+    RustMatrixFunction const& func_;
+
+public:
+    RustMatrixFunctionCoefficient(int height, int width, RustMatrixFunction const& func) : MatrixCoefficient(height, width), func_(func) {}
+
+    using MatrixCoefficient::Eval;
+    auto Eval(DenseMatrix& K, ElementTransformation& T, IntegrationPoint const& ip) -> void override {
+        real_t x[3];
+        Vector transip(x, 3);
+        T.Transform(ip, transip);
+        K.SetSize(height, width);
+        auto x_slice = rust::Slice<const double>(transip.GetData(), transip.Size());
+        auto k_slice = rust::Slice<double>(K.Data(), K.Height() * K.Width());
+        RustMatrixFunction_eval(func_, x_slice, GetTime(), k_slice);
+    }
+};
+
+auto RustMatrixFunctionCoefficient_ctor(int height, int width, RustMatrixFunction const& func) -> std::unique_ptr<RustMatrixFunctionCoefficient> {
+    return std::make_unique<RustMatrixFunctionCoefficient>(height, width, func);
+}
+
+auto RustMatrixFunctionCoefficient_as_MatrixCoeff(RustMatrixFunctionCoefficient const& coeff) -> MatrixCoefficient const& {
+    return coeff;
+}
+
+//////////////////////////
+// MatrixSumCoefficient //
+//////////////////////////
+
+auto MatrixSumCoefficient_ctor(MatrixCoefficient const& a, MatrixCoefficient const& b, double alpha, double beta) -> std::unique_ptr<MatrixSumCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    if (a.GetHeight() != b.GetHeight() || a.GetWidth() != b.GetWidth()) {
+        throw mfem_size_mismatch("MatrixSumCoefficient: operands have different sizes");
+    }
+    return std::make_unique<MatrixSumCoefficient>(mut_a, mut_b, alpha, beta);
+}
+
+auto MatrixSumCoefficient_as_MatrixCoeff(MatrixSumCoefficient const& coeff) -> MatrixCoefficient const& {
+    return coeff;
+}
+
+////////////////////////////////////
+// ScalarMatrixProductCoefficient //
+////////////////////////////////////
+
+auto ScalarMatrixProductCoefficient_ctor(Coefficient const& a, MatrixCoefficient const& b) -> std::unique_ptr<ScalarMatrixProductCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    return std::make_unique<ScalarMatrixProductCoefficient>(mut_a, mut_b);
+}
+
+auto ScalarMatrixProductCoefficient_ctor_const(double a, MatrixCoefficient const& b) -> std::unique_ptr<ScalarMatrixProductCoefficient> {
+    auto& mut_b = as_mut(b);
+    return std::make_unique<ScalarMatrixProductCoefficient>(a, mut_b);
+}
+
+auto ScalarMatrixProductCoefficient_as_MatrixCoeff(ScalarMatrixProductCoefficient const& coeff) -> MatrixCoefficient const& {
+    return coeff;
+}
+
+////////////////////////////////////
+// MatrixVectorProductCoefficient //
+////////////////////////////////////
+
+auto MatrixVectorProductCoefficient_ctor(MatrixCoefficient const& a, VectorCoefficient const& b) -> std::unique_ptr<MatrixVectorProductCoefficient> {
+    auto& mut_a = as_mut(a);
+    auto& mut_b = as_mut(b);
+    if (a.GetWidth() != mut_b.GetVDim()) {
+        throw mfem_size_mismatch("MatrixVectorProductCoefficient: matrix width doesn't match vdim");
+    }
+    return std::make_unique<MatrixVectorProductCoefficient>(mut_a, mut_b);
+}
+
+auto MatrixVectorProductCoefficient_as_VectorCoeff(MatrixVectorProductCoefficient const& coeff) -> VectorCoefficient const& {
+    return coeff;
+}
+
+////////////////////////
+// DomainLFIntegrator //
+////////////////////////
+
+auto DomainLFIntegrator_ctor_ab(Coefficient const& coeff, int a, int b) -> std::unique_ptr<DomainLFIntegrator> {
+    // HACK(mkovaxx): This might come back to bite me...
+    auto& mut_coeff = const_cast<Coefficient&>(coeff);
+    return std::make_unique<DomainLFIntegrator>(mut_coeff, a, b);
+}
+
+auto DomainLFIntegrator_as_LFI(DomainLFIntegrator const& domain_lfi) -> LinearFormIntegrator const& {
+    return domain_lfi;
+}
+
+auto DomainLFIntegrator_into_LFI(std::unique_ptr<DomainLFIntegrator> domain_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(domain_lfi);
+}
+
+//////////////////////////
+// BoundaryLFIntegrator //
+//////////////////////////
+
+auto BoundaryLFIntegrator_ctor_ab(Coefficient const& coeff, int a, int b) -> std::unique_ptr<BoundaryLFIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<BoundaryLFIntegrator>(mut_coeff, a, b);
+}
+
+auto BoundaryLFIntegrator_as_LFI(BoundaryLFIntegrator const& boundary_lfi) -> LinearFormIntegrator const& {
+    return boundary_lfi;
+}
+
+auto BoundaryLFIntegrator_into_LFI(std::unique_ptr<BoundaryLFIntegrator> boundary_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(boundary_lfi);
+}
+
+////////////////////////////////
+// BoundaryNormalLFIntegrator //
+////////////////////////////////
+
+auto BoundaryNormalLFIntegrator_ctor_ab(VectorCoefficient const& coeff, int a, int b) -> std::unique_ptr<BoundaryNormalLFIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<BoundaryNormalLFIntegrator>(mut_coeff, a, b);
+}
+
+auto BoundaryNormalLFIntegrator_as_LFI(BoundaryNormalLFIntegrator const& boundary_normal_lfi) -> LinearFormIntegrator const& {
+    return boundary_normal_lfi;
+}
+
+auto BoundaryNormalLFIntegrator_into_LFI(std::unique_ptr<BoundaryNormalLFIntegrator> boundary_normal_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(boundary_normal_lfi);
+}
+
+////////////////////////////////
+// VectorBoundaryLFIntegrator //
+////////////////////////////////
+
+auto VectorBoundaryLFIntegrator_ctor(VectorCoefficient const& coeff) -> std::unique_ptr<VectorBoundaryLFIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorBoundaryLFIntegrator>(mut_coeff);
+}
+
+auto VectorBoundaryLFIntegrator_as_LFI(VectorBoundaryLFIntegrator const& vector_boundary_lfi) -> LinearFormIntegrator const& {
+    return vector_boundary_lfi;
+}
+
+auto VectorBoundaryLFIntegrator_into_LFI(std::unique_ptr<VectorBoundaryLFIntegrator> vector_boundary_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(vector_boundary_lfi);
+}
+
+/////////////////////////////
+// DGDirichletLFIntegrator //
+/////////////////////////////
+
+auto DGDirichletLFIntegrator_ctor(Coefficient const& u_d, double sigma, double kappa) -> std::unique_ptr<DGDirichletLFIntegrator> {
+    auto& mut_u_d = as_mut(u_d);
+    return std::make_unique<DGDirichletLFIntegrator>(mut_u_d, sigma, kappa);
+}
+
+auto DGDirichletLFIntegrator_ctor_q(Coefficient const& u_d, Coefficient const& q, double sigma, double kappa) -> std::unique_ptr<DGDirichletLFIntegrator> {
+    auto& mut_u_d = as_mut(u_d);
+    auto& mut_q = as_mut(q);
+    return std::make_unique<DGDirichletLFIntegrator>(mut_u_d, mut_q, sigma, kappa);
+}
+
+auto DGDirichletLFIntegrator_as_LFI(DGDirichletLFIntegrator const& dg_dirichlet_lfi) -> LinearFormIntegrator const& {
+    return dg_dirichlet_lfi;
+}
+
+auto DGDirichletLFIntegrator_into_LFI(std::unique_ptr<DGDirichletLFIntegrator> dg_dirichlet_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(dg_dirichlet_lfi);
+}
+
+////////////////////////////
+// BoundaryFlowIntegrator //
+////////////////////////////
+
+auto BoundaryFlowIntegrator_ctor(Coefficient const& f, VectorCoefficient const& u, double alpha, double beta) -> std::unique_ptr<BoundaryFlowIntegrator> {
+    auto& mut_f = as_mut(f);
+    auto& mut_u = as_mut(u);
+    return std::make_unique<BoundaryFlowIntegrator>(mut_f, mut_u, alpha, beta);
+}
+
+auto BoundaryFlowIntegrator_as_LFI(BoundaryFlowIntegrator const& boundary_flow_lfi) -> LinearFormIntegrator const& {
+    return boundary_flow_lfi;
+}
+
+auto BoundaryFlowIntegrator_into_LFI(std::unique_ptr<BoundaryFlowIntegrator> boundary_flow_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(boundary_flow_lfi);
+}
+
+////////////////////////////////
+// VectorFEDomainLFIntegrator //
+////////////////////////////////
+
+auto VectorFEDomainLFIntegrator_ctor(VectorCoefficient const& coeff) -> std::unique_ptr<VectorFEDomainLFIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorFEDomainLFIntegrator>(mut_coeff);
+}
+
+auto VectorFEDomainLFIntegrator_as_LFI(VectorFEDomainLFIntegrator const& vector_fe_domain_lfi) -> LinearFormIntegrator const& {
+    return vector_fe_domain_lfi;
+}
+
+auto VectorFEDomainLFIntegrator_into_LFI(std::unique_ptr<VectorFEDomainLFIntegrator> vector_fe_domain_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(vector_fe_domain_lfi);
+}
+
+//////////////////////////////
+// RustLinearFormIntegrator //
+//////////////////////////////
+
+class RustLinearFormIntegrator : public LinearFormIntegrator {
+    RustLinearIntegrator* integ_;
+
+public:
+    explicit RustLinearFormIntegrator(RustLinearIntegrator* integ) : integ_(integ) {}
+    RustLinearFormIntegrator(RustLinearFormIntegrator const&) = delete;
+    auto operator=(RustLinearFormIntegrator const&) -> RustLinearFormIntegrator& = delete;
+
+    ~RustLinearFormIntegrator() override {
+        RustLinearIntegrator_drop(integ_);
+    }
+
+    using LinearFormIntegrator::AssembleRHSElementVect;
+
+    // One entry per shape function of `el`, zeroed before calling into Rust.
+    // MFEM adds `elvect` to the global vector by the vdofs of the element, so the
+    // vector dimension must be 1, see LinearForm_check_integrator().
+    auto AssembleRHSElementVect(
+        FiniteElement const& el,
+        ElementTransformation& trans,
+        Vector& elvect
+    ) -> void override {
+        elvect.SetSize(el.GetDof());
+        elvect = 0.0;
+        auto elvect_slice = rust::Slice<double>(elvect.GetData(), elvect.Size());
+        RustLinearIntegrator_AssembleRHSElementVect(*integ_, el, trans, elvect_slice);
+    }
+};
+
+auto LinearForm_check_integrator(LinearForm const& lf, LinearFormIntegrator const& lfi) -> void {
+    auto is_rust = dynamic_cast<RustLinearFormIntegrator const*>(&lfi) != nullptr;
+    if (is_rust && lf.FESpace()->GetVDim() != 1) {
+        throw mfem_invalid_argument("LinearForm: Rust integrators need a FE space with a vector dimension of 1");
+    }
+}
+
+auto RustLinearFormIntegrator_ctor(RustLinearIntegrator* integ) -> std::unique_ptr<RustLinearFormIntegrator> {
+    return std::make_unique<RustLinearFormIntegrator>(integ);
+}
+
+auto RustLinearFormIntegrator_as_LFI(RustLinearFormIntegrator const& rust_lfi) -> LinearFormIntegrator const& {
+    return rust_lfi;
+}
+
+auto RustLinearFormIntegrator_into_LFI(std::unique_ptr<RustLinearFormIntegrator> rust_lfi) -> std::unique_ptr<LinearFormIntegrator> {
+    return std::move(rust_lfi);
+}
+
+//////////////////
+// BilinearForm //
+//////////////////
+
+// Defined after RustBilinearFormIntegrator. Throws if `bfi` doesn't fit the space of `bf`.
+auto BilinearForm_check_integrator(BilinearForm const& bf, BilinearFormIntegrator const& bfi) -> void;
+
+auto BilinearForm_ctor_fes(FiniteElementSpace const& fespace) -> std::unique_ptr<BilinearForm> {
+    // HACK(mkovaxx): This might come back to bite me...
+    auto& mut_fespace = const_cast<FiniteElementSpace&>(fespace);
+    return std::make_unique<BilinearForm>(&mut_fespace);
+}
+
+auto BilinearForm_AddDomainIntegrator(BilinearForm& bf, std::unique_ptr<BilinearFormIntegrator> bfi) -> void {
+    BilinearForm_check_integrator(bf, *bfi);
+    bf.AddDomainIntegrator(bfi.release());
+}
+
+auto BilinearForm_AddBoundaryIntegrator(BilinearForm& bf, std::unique_ptr<BilinearFormIntegrator> bfi) -> void {
+    BilinearForm_check_integrator(bf, *bfi);
+    bf.AddBoundaryIntegrator(bfi.release());
+}
+
+auto BilinearForm_AddBoundaryIntegrator_attrs(
+    BilinearForm& bf,
+    std::unique_ptr<BilinearFormIntegrator> bfi,
+    rust::Slice<const int> bdr_attrs
+) -> std::unique_ptr<ArrayInt> {
+    BilinearForm_check_integrator(bf, *bfi);
+    auto marker = Mesh_bdr_marker(*bf.FESpace()->GetMesh(), bdr_attrs);
+    bf.AddBoundaryIntegrator(bfi.release(), *marker);
+    return marker;
+}
+
+auto BilinearForm_AddInteriorFaceIntegrator(BilinearForm& bf, std::unique_ptr<BilinearFormIntegrator> bfi) -> void {
+    BilinearForm_check_integrator(bf, *bfi);
+    bf.AddInteriorFaceIntegrator(bfi.release());
+}
+
+auto BilinearForm_AddBdrFaceIntegrator(BilinearForm& bf, std::unique_ptr<BilinearFormIntegrator> bfi) -> void {
+    BilinearForm_check_integrator(bf, *bfi);
+    bf.AddBdrFaceIntegrator(bfi.release());
+}
+
+auto BilinearForm_AddBdrFaceIntegrator_attrs(
+    BilinearForm& bf,
+    std::unique_ptr<BilinearFormIntegrator> bfi,
+    rust::Slice<const int> bdr_attrs
+) -> std::unique_ptr<ArrayInt> {
+    BilinearForm_check_integrator(bf, *bfi);
+    auto marker = Mesh_bdr_marker(*bf.FESpace()->GetMesh(), bdr_attrs);
+    bf.AddBdrFaceIntegrator(bfi.release(), *marker);
+    return marker;
+}
+
+// Exposes the protected assembled matrices, to zero them in place.
+struct BilinearForm_access : BilinearForm {
+    using BilinearForm::mat;
+    using BilinearForm::mat_e;
+};
+
+// Unlike BilinearForm::Update(), which deletes the matrix, this keeps it alive for the
+// OperatorHandle filled by FormLinearSystem(), which still refers to it.
+auto BilinearForm_Update(BilinearForm& bf) -> void {
+    auto mat = bf.*(&BilinearForm_access::mat);
+    if (mat != nullptr) {
+        *mat = 0.0;
+    }
+    auto mat_e = bf.*(&BilinearForm_access::mat_e);
+    if (mat_e != nullptr) {
+        *mat_e = 0.0;
+    }
+}
+
+auto BilinearForm_Mult(BilinearForm const& bf, Vector const& x, Vector& y) -> void {
+    // SpMat() throws if the form is not assembled
+    auto const& mat = bf.SpMat();
+    if (mat.Width() != x.Size() || mat.Height() != y.Size()) {
+        throw mfem_size_mismatch("BilinearForm::Mult(): vector sizes don't match the matrix");
+    }
+    mat.Mult(x, y);
+}
+
+auto BilinearForm_SpMat(BilinearForm const& bf) -> SparseMatrix const& {
+    return bf.SpMat();
+}
+
+auto BilinearForm_FormLinearSystem(
+    BilinearForm const& a,
+    ArrayInt const& ess_tdof_list,
+    Vector const& x,
+    Vector const& b,
+    OperatorHandle& a_mat,
+    Vector& x_vec,
+    Vector& b_vec
+) {
+    // HACK(mkovaxx): This might come back to bite me...
+    auto& mut_a = const_cast<BilinearForm&>(a);
+    auto& mut_x = const_cast<Vector&>(x);
+    auto& mut_b = const_cast<Vector&>(b);
+    mut_a.FormLinearSystem(ess_tdof_list, mut_x, mut_b, a_mat, x_vec, b_vec);
+}
+
+auto BilinearForm_RecoverFEMSolution(BilinearForm& bf, Vector const& x_vec, Vector const& b_vec, Vector& x) -> void {
+    // RecoverFEMSolution() may resize `x`, which would leave the blocks of a BlockVector dangling.
+    if (x.Size() != bf.FESpace()->GetVSize()) {
+        throw mfem_size_mismatch("BilinearForm::RecoverFEMSolution(): the solution size doesn't match the FE space");
+    }
+    bf.RecoverFEMSolution(x_vec, b_vec, x);
+}
+
+///////////////////////
+// MixedBilinearForm //
+///////////////////////
+
+auto MixedBilinearForm_ctor_fes(
+    FiniteElementSpace const& trial_fespace,
+    FiniteElementSpace const& test_fespace
+) -> std::unique_ptr<MixedBilinearForm> {
+    auto& mut_trial_fespace = as_mut(trial_fespace);
+    auto& mut_test_fespace = as_mut(test_fespace);
+    return std::make_unique<MixedBilinearForm>(&mut_trial_fespace, &mut_test_fespace);
+}
+
+auto MixedBilinearForm_AddDomainIntegrator(MixedBilinearForm& mbf, std::unique_ptr<BilinearFormIntegrator> bfi) {
+    mbf.AddDomainIntegrator(bfi.release());
+}
+
+auto MixedBilinearForm_AddBoundaryIntegrator(MixedBilinearForm& mbf, std::unique_ptr<BilinearFormIntegrator> bfi) {
+    mbf.AddBoundaryIntegrator(bfi.release());
+}
+
+auto MixedBilinearForm_AddBoundaryIntegrator_attrs(
+    MixedBilinearForm& mbf,
+    std::unique_ptr<BilinearFormIntegrator> bfi,
+    rust::Slice<const int> bdr_attrs
+) -> std::unique_ptr<ArrayInt> {
+    auto marker = Mesh_bdr_marker(*mbf.TestFESpace()->GetMesh(), bdr_attrs);
+    mbf.AddBoundaryIntegrator(bfi.release(), *marker);
+    return marker;
+}
+
+// Exposes the protected assembled matrices, to zero them in place.
+struct MixedBilinearForm_access : MixedBilinearForm {
+    using MixedBilinearForm::mat;
+    using MixedBilinearForm::mat_e;
+};
+
+// Unlike MixedBilinearForm::Update(), which deletes the matrix, this keeps it alive for the
+// OperatorHandle filled by FormRectangularLinearSystem(), which still refers to it.
+auto MixedBilinearForm_Update(MixedBilinearForm& mbf) -> void {
+    auto mat = mbf.*(&MixedBilinearForm_access::mat);
+    if (mat != nullptr) {
+        *mat = 0.0;
+    }
+    auto mat_e = mbf.*(&MixedBilinearForm_access::mat_e);
+    if (mat_e != nullptr) {
+        *mat_e = 0.0;
+    }
+}
+
+auto MixedBilinearForm_Mult(MixedBilinearForm const& mbf, Vector const& x, Vector& y) -> void {
+    // SpMat() throws if the form is not assembled
+    auto const& mat = mbf.SpMat();
+    if (mat.Width() != x.Size() || mat.Height() != y.Size()) {
+        throw mfem_size_mismatch("MixedBilinearForm::Mult(): vector sizes don't match the matrix");
+    }
+    mat.Mult(x, y);
+}
+
+auto MixedBilinearForm_MultTranspose(MixedBilinearForm const& mbf, Vector const& x, Vector& y) -> void {
+    // SpMat() throws if the form is not assembled
+    auto const& mat = mbf.SpMat();
+    if (mat.Height() != x.Size() || mat.Width() != y.Size()) {
+        throw mfem_size_mismatch("MixedBilinearForm::MultTranspose(): vector sizes don't match the matrix");
+    }
+    mat.MultTranspose(x, y);
+}
+
+auto MixedBilinearForm_SpMat(MixedBilinearForm const& mbf) -> SparseMatrix const& {
+    return mbf.SpMat();
+}
+
+auto MixedBilinearForm_FormRectangularLinearSystem(
+    MixedBilinearForm const& a,
+    ArrayInt const& trial_tdof_list,
+    ArrayInt const& test_tdof_list,
+    Vector const& x,
+    Vector const& b,
+    OperatorHandle& a_mat,
+    Vector& x_vec,
+    Vector& b_vec
+) {
+    auto& mut_a = as_mut(a);
+    auto& mut_x = as_mut(x);
+    auto& mut_b = as_mut(b);
+    mut_a.FormRectangularLinearSystem(trial_tdof_list, test_tdof_list, mut_x, mut_b, a_mat, x_vec, b_vec);
+}
+
+////////////////////////////
+// DiscreteLinearOperator //
+////////////////////////////
+
+auto DiscreteLinearOperator_ctor_fes(
+    FiniteElementSpace const& domain_fespace,
+    FiniteElementSpace const& range_fespace
+) -> std::unique_ptr<DiscreteLinearOperator> {
+    auto& mut_domain_fespace = as_mut(domain_fespace);
+    auto& mut_range_fespace = as_mut(range_fespace);
+    return std::make_unique<DiscreteLinearOperator>(&mut_domain_fespace, &mut_range_fespace);
+}
+
+auto DiscreteLinearOperator_AddDomainInterpolator(DiscreteLinearOperator& dlo, std::unique_ptr<DiscreteInterpolator> di) {
+    dlo.AddDomainInterpolator(di.release());
+}
+
+auto DiscreteLinearOperator_as_MixedBilinearForm(DiscreteLinearOperator const& dlo) -> MixedBilinearForm const& {
+    return dlo;
+}
+
+///////////////////
+// NonlinearForm //
+///////////////////
+
+auto NonlinearForm_ctor_fes(FiniteElementSpace const& fespace) -> std::unique_ptr<NonlinearForm> {
+    auto& mut_fespace = as_mut(fespace);
+    return std::make_unique<NonlinearForm>(&mut_fespace);
+}
+
+auto NonlinearForm_AddDomainIntegrator(NonlinearForm& nlf, std::unique_ptr<NonlinearFormIntegrator> nlfi) {
+    nlf.AddDomainIntegrator(nlfi.release());
+}
+
+auto NonlinearForm_Mult(NonlinearForm const& nlf, Vector const& x, Vector& y) -> void {
+    if (x.Size() != nlf.Width() || y.Size() != nlf.Height()) {
+        throw mfem_size_mismatch("NonlinearForm::Mult(): vector sizes don't match the true vector size");
+    }
+    nlf.Mult(x, y);
+}
+
+// Takes the form by mutable reference, because the returned matrix is overwritten by the next call.
+auto NonlinearForm_GetGradient(NonlinearForm& nlf, Vector const& x) -> SparseMatrix const& {
+    if (x.Size() != nlf.Width()) {
+        throw mfem_size_mismatch("NonlinearForm::GetGradient(): vector size doesn't match the true vector size");
+    }
+    auto grad = dynamic_cast<SparseMatrix*>(&nlf.GetGradient(x));
+    if (grad == nullptr) {
+        throw mfem_exception("NonlinearForm::GetGradient(): the gradient is not a SparseMatrix");
+    }
+    return *grad;
+}
+
+auto NonlinearForm_GetEnergy(NonlinearForm const& nlf, Vector const& x) -> double {
+    if (x.Size() != nlf.Width()) {
+        throw mfem_size_mismatch("NonlinearForm::GetEnergy(): vector size doesn't match the true vector size");
+    }
+    return nlf.GetEnergy(x);
+}
+
+auto NonlinearForm_as_Operator(NonlinearForm const& nlf) -> Operator const& {
+    return nlf;
+}
+
+/////////////////////////
+// DiffusionIntegrator //
 /////////////////////////
 
 auto DiffusionIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<DiffusionIntegrator> {
@@ -222,12 +1678,522 @@ auto DiffusionIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<Diffu
     return std::make_unique<DiffusionIntegrator>(mut_coeff);
 }
 
-auto DiffusionIntegrator_as_BFI(DiffusionIntegrator const& diffusion_bfi) -> BilinearFormIntegrator const& {
-    return diffusion_bfi;
+auto DiffusionIntegrator_ctor_matrix(MatrixCoefficient const& coeff) -> std::unique_ptr<DiffusionIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<DiffusionIntegrator>(mut_coeff);
+}
+
+auto DiffusionIntegrator_as_BFI(DiffusionIntegrator const& diffusion_bfi) -> BilinearFormIntegrator const& {
+    return diffusion_bfi;
+}
+
+auto DiffusionIntegrator_into_BFI(std::unique_ptr<DiffusionIntegrator> diffusion_bfi) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(diffusion_bfi);
+}
+
+////////////////////
+// MassIntegrator //
+////////////////////
+
+auto MassIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<MassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<MassIntegrator>(mut_coeff);
+}
+
+auto MassIntegrator_as_BFI(MassIntegrator const& mass_int) -> BilinearFormIntegrator const& {
+    return mass_int;
+}
+
+auto MassIntegrator_into_BFI(std::unique_ptr<MassIntegrator> mass_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(mass_int);
+}
+
+//////////////////////////
+// ConvectionIntegrator //
+//////////////////////////
+
+auto ConvectionIntegrator_ctor(VectorCoefficient const& velocity, double alpha) -> std::unique_ptr<ConvectionIntegrator> {
+    auto& mut_velocity = as_mut(velocity);
+    return std::make_unique<ConvectionIntegrator>(mut_velocity, alpha);
+}
+
+auto ConvectionIntegrator_as_BFI(ConvectionIntegrator const& convection_int) -> BilinearFormIntegrator const& {
+    return convection_int;
+}
+
+auto ConvectionIntegrator_into_BFI(std::unique_ptr<ConvectionIntegrator> convection_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(convection_int);
+}
+
+//////////////////////////
+// ElasticityIntegrator //
+//////////////////////////
+
+auto ElasticityIntegrator_ctor(Coefficient const& lambda, Coefficient const& mu) -> std::unique_ptr<ElasticityIntegrator> {
+    auto& mut_lambda = as_mut(lambda);
+    auto& mut_mu = as_mut(mu);
+    return std::make_unique<ElasticityIntegrator>(mut_lambda, mut_mu);
+}
+
+auto ElasticityIntegrator_as_BFI(ElasticityIntegrator const& elasticity_int) -> BilinearFormIntegrator const& {
+    return elasticity_int;
+}
+
+auto ElasticityIntegrator_into_BFI(std::unique_ptr<ElasticityIntegrator> elasticity_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(elasticity_int);
+}
+
+///////////////////////////////
+// VectorDiffusionIntegrator //
+///////////////////////////////
+
+auto VectorDiffusionIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<VectorDiffusionIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorDiffusionIntegrator>(mut_coeff);
+}
+
+auto VectorDiffusionIntegrator_ctor_vector(VectorCoefficient const& coeff) -> std::unique_ptr<VectorDiffusionIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorDiffusionIntegrator>(mut_coeff);
+}
+
+auto VectorDiffusionIntegrator_ctor_matrix(MatrixCoefficient const& coeff) -> std::unique_ptr<VectorDiffusionIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorDiffusionIntegrator>(mut_coeff);
+}
+
+auto VectorDiffusionIntegrator_as_BFI(VectorDiffusionIntegrator const& vector_diffusion_int) -> BilinearFormIntegrator const& {
+    return vector_diffusion_int;
+}
+
+auto VectorDiffusionIntegrator_into_BFI(std::unique_ptr<VectorDiffusionIntegrator> vector_diffusion_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(vector_diffusion_int);
+}
+
+//////////////////////////
+// VectorMassIntegrator //
+//////////////////////////
+
+auto VectorMassIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<VectorMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorMassIntegrator>(mut_coeff);
+}
+
+auto VectorMassIntegrator_ctor_vector(VectorCoefficient const& coeff) -> std::unique_ptr<VectorMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorMassIntegrator>(mut_coeff);
+}
+
+auto VectorMassIntegrator_ctor_matrix(MatrixCoefficient const& coeff) -> std::unique_ptr<VectorMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorMassIntegrator>(mut_coeff);
+}
+
+auto VectorMassIntegrator_as_BFI(VectorMassIntegrator const& vector_mass_int) -> BilinearFormIntegrator const& {
+    return vector_mass_int;
+}
+
+auto VectorMassIntegrator_into_BFI(std::unique_ptr<VectorMassIntegrator> vector_mass_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(vector_mass_int);
+}
+
+////////////////////////
+// CurlCurlIntegrator //
+////////////////////////
+
+auto CurlCurlIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<CurlCurlIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<CurlCurlIntegrator>(mut_coeff);
+}
+
+auto CurlCurlIntegrator_ctor_vector(VectorCoefficient const& coeff) -> std::unique_ptr<CurlCurlIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<CurlCurlIntegrator>(mut_coeff);
+}
+
+auto CurlCurlIntegrator_ctor_matrix(MatrixCoefficient const& coeff) -> std::unique_ptr<CurlCurlIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<CurlCurlIntegrator>(mut_coeff);
+}
+
+auto CurlCurlIntegrator_as_BFI(CurlCurlIntegrator const& curl_curl_int) -> BilinearFormIntegrator const& {
+    return curl_curl_int;
+}
+
+auto CurlCurlIntegrator_into_BFI(std::unique_ptr<CurlCurlIntegrator> curl_curl_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(curl_curl_int);
+}
+
+//////////////////////
+// DivDivIntegrator //
+//////////////////////
+
+auto DivDivIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<DivDivIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<DivDivIntegrator>(mut_coeff);
+}
+
+auto DivDivIntegrator_as_BFI(DivDivIntegrator const& div_div_int) -> BilinearFormIntegrator const& {
+    return div_div_int;
+}
+
+auto DivDivIntegrator_into_BFI(std::unique_ptr<DivDivIntegrator> div_div_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(div_div_int);
+}
+
+////////////////////////////
+// VectorFEMassIntegrator //
+////////////////////////////
+
+auto VectorFEMassIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<VectorFEMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorFEMassIntegrator>(mut_coeff);
+}
+
+auto VectorFEMassIntegrator_ctor_vector(VectorCoefficient const& coeff) -> std::unique_ptr<VectorFEMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorFEMassIntegrator>(mut_coeff);
+}
+
+auto VectorFEMassIntegrator_ctor_matrix(MatrixCoefficient const& coeff) -> std::unique_ptr<VectorFEMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorFEMassIntegrator>(mut_coeff);
+}
+
+auto VectorFEMassIntegrator_as_BFI(VectorFEMassIntegrator const& vector_femass_int) -> BilinearFormIntegrator const& {
+    return vector_femass_int;
+}
+
+auto VectorFEMassIntegrator_into_BFI(std::unique_ptr<VectorFEMassIntegrator> vector_femass_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(vector_femass_int);
+}
+
+////////////////////////////
+// BoundaryMassIntegrator //
+////////////////////////////
+
+auto BoundaryMassIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<BoundaryMassIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<BoundaryMassIntegrator>(mut_coeff);
+}
+
+auto BoundaryMassIntegrator_as_BFI(BoundaryMassIntegrator const& boundary_mass_int) -> BilinearFormIntegrator const& {
+    return boundary_mass_int;
+}
+
+auto BoundaryMassIntegrator_into_BFI(std::unique_ptr<BoundaryMassIntegrator> boundary_mass_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(boundary_mass_int);
+}
+
+///////////////////////////
+// DGDiffusionIntegrator //
+///////////////////////////
+
+auto DGDiffusionIntegrator_ctor(Coefficient const& coeff, double sigma, double kappa) -> std::unique_ptr<DGDiffusionIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<DGDiffusionIntegrator>(mut_coeff, sigma, kappa);
+}
+
+auto DGDiffusionIntegrator_ctor_matrix(MatrixCoefficient const& coeff, double sigma, double kappa) -> std::unique_ptr<DGDiffusionIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<DGDiffusionIntegrator>(mut_coeff, sigma, kappa);
+}
+
+auto DGDiffusionIntegrator_as_BFI(DGDiffusionIntegrator const& dgdiffusion_int) -> BilinearFormIntegrator const& {
+    return dgdiffusion_int;
+}
+
+auto DGDiffusionIntegrator_into_BFI(std::unique_ptr<DGDiffusionIntegrator> dgdiffusion_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(dgdiffusion_int);
+}
+
+///////////////////////
+// DGTraceIntegrator //
+///////////////////////
+
+auto DGTraceIntegrator_ctor(VectorCoefficient const& u, double alpha, double beta) -> std::unique_ptr<DGTraceIntegrator> {
+    auto& mut_u = as_mut(u);
+    return std::make_unique<DGTraceIntegrator>(mut_u, alpha, beta);
+}
+
+auto DGTraceIntegrator_ctor_rho(Coefficient const& rho, VectorCoefficient const& u, double alpha, double beta) -> std::unique_ptr<DGTraceIntegrator> {
+    auto& mut_rho = as_mut(rho);
+    auto& mut_u = as_mut(u);
+    return std::make_unique<DGTraceIntegrator>(mut_rho, mut_u, alpha, beta);
+}
+
+auto DGTraceIntegrator_as_BFI(DGTraceIntegrator const& dgtrace_int) -> BilinearFormIntegrator const& {
+    return dgtrace_int;
+}
+
+auto DGTraceIntegrator_into_BFI(std::unique_ptr<DGTraceIntegrator> dgtrace_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(dgtrace_int);
+}
+
+/////////////////////////
+// TransposeIntegrator //
+/////////////////////////
+
+auto TransposeIntegrator_ctor(std::unique_ptr<BilinearFormIntegrator> bfi) -> std::unique_ptr<TransposeIntegrator> {
+    // TransposeIntegrator takes ownership of bfi
+    return std::make_unique<TransposeIntegrator>(bfi.release());
+}
+
+auto TransposeIntegrator_as_BFI(TransposeIntegrator const& transpose_int) -> BilinearFormIntegrator const& {
+    return transpose_int;
+}
+
+auto TransposeIntegrator_into_BFI(std::unique_ptr<TransposeIntegrator> transpose_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(transpose_int);
+}
+
+/////////////////////////////////////
+// MixedScalarDivergenceIntegrator //
+/////////////////////////////////////
+
+auto MixedScalarDivergenceIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<MixedScalarDivergenceIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<MixedScalarDivergenceIntegrator>(mut_coeff);
+}
+
+auto MixedScalarDivergenceIntegrator_as_BFI(MixedScalarDivergenceIntegrator const& mixed_scalar_divergence_int) -> BilinearFormIntegrator const& {
+    return mixed_scalar_divergence_int;
+}
+
+auto MixedScalarDivergenceIntegrator_into_BFI(std::unique_ptr<MixedScalarDivergenceIntegrator> mixed_scalar_divergence_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(mixed_scalar_divergence_int);
+}
+
+//////////////////////////////////
+// VectorFEDivergenceIntegrator //
+//////////////////////////////////
+
+auto VectorFEDivergenceIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<VectorFEDivergenceIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<VectorFEDivergenceIntegrator>(mut_coeff);
+}
+
+auto VectorFEDivergenceIntegrator_as_BFI(VectorFEDivergenceIntegrator const& vector_fedivergence_int) -> BilinearFormIntegrator const& {
+    return vector_fedivergence_int;
+}
+
+auto VectorFEDivergenceIntegrator_into_BFI(std::unique_ptr<VectorFEDivergenceIntegrator> vector_fedivergence_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(vector_fedivergence_int);
+}
+
+////////////////////////
+// GradientIntegrator //
+////////////////////////
+
+auto GradientIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<GradientIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<GradientIntegrator>(mut_coeff);
+}
+
+auto GradientIntegrator_as_BFI(GradientIntegrator const& gradient_int) -> BilinearFormIntegrator const& {
+    return gradient_int;
+}
+
+auto GradientIntegrator_into_BFI(std::unique_ptr<GradientIntegrator> gradient_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(gradient_int);
+}
+
+///////////////////////////////////
+// MixedVectorGradientIntegrator //
+///////////////////////////////////
+
+auto MixedVectorGradientIntegrator_ctor(Coefficient const& coeff) -> std::unique_ptr<MixedVectorGradientIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<MixedVectorGradientIntegrator>(mut_coeff);
+}
+
+auto MixedVectorGradientIntegrator_ctor_vector(VectorCoefficient const& coeff) -> std::unique_ptr<MixedVectorGradientIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<MixedVectorGradientIntegrator>(mut_coeff);
+}
+
+auto MixedVectorGradientIntegrator_ctor_matrix(MatrixCoefficient const& coeff) -> std::unique_ptr<MixedVectorGradientIntegrator> {
+    auto& mut_coeff = as_mut(coeff);
+    return std::make_unique<MixedVectorGradientIntegrator>(mut_coeff);
+}
+
+auto MixedVectorGradientIntegrator_as_BFI(MixedVectorGradientIntegrator const& mixed_vector_gradient_int) -> BilinearFormIntegrator const& {
+    return mixed_vector_gradient_int;
+}
+
+auto MixedVectorGradientIntegrator_into_BFI(std::unique_ptr<MixedVectorGradientIntegrator> mixed_vector_gradient_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(mixed_vector_gradient_int);
+}
+
+//////////////////////////
+// GradientInterpolator //
+//////////////////////////
+
+auto GradientInterpolator_ctor() -> std::unique_ptr<GradientInterpolator> {
+    return std::make_unique<GradientInterpolator>();
+}
+
+auto GradientInterpolator_as_DI(GradientInterpolator const& gradient_interp) -> DiscreteInterpolator const& {
+    return gradient_interp;
+}
+
+auto GradientInterpolator_into_DI(std::unique_ptr<GradientInterpolator> gradient_interp) -> std::unique_ptr<DiscreteInterpolator> {
+    return std::move(gradient_interp);
+}
+
+//////////////////////
+// CurlInterpolator //
+//////////////////////
+
+auto CurlInterpolator_ctor() -> std::unique_ptr<CurlInterpolator> {
+    return std::make_unique<CurlInterpolator>();
+}
+
+auto CurlInterpolator_as_DI(CurlInterpolator const& curl_interp) -> DiscreteInterpolator const& {
+    return curl_interp;
+}
+
+auto CurlInterpolator_into_DI(std::unique_ptr<CurlInterpolator> curl_interp) -> std::unique_ptr<DiscreteInterpolator> {
+    return std::move(curl_interp);
+}
+
+////////////////////////////
+// DivergenceInterpolator //
+////////////////////////////
+
+auto DivergenceInterpolator_ctor() -> std::unique_ptr<DivergenceInterpolator> {
+    return std::make_unique<DivergenceInterpolator>();
+}
+
+auto DivergenceInterpolator_as_DI(DivergenceInterpolator const& divergence_interp) -> DiscreteInterpolator const& {
+    return divergence_interp;
+}
+
+auto DivergenceInterpolator_into_DI(std::unique_ptr<DivergenceInterpolator> divergence_interp) -> std::unique_ptr<DiscreteInterpolator> {
+    return std::move(divergence_interp);
+}
+
+//////////////////////////
+// IdentityInterpolator //
+//////////////////////////
+
+auto IdentityInterpolator_ctor() -> std::unique_ptr<IdentityInterpolator> {
+    return std::make_unique<IdentityInterpolator>();
+}
+
+auto IdentityInterpolator_as_DI(IdentityInterpolator const& identity_interp) -> DiscreteInterpolator const& {
+    return identity_interp;
+}
+
+auto IdentityInterpolator_into_DI(std::unique_ptr<IdentityInterpolator> identity_interp) -> std::unique_ptr<DiscreteInterpolator> {
+    return std::move(identity_interp);
+}
+
+////////////////////////////////
+// RustBilinearFormIntegrator //
+////////////////////////////////
+
+class RustBilinearFormIntegrator : public BilinearFormIntegrator {
+    RustBilinearIntegrator* integ_;
+
+public:
+    explicit RustBilinearFormIntegrator(RustBilinearIntegrator* integ) : integ_(integ) {}
+    RustBilinearFormIntegrator(RustBilinearFormIntegrator const&) = delete;
+    auto operator=(RustBilinearFormIntegrator const&) -> RustBilinearFormIntegrator& = delete;
+
+    ~RustBilinearFormIntegrator() override {
+        RustBilinearIntegrator_drop(integ_);
+    }
+
+    using BilinearFormIntegrator::AssembleElementMatrix;
+    using BilinearFormIntegrator::AssembleFaceMatrix;
+
+    // One row and column per shape function of `el`, zeroed before calling into Rust.
+    auto AssembleElementMatrix(
+        FiniteElement const& el,
+        ElementTransformation& trans,
+        DenseMatrix& elmat
+    ) -> void override {
+        auto size = el.GetDof();
+        elmat.SetSize(size);
+        elmat = 0.0;
+        RustBilinearIntegrator_AssembleElementMatrix(*integ_, el, trans, elmat);
+        check_size(elmat, size, "RustBilinearFormIntegrator::AssembleElementMatrix()");
+    }
+
+    // One row and column per shape function of `el1`, then of `el2` unless on the boundary,
+    // zeroed before calling into Rust.
+    auto AssembleFaceMatrix(
+        FiniteElement const& el1,
+        FiniteElement const& el2,
+        FaceElementTransformations& trans,
+        DenseMatrix& elmat
+    ) -> void override {
+        auto size = el1.GetDof() + (trans.Elem2No >= 0 ? el2.GetDof() : 0);
+        elmat.SetSize(size);
+        elmat = 0.0;
+        RustBilinearIntegrator_AssembleFaceMatrix(*integ_, el1, el2, trans, elmat);
+        check_size(elmat, size, "RustBilinearFormIntegrator::AssembleFaceMatrix()");
+    }
+
+private:
+    // MFEM adds `elmat` to the global matrix by the dofs of the element(s),
+    // and only checks its size in debug builds.
+    static auto check_size(DenseMatrix const& elmat, int size, char const* what) -> void {
+        if (elmat.Height() != size || elmat.Width() != size) {
+            throw mfem_size_mismatch(std::string(what) + ": the element matrix was resized");
+        }
+    }
+};
+
+auto BilinearForm_check_integrator(BilinearForm const& bf, BilinearFormIntegrator const& bfi) -> void {
+    auto is_rust = dynamic_cast<RustBilinearFormIntegrator const*>(&bfi) != nullptr;
+    if (is_rust && bf.FESpace()->GetVDim() != 1) {
+        throw mfem_invalid_argument("BilinearForm: Rust integrators need a FE space with a vector dimension of 1");
+    }
+}
+
+auto RustBilinearFormIntegrator_ctor(RustBilinearIntegrator* integ) -> std::unique_ptr<RustBilinearFormIntegrator> {
+    return std::make_unique<RustBilinearFormIntegrator>(integ);
 }
 
-auto DiffusionIntegrator_into_BFI(std::unique_ptr<DiffusionIntegrator> diffusion_bfi) -> std::unique_ptr<BilinearFormIntegrator> {
-    return std::move(diffusion_bfi);
+auto RustBilinearFormIntegrator_as_BFI(RustBilinearFormIntegrator const& rust_int) -> BilinearFormIntegrator const& {
+    return rust_int;
+}
+
+auto RustBilinearFormIntegrator_into_BFI(std::unique_ptr<RustBilinearFormIntegrator> rust_int) -> std::unique_ptr<BilinearFormIntegrator> {
+    return std::move(rust_int);
+}
+
+/////////////////////
+// NeoHookeanModel //
+/////////////////////
+
+auto NeoHookeanModel_ctor_coeff(Coefficient const& mu, Coefficient const& k) -> std::unique_ptr<NeoHookeanModel> {
+    auto& mut_mu = as_mut(mu);
+    auto& mut_k = as_mut(k);
+    return std::make_unique<NeoHookeanModel>(mut_mu, mut_k);
+}
+
+auto NeoHookeanModel_as_HM(NeoHookeanModel const& neo_hookean_model) -> HyperelasticModel const& {
+    return neo_hookean_model;
+}
+
+///////////////////////////////
+// HyperelasticNLFIntegrator //
+///////////////////////////////
+
+auto HyperelasticNLFIntegrator_ctor(HyperelasticModel const& model) -> std::unique_ptr<HyperelasticNLFIntegrator> {
+    auto& mut_model = as_mut(model);
+    return std::make_unique<HyperelasticNLFIntegrator>(&mut_model);
+}
+
+auto HyperelasticNLFIntegrator_as_NLFI(HyperelasticNLFIntegrator const& hyperelastic_nlfi) -> NonlinearFormIntegrator const& {
+    return hyperelastic_nlfi;
+}
+
+auto HyperelasticNLFIntegrator_into_NLFI(std::unique_ptr<HyperelasticNLFIntegrator> hyperelastic_nlfi) -> std::unique_ptr<NonlinearFormIntegrator> {
+    return std::move(hyperelastic_nlfi);
 }
 
 //////////////////
@@ -251,6 +2217,323 @@ auto OperatorHandle_try_as_SparseMatrix(OperatorHandle const& handle) -> SparseM
     return *handle.As<SparseMatrix>();
 }
 
+// Takes over the matrix if the handle owns it, and copies it otherwise.
+auto OperatorHandle_into_SparseMatrix(std::unique_ptr<OperatorHandle> handle) -> std::unique_ptr<SparseMatrix> {
+    if (handle->Type() != OperatorType::MFEM_SPARSEMAT) {
+        throw mfem_exception("OperatorHandle_into_SparseMatrix: wrong type");
+    }
+    auto mat = handle->As<SparseMatrix>();
+    if (!handle->OwnsOperator()) {
+        return std::make_unique<SparseMatrix>(*mat);
+    }
+    handle->SetOperatorOwner(false);
+    return std::unique_ptr<SparseMatrix>(mat);
+}
+
+//////////////
+// Operator //
+//////////////
+
+auto Operator_Mult(Operator const& op, Vector const& x, Vector& y) -> void {
+    if (x.Size() != op.Width() || y.Size() != op.Height()) {
+        throw mfem_size_mismatch("Operator::Mult(): vector sizes don't match the operator");
+    }
+    op.Mult(x, y);
+}
+
+auto Operator_MultTranspose(Operator const& op, Vector const& x, Vector& y) -> void {
+    if (x.Size() != op.Height() || y.Size() != op.Width()) {
+        throw mfem_size_mismatch("Operator::MultTranspose(): vector sizes don't match the operator");
+    }
+    op.MultTranspose(x, y);
+}
+
+auto Operator_try_as_SparseMatrix(Operator const& op) -> SparseMatrix const& {
+    auto mat = dynamic_cast<SparseMatrix const*>(&op);
+    if (mat == nullptr) {
+        throw mfem_exception("Operator_try_as_SparseMatrix: wrong type");
+    }
+    return *mat;
+}
+
+//////////////////
+// RustOperator //
+//////////////////
+
+class RustOperator : public Operator {
+    RustMult* mult_;
+
+public:
+    RustOperator(int height, int width, RustMult* mult) : Operator(height, width), mult_(mult) {}
+    RustOperator(RustOperator const&) = delete;
+    auto operator=(RustOperator const&) -> RustOperator& = delete;
+
+    ~RustOperator() override {
+        RustMult_drop(mult_);
+    }
+
+    auto Mult(Vector const& x, Vector& y) const -> void override {
+        if (x.Size() != Width() || y.Size() != Height()) {
+            throw mfem_size_mismatch("RustOperator::Mult(): vector sizes don't match the operator");
+        }
+        RustMult_Mult(
+            *mult_,
+            rust::Slice<const double>(x.GetData(), x.Size()),
+            rust::Slice<double>(y.GetData(), y.Size())
+        );
+    }
+
+    auto MultTranspose(Vector const& x, Vector& y) const -> void override {
+        if (x.Size() != Height() || y.Size() != Width()) {
+            throw mfem_size_mismatch("RustOperator::MultTranspose(): vector sizes don't match the operator");
+        }
+        RustMult_MultTranspose(
+            *mult_,
+            rust::Slice<const double>(x.GetData(), x.Size()),
+            rust::Slice<double>(y.GetData(), y.Size())
+        );
+    }
+};
+
+auto RustOperator_ctor(int height, int width, RustMult* mult) -> std::unique_ptr<RustOperator> {
+    return std::make_unique<RustOperator>(height, width, mult);
+}
+
+auto RustOperator_as_Operator(RustOperator const& op) -> Operator const& {
+    return op;
+}
+
+//////////////////
+// SparseMatrix //
+//////////////////
+
+auto SparseMatrix_as_Operator(SparseMatrix const& mat) -> Operator const& {
+    return mat;
+}
+
+// The CSR arrays only exist once the matrix is finalized.
+auto SparseMatrix_check_finalized(SparseMatrix const& mat, char const* what) -> void {
+    if (!mat.Finalized()) {
+        throw mfem_exception(std::string(what) + ": the matrix is not finalized");
+    }
+}
+
+auto SparseMatrix_GetI(SparseMatrix const& mat) -> rust::Slice<const int> {
+    SparseMatrix_check_finalized(mat, "SparseMatrix::GetI()");
+    return rust::Slice<const int>(mat.GetI(), mat.Height() + 1);
+}
+
+auto SparseMatrix_GetJ(SparseMatrix const& mat) -> rust::Slice<const int> {
+    SparseMatrix_check_finalized(mat, "SparseMatrix::GetJ()");
+    return rust::Slice<const int>(mat.GetJ(), mat.NumNonZeroElems());
+}
+
+auto SparseMatrix_GetData(SparseMatrix const& mat) -> rust::Slice<const double> {
+    SparseMatrix_check_finalized(mat, "SparseMatrix::GetData()");
+    return rust::Slice<const double>(mat.GetData(), mat.NumNonZeroElems());
+}
+
+///////////////////
+// BlockOperator //
+///////////////////
+
+// BlockOperator keeps a reference to the offsets, so they need to outlive it.
+auto BlockOperator_ctor_row_col_offsets(
+    ArrayInt const& row_offsets,
+    ArrayInt const& col_offsets
+) -> std::unique_ptr<BlockOperator> {
+    return std::make_unique<BlockOperator>(row_offsets, col_offsets);
+}
+
+auto BlockOperator_SetBlock(BlockOperator& bop, int i, int j, Operator const& op, double c) -> void {
+    if (i < 0 || i >= bop.NumRowBlocks() || j < 0 || j >= bop.NumColBlocks()) {
+        throw mfem_invalid_argument(
+            "BlockOperator::SetBlock(): no block (" + std::to_string(i) + ", " + std::to_string(j) + ")"
+        );
+    }
+    auto& row_offsets = bop.RowOffsets();
+    auto& col_offsets = bop.ColOffsets();
+    if (op.Height() != row_offsets[i + 1] - row_offsets[i] || op.Width() != col_offsets[j + 1] - col_offsets[j]) {
+        throw mfem_size_mismatch("BlockOperator::SetBlock(): operator size doesn't match the block offsets");
+    }
+    auto& mut_op = as_mut(op);
+    bop.SetBlock(i, j, &mut_op, c);
+}
+
+auto BlockOperator_as_Operator(BlockOperator const& bop) -> Operator const& {
+    return bop;
+}
+
+/////////////////////
+// ProductOperator //
+/////////////////////
+
+auto ProductOperator_ctor(Operator const& a, Operator const& b) -> std::unique_ptr<ProductOperator> {
+    if (a.Width() != b.Height()) {
+        throw mfem_size_mismatch("ProductOperator: the width of A doesn't match the height of B");
+    }
+    return std::make_unique<ProductOperator>(&a, &b, false, false);
+}
+
+auto ProductOperator_as_Operator(ProductOperator const& op) -> Operator const& {
+    return op;
+}
+
+///////////////////////
+// TransposeOperator //
+///////////////////////
+
+auto TransposeOperator_ctor(Operator const& a) -> std::unique_ptr<TransposeOperator> {
+    return std::make_unique<TransposeOperator>(&a);
+}
+
+auto TransposeOperator_as_Operator(TransposeOperator const& op) -> Operator const& {
+    return op;
+}
+
+/////////////////
+// SumOperator //
+/////////////////
+
+auto SumOperator_ctor(Operator const& a, double alpha, Operator const& b, double beta) -> std::unique_ptr<SumOperator> {
+    if (a.Height() != b.Height() || a.Width() != b.Width()) {
+        throw mfem_size_mismatch("SumOperator: the sizes of A and B don't match");
+    }
+    return std::make_unique<SumOperator>(&a, alpha, &b, beta, false, false);
+}
+
+auto SumOperator_as_Operator(SumOperator const& op) -> Operator const& {
+    return op;
+}
+
+////////////////////
+// ScaledOperator //
+////////////////////
+
+auto ScaledOperator_ctor(Operator const& a, double alpha) -> std::unique_ptr<ScaledOperator> {
+    return std::make_unique<ScaledOperator>(&a, alpha);
+}
+
+auto ScaledOperator_as_Operator(ScaledOperator const& op) -> Operator const& {
+    return op;
+}
+
+/////////////////
+// RAPOperator //
+/////////////////
+
+auto RAPOperator_ctor(Operator const& rt, Operator const& a, Operator const& p) -> std::unique_ptr<RAPOperator> {
+    if (rt.Height() != a.Height() || a.Width() != p.Height()) {
+        throw mfem_size_mismatch("RAPOperator: the sizes of Rt, A and P don't match");
+    }
+    return std::make_unique<RAPOperator>(rt, a, p);
+}
+
+auto RAPOperator_as_Operator(RAPOperator const& op) -> Operator const& {
+    return op;
+}
+
+///////////////////////////
+// TripleProductOperator //
+///////////////////////////
+
+auto TripleProductOperator_ctor(
+    Operator const& a,
+    Operator const& b,
+    Operator const& c
+) -> std::unique_ptr<TripleProductOperator> {
+    if (a.Width() != b.Height() || b.Width() != c.Height()) {
+        throw mfem_size_mismatch("TripleProductOperator: the sizes of A, B and C don't match");
+    }
+    return std::make_unique<TripleProductOperator>(&a, &b, &c, false, false, false);
+}
+
+auto TripleProductOperator_as_Operator(TripleProductOperator const& op) -> Operator const& {
+    return op;
+}
+
+/////////////////////////
+// ConstrainedOperator //
+/////////////////////////
+
+using DiagonalPolicy = Operator::DiagonalPolicy;
+
+// The list is referenced, not copied, by the constructor.
+auto ConstrainedOperator_ctor(
+    Operator const& a,
+    ArrayInt const& list,
+    DiagonalPolicy diag_policy
+) -> std::unique_ptr<ConstrainedOperator> {
+    if (a.Height() != a.Width()) {
+        throw mfem_invalid_argument("ConstrainedOperator: the operator is not square");
+    }
+    for (int i = 0; i < list.Size(); i++) {
+        if (list[i] < 0 || list[i] >= a.Height()) {
+            throw mfem_invalid_argument("ConstrainedOperator: constrained index out of range");
+        }
+    }
+    auto& mut_a = as_mut(a);
+    return std::make_unique<ConstrainedOperator>(&mut_a, list, false, diag_policy);
+}
+
+auto ConstrainedOperator_EliminateRHS(ConstrainedOperator const& cop, Vector const& x, Vector& b) -> void {
+    if (x.Size() != cop.Width() || b.Size() != cop.Height()) {
+        throw mfem_size_mismatch("ConstrainedOperator::EliminateRHS(): vector sizes don't match the operator");
+    }
+    cop.EliminateRHS(x, b);
+}
+
+auto ConstrainedOperator_as_Operator(ConstrainedOperator const& op) -> Operator const& {
+    return op;
+}
+
+////////////////
+// RustSolver //
+////////////////
+
+class RustSolver : public Solver {
+    RustSolve* solve_;
+
+public:
+    explicit RustSolver(RustSolve* solve) : solve_(solve) {}
+    RustSolver(RustSolver const&) = delete;
+    auto operator=(RustSolver const&) -> RustSolver& = delete;
+
+    ~RustSolver() override {
+        RustSolve_drop(solve_);
+    }
+
+    // As an approximate inverse, this maps the range of `op` to its domain.
+    auto SetOperator(Operator const& op) -> void override {
+        RustSolve_SetOperator(*solve_, op);
+        height = op.Width();
+        width = op.Height();
+    }
+
+    auto Mult(Vector const& x, Vector& y) const -> void override {
+        if (x.Size() != Width() || y.Size() != Height()) {
+            throw mfem_size_mismatch("RustSolver::Mult(): vector sizes don't match the operator");
+        }
+        RustSolve_Mult(
+            *solve_,
+            rust::Slice<const double>(x.GetData(), x.Size()),
+            rust::Slice<double>(y.GetData(), y.Size())
+        );
+    }
+};
+
+auto RustSolver_ctor(RustSolve* solve) -> std::unique_ptr<RustSolver> {
+    return std::make_unique<RustSolver>(solve);
+}
+
+auto RustSolver_as_Operator(RustSolver const& solver) -> Operator const& {
+    return solver;
+}
+
+auto RustSolver_as_mut_Solver(RustSolver& solver) -> Solver& {
+    return solver;
+}
+
 ////////////////
 // GSSmoother //
 ////////////////
@@ -258,3 +2541,224 @@ auto OperatorHandle_try_as_SparseMatrix(OperatorHandle const& handle) -> SparseM
 auto GSSmoother_as_mut_Solver(GSSmoother& smoother) -> Solver& {
     return smoother;
 }
+
+// A GSSmoother created without a matrix would dereference a null pointer in Mult().
+class GSSmoother_checked : public GSSmoother {
+  public:
+    using GSSmoother::GSSmoother;
+
+    void Mult(Vector const& x, Vector& y) const override {
+        if (oper == nullptr) {
+            throw mfem_exception("GSSmoother::Mult(): no matrix was set");
+        }
+        GSSmoother::Mult(x, y);
+    }
+};
+
+auto GSSmoother_ctor_type(int t, int it) -> std::unique_ptr<GSSmoother> {
+    return std::make_unique<GSSmoother_checked>(t, it);
+}
+
+auto GSSmoother_as_Operator(GSSmoother const& smoother) -> Operator const& {
+    return smoother;
+}
+
+/////////////////////////////////
+// BlockDiagonalPreconditioner //
+/////////////////////////////////
+
+// BlockDiagonalPreconditioner keeps a pointer to the offsets, so they need to outlive it.
+auto BlockDiagonalPreconditioner_ctor_offsets(ArrayInt const& offsets) -> std::unique_ptr<BlockDiagonalPreconditioner> {
+    return std::make_unique<BlockDiagonalPreconditioner>(offsets);
+}
+
+auto BlockDiagonalPreconditioner_SetDiagonalBlock(BlockDiagonalPreconditioner& bdp, int i, Operator const& op) -> void {
+    if (i < 0 || i >= bdp.NumBlocks()) {
+        throw mfem_invalid_argument("BlockDiagonalPreconditioner::SetDiagonalBlock(): no block " + std::to_string(i));
+    }
+    auto& offsets = bdp.Offsets();
+    if (op.Height() != offsets[i + 1] - offsets[i] || op.Width() != offsets[i + 1] - offsets[i]) {
+        throw mfem_size_mismatch("BlockDiagonalPreconditioner::SetDiagonalBlock(): operator size doesn't match the block offsets");
+    }
+    auto& mut_op = as_mut(op);
+    bdp.SetDiagonalBlock(i, &mut_op);
+}
+
+auto BlockDiagonalPreconditioner_as_Operator(BlockDiagonalPreconditioner const& bdp) -> Operator const& {
+    return bdp;
+}
+
+auto BlockDiagonalPreconditioner_as_mut_Solver(BlockDiagonalPreconditioner& bdp) -> Solver& {
+    return bdp;
+}
+
+/////////////////////
+// IterativeSolver //
+/////////////////////
+
+// Exposes the protected operator and preconditioner pointers, to throw instead of dereferencing nullptr.
+struct IterativeSolver_access : IterativeSolver {
+    using IterativeSolver::oper;
+    using IterativeSolver::prec;
+};
+
+auto IterativeSolver_SetPrintLevel(IterativeSolver& solver, int print_lvl) -> void {
+    solver.SetPrintLevel(print_lvl);
+}
+
+auto IterativeSolver_SetIterativeMode(IterativeSolver& solver, bool iterative_mode) -> void {
+    solver.iterative_mode = iterative_mode;
+}
+
+auto IterativeSolver_SetPreconditioner(IterativeSolver& solver, Solver& prec) -> void {
+    solver.SetPreconditioner(prec);
+}
+
+auto IterativeSolver_SetOperator(IterativeSolver& solver, Operator const& op) -> void {
+    if (op.Height() != op.Width()) {
+        throw mfem_size_mismatch("IterativeSolver::SetOperator(): the operator is not square");
+    }
+    solver.SetOperator(op);
+}
+
+auto IterativeSolver_Mult(IterativeSolver const& solver, Vector const& b, Vector& x) -> void {
+    if (solver.*(&IterativeSolver_access::oper) == nullptr) {
+        throw mfem_exception("IterativeSolver::Mult(): no operator was set");
+    }
+    if (dynamic_cast<NewtonSolver const*>(&solver) != nullptr && solver.*(&IterativeSolver_access::prec) == nullptr) {
+        throw mfem_exception("NewtonSolver::Mult(): no linear solver was set");
+    }
+    if (b.Size() != solver.Height() || x.Size() != solver.Width()) {
+        throw mfem_size_mismatch("IterativeSolver::Mult(): vector sizes don't match the operator");
+    }
+    solver.Mult(b, x);
+}
+
+////////////////////////////////
+// RustIterativeSolverMonitor //
+////////////////////////////////
+
+class RustIterativeSolverMonitor : public IterativeSolverMonitor {
+    RustSolverMonitor* monitor_;
+
+public:
+    explicit RustIterativeSolverMonitor(RustSolverMonitor* monitor) : monitor_(monitor) {}
+    RustIterativeSolverMonitor(RustIterativeSolverMonitor const&) = delete;
+    auto operator=(RustIterativeSolverMonitor const&) -> RustIterativeSolverMonitor& = delete;
+
+    ~RustIterativeSolverMonitor() override {
+        RustSolverMonitor_drop(monitor_);
+    }
+
+    // MFEM's solvers can't be told to stop, so this throws out of them instead.
+    auto MonitorResidual(int it, double norm, Vector const& r, bool final) -> void override {
+        auto stop = RustSolverMonitor_MonitorResidual(*monitor_, it, norm, r, final);
+        if (stop && !final) {
+            throw mfem_cancelled("the monitor stopped the solver at iteration " + std::to_string(it));
+        }
+    }
+};
+
+auto RustIterativeSolverMonitor_ctor(RustSolverMonitor* monitor) -> std::unique_ptr<RustIterativeSolverMonitor> {
+    return std::make_unique<RustIterativeSolverMonitor>(monitor);
+}
+
+auto IterativeSolver_SetMonitor(IterativeSolver& solver, RustIterativeSolverMonitor& monitor) -> void {
+    solver.SetMonitor(monitor);
+}
+
+//////////////
+// CGSolver //
+//////////////
+
+auto CGSolver_as_IterativeSolver(CGSolver const& solver) -> IterativeSolver const& {
+    return solver;
+}
+
+auto CGSolver_as_mut_IterativeSolver(CGSolver& solver) -> IterativeSolver& {
+    return solver;
+}
+
+auto CGSolver_as_mut_Solver(CGSolver& solver) -> Solver& {
+    return solver;
+}
+
+/////////////////
+// GMRESSolver //
+/////////////////
+
+auto GMRESSolver_as_IterativeSolver(GMRESSolver const& solver) -> IterativeSolver const& {
+    return solver;
+}
+
+auto GMRESSolver_as_mut_IterativeSolver(GMRESSolver& solver) -> IterativeSolver& {
+    return solver;
+}
+
+auto GMRESSolver_as_mut_Solver(GMRESSolver& solver) -> Solver& {
+    return solver;
+}
+
+//////////////////
+// FGMRESSolver //
+//////////////////
+
+auto FGMRESSolver_as_IterativeSolver(FGMRESSolver const& solver) -> IterativeSolver const& {
+    return solver;
+}
+
+auto FGMRESSolver_as_mut_IterativeSolver(FGMRESSolver& solver) -> IterativeSolver& {
+    return solver;
+}
+
+auto FGMRESSolver_as_mut_Solver(FGMRESSolver& solver) -> Solver& {
+    return solver;
+}
+
+////////////////////
+// BiCGSTABSolver //
+////////////////////
+
+auto BiCGSTABSolver_as_IterativeSolver(BiCGSTABSolver const& solver) -> IterativeSolver const& {
+    return solver;
+}
+
+auto BiCGSTABSolver_as_mut_IterativeSolver(BiCGSTABSolver& solver) -> IterativeSolver& {
+    return solver;
+}
+
+auto BiCGSTABSolver_as_mut_Solver(BiCGSTABSolver& solver) -> Solver& {
+    return solver;
+}
+
+//////////////////
+// MINRESSolver //
+//////////////////
+
+auto MINRESSolver_as_IterativeSolver(MINRESSolver const& solver) -> IterativeSolver const& {
+    return solver;
+}
+
+auto MINRESSolver_as_mut_IterativeSolver(MINRESSolver& solver) -> IterativeSolver& {
+    return solver;
+}
+
+auto MINRESSolver_as_mut_Solver(MINRESSolver& solver) -> Solver& {
+    return solver;
+}
+
+//////////////////
+// NewtonSolver //
+//////////////////
+
+auto NewtonSolver_as_IterativeSolver(NewtonSolver const& solver) -> IterativeSolver const& {
+    return solver;
+}
+
+auto NewtonSolver_as_mut_IterativeSolver(NewtonSolver& solver) -> IterativeSolver& {
+    return solver;
+}
+
+auto NewtonSolver_as_mut_Solver(NewtonSolver& solver) -> Solver& {
+    return solver;
+}
@@ -1,4 +1,6 @@
-#[allow(clippy::needless_lifetimes)]
+use std::pin::Pin;
+
+#[allow(clippy::needless_lifetimes, clippy::missing_safety_doc)]
 #[cxx::bridge]
 pub mod ffi {
     #[repr(i32)]
@@ -36,6 +38,19 @@ pub mod ffi {
         byVDIM,
     }
 
+    #[derive(Debug)]
+    #[repr(i32)]
+    enum ElementType {
+        POINT,
+        SEGMENT,
+        TRIANGLE,
+        QUADRILATERAL,
+        TETRAHEDRON,
+        HEXAHEDRON,
+        WEDGE,
+        PYRAMID,
+    }
+
     #[derive(Debug)]
     #[repr(i32)]
     enum OperatorType {
@@ -71,6 +86,156 @@ pub mod ffi {
         MFEM_Block_Operator,
     }
 
+    #[derive(Debug)]
+    #[repr(i32)]
+    enum DiagonalPolicy {
+        /// Set the diagonal value to zero
+        DIAG_ZERO,
+        /// Set the diagonal value to one
+        DIAG_ONE,
+        /// Keep the diagonal value
+        DIAG_KEEP,
+    }
+
+    extern "Rust" {
+        //////////////
+        // RustRead //
+        //////////////
+
+        type RustRead<'a>;
+
+        fn RustRead_read(reader: &mut RustRead, buf: &mut [u8]) -> usize;
+
+        ///////////////
+        // RustWrite //
+        ///////////////
+
+        type RustWrite<'a>;
+
+        fn RustWrite_write_all(writer: &mut RustWrite, buf: &[u8]) -> bool;
+        fn RustWrite_flush(writer: &mut RustWrite) -> bool;
+
+        //////////////////
+        // RustFunction //
+        //////////////////
+
+        type RustFunction<'a>;
+
+        fn RustFunction_eval(func: &RustFunction, x: &[f64], t: f64) -> Result<f64>;
+
+        ////////////////////////
+        // RustVectorFunction //
+        ////////////////////////
+
+        type RustVectorFunction<'a>;
+
+        fn RustVectorFunction_eval(
+            func: &RustVectorFunction,
+            x: &[f64],
+            t: f64,
+            v: &mut [f64],
+        ) -> Result<()>;
+
+        ////////////////////////
+        // RustMatrixFunction //
+        ////////////////////////
+
+        type RustMatrixFunction<'a>;
+
+        fn RustMatrixFunction_eval(
+            func: &RustMatrixFunction,
+            x: &[f64],
+            t: f64,
+            k: &mut [f64],
+        ) -> Result<()>;
+
+        ///////////////////
+        // RustTransform //
+        ///////////////////
+
+        type RustTransform;
+
+        fn RustTransform_eval(func: &RustTransform, v1: f64, v2: f64) -> Result<f64>;
+
+        ////////////////////////////
+        // RustBilinearIntegrator //
+        ////////////////////////////
+
+        type RustBilinearIntegrator<'a>;
+
+        fn RustBilinearIntegrator_AssembleElementMatrix(
+            integ: &RustBilinearIntegrator,
+            el: &FiniteElement,
+            trans: Pin<&mut ElementTransformation>,
+            elmat: Pin<&mut DenseMatrix>,
+        ) -> Result<()>;
+
+        fn RustBilinearIntegrator_AssembleFaceMatrix(
+            integ: &RustBilinearIntegrator,
+            el1: &FiniteElement,
+            el2: &FiniteElement,
+            trans: Pin<&mut FaceElementTransformations>,
+            elmat: Pin<&mut DenseMatrix>,
+        ) -> Result<()>;
+
+        unsafe fn RustBilinearIntegrator_drop(integ: *mut RustBilinearIntegrator);
+
+        //////////////////////////
+        // RustLinearIntegrator //
+        //////////////////////////
+
+        type RustLinearIntegrator<'a>;
+
+        fn RustLinearIntegrator_AssembleRHSElementVect(
+            integ: &RustLinearIntegrator,
+            el: &FiniteElement,
+            trans: Pin<&mut ElementTransformation>,
+            elvect: &mut [f64],
+        ) -> Result<()>;
+
+        unsafe fn RustLinearIntegrator_drop(integ: *mut RustLinearIntegrator);
+
+        ///////////////////////
+        // RustSolverMonitor //
+        ///////////////////////
+
+        type RustSolverMonitor<'a>;
+
+        fn RustSolverMonitor_MonitorResidual(
+            monitor: &mut RustSolverMonitor,
+            it: i32,
+            norm: f64,
+            r: &Vector,
+            is_final: bool,
+        ) -> Result<bool>;
+
+        unsafe fn RustSolverMonitor_drop(monitor: *mut RustSolverMonitor);
+
+        //////////////
+        // RustMult //
+        //////////////
+
+        type RustMult<'a>;
+
+        fn RustMult_Mult(mult: &RustMult, x: &[f64], y: &mut [f64]) -> Result<()>;
+
+        fn RustMult_MultTranspose(mult: &RustMult, x: &[f64], y: &mut [f64]) -> Result<()>;
+
+        unsafe fn RustMult_drop(mult: *mut RustMult);
+
+        ///////////////
+        // RustSolve //
+        ///////////////
+
+        type RustSolve<'a>;
+
+        fn RustSolve_SetOperator(solve: &RustSolve, op: &Operator) -> Result<()>;
+
+        fn RustSolve_Mult(solve: &RustSolve, x: &[f64], y: &mut [f64]) -> Result<()>;
+
+        unsafe fn RustSolve_drop(solve: *mut RustSolve);
+    }
+
     unsafe extern "C++" {
         // https://github.com/dtolnay/cxx/issues/280
 
@@ -86,7 +251,9 @@ pub mod ffi {
         fn ArrayInt_ctor() -> UniquePtr<ArrayInt>;
 
         #[cxx_name = "construct_unique"]
-        fn ArrayInt_ctor_size(asize: i32) -> UniquePtr<ArrayInt>;
+        fn ArrayInt_ctor_size(asize: i32) -> Result<UniquePtr<ArrayInt>>;
+
+        fn ArrayInt_ctor_slice(values: &[i32]) -> UniquePtr<ArrayInt>;
 
         fn GetData(self: &ArrayInt) -> *const i32;
         fn Size(self: &ArrayInt) -> i32;
@@ -102,6 +269,54 @@ pub mod ffi {
         #[cxx_name = "construct_unique"]
         fn Vector_ctor() -> UniquePtr<Vector>;
 
+        #[cxx_name = "construct_unique"]
+        fn Vector_ctor_size(size: i32) -> UniquePtr<Vector>;
+
+        fn GetData(self: &Vector) -> *mut f64;
+        fn Size(self: &Vector) -> i32;
+        fn Norml2(self: &Vector) -> f64;
+        fn Normlinf(self: &Vector) -> f64;
+        fn Vector_SetAll(vector: Pin<&mut Vector>, value: f64);
+        fn Vector_Dot(x: &Vector, y: &Vector) -> Result<f64>;
+        fn Vector_AddAssign(x: Pin<&mut Vector>, y: &Vector) -> Result<()>;
+        fn Vector_SubAssign(x: Pin<&mut Vector>, y: &Vector) -> Result<()>;
+        fn Vector_MulAssign(x: Pin<&mut Vector>, c: f64);
+
+        /////////////////
+        // BlockVector //
+        /////////////////
+
+        type BlockVector;
+
+        fn BlockVector_ctor_offsets(offsets: &ArrayInt) -> UniquePtr<BlockVector>;
+
+        fn NumBlocks(self: &BlockVector) -> i32;
+        fn BlockVector_GetBlock(bv: &BlockVector, i: i32) -> &Vector;
+        fn BlockVector_GetBlock_mut(bv: Pin<&mut BlockVector>, i: i32) -> Pin<&mut Vector>;
+        fn BlockVector_as_Vector(bv: &BlockVector) -> &Vector;
+        fn BlockVector_as_mut_Vector(bv: Pin<&mut BlockVector>) -> Pin<&mut Vector>;
+
+        /////////////////
+        // DenseMatrix //
+        /////////////////
+
+        type DenseMatrix;
+
+        #[cxx_name = "construct_unique"]
+        fn DenseMatrix_ctor() -> UniquePtr<DenseMatrix>;
+
+        fn Data(self: &DenseMatrix) -> *mut f64;
+        fn Height(self: &DenseMatrix) -> i32;
+        fn Width(self: &DenseMatrix) -> i32;
+        fn DenseMatrix_SetSize(mat: Pin<&mut DenseMatrix>, h: i32, w: i32);
+        fn DenseMatrix_SetAll(mat: Pin<&mut DenseMatrix>, value: f64);
+        fn DenseMatrix_AddMult_a_AAt(
+            a: f64,
+            mat: &DenseMatrix,
+            aat: Pin<&mut DenseMatrix>,
+        ) -> Result<()>;
+        fn DenseMatrix_AddMult_a_VVt(a: f64, v: &Vector, vvt: Pin<&mut DenseMatrix>) -> Result<()>;
+
         /////////////////////////////
         // FiniteElementCollection //
         /////////////////////////////
@@ -121,33 +336,258 @@ pub mod ffi {
             p: i32,
             dim: i32,
             btype: /*BasisType*/ i32,
-        ) -> UniquePtr<H1_FECollection>;
+        ) -> Result<UniquePtr<H1_FECollection>>;
 
         fn H1_FECollection_as_FEC(h1_fec: &H1_FECollection) -> &FiniteElementCollection;
 
+        /////////////////////
+        // L2_FECollection //
+        /////////////////////
+
+        type L2_FECollection;
+
+        #[cxx_name = "construct_unique"]
+        fn L2_FECollection_ctor(
+            p: i32,
+            dim: i32,
+            btype: /*BasisType*/ i32,
+        ) -> Result<UniquePtr<L2_FECollection>>;
+
+        fn L2_FECollection_as_FEC(l2_fec: &L2_FECollection) -> &FiniteElementCollection;
+
+        /////////////////////
+        // ND_FECollection //
+        /////////////////////
+
+        type ND_FECollection;
+
+        #[cxx_name = "construct_unique"]
+        fn ND_FECollection_ctor(
+            p: i32,
+            dim: i32,
+            cb_type: /*BasisType*/ i32,
+            ob_type: /*BasisType*/ i32,
+        ) -> Result<UniquePtr<ND_FECollection>>;
+
+        fn ND_FECollection_as_FEC(nd_fec: &ND_FECollection) -> &FiniteElementCollection;
+
+        /////////////////////
+        // RT_FECollection //
+        /////////////////////
+
+        type RT_FECollection;
+
+        #[cxx_name = "construct_unique"]
+        fn RT_FECollection_ctor(
+            p: i32,
+            dim: i32,
+            cb_type: /*BasisType*/ i32,
+            ob_type: /*BasisType*/ i32,
+        ) -> Result<UniquePtr<RT_FECollection>>;
+
+        fn RT_FECollection_as_FEC(rt_fec: &RT_FECollection) -> &FiniteElementCollection;
+
+        ///////////////////
+        // FiniteElement //
+        ///////////////////
+
+        type FiniteElement;
+
+        fn GetDim(self: &FiniteElement) -> i32;
+        fn GetDof(self: &FiniteElement) -> i32;
+        fn GetOrder(self: &FiniteElement) -> i32;
+        fn FiniteElement_GetGeomType(fe: &FiniteElement) -> i32;
+
+        fn FiniteElement_CalcShape(
+            fe: &FiniteElement,
+            ip: &IntegrationPoint,
+            shape: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn FiniteElement_CalcDShape(
+            fe: &FiniteElement,
+            ip: &IntegrationPoint,
+            dshape: Pin<&mut DenseMatrix>,
+        ) -> Result<()>;
+
+        fn FiniteElement_CalcPhysShape(
+            fe: &FiniteElement,
+            trans: Pin<&mut ElementTransformation>,
+            shape: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn FiniteElement_CalcPhysDShape(
+            fe: &FiniteElement,
+            trans: Pin<&mut ElementTransformation>,
+            dshape: Pin<&mut DenseMatrix>,
+        ) -> Result<()>;
+
+        //////////////////////
+        // IntegrationPoint //
+        //////////////////////
+
+        type IntegrationPoint;
+
+        fn IntegrationPoint_weight(ip: &IntegrationPoint) -> f64;
+        fn IntegrationPoint_Get(ip: &IntegrationPoint, p: &mut [f64]);
+
+        /////////////////////
+        // IntegrationRule //
+        /////////////////////
+
+        type IntegrationRule;
+
+        fn GetNPoints(self: &IntegrationRule) -> i32;
+        fn IntegrationRule_IntPoint(ir: &IntegrationRule, i: i32) -> &IntegrationPoint;
+        fn IntRules_Get(geom: i32, order: i32) -> Result<&'static IntegrationRule>;
+
+        ///////////////////////////
+        // ElementTransformation //
+        ///////////////////////////
+
+        type ElementTransformation;
+
+        fn GetSpaceDim(self: &ElementTransformation) -> i32;
+        fn OrderW(self: &ElementTransformation) -> i32;
+        fn ElementTransformation_ElementNo(trans: &ElementTransformation) -> i32;
+        fn ElementTransformation_Attribute(trans: &ElementTransformation) -> i32;
+        fn ElementTransformation_GetGeometryType(trans: &ElementTransformation) -> i32;
+
+        fn ElementTransformation_SetIntPoint(
+            trans: Pin<&mut ElementTransformation>,
+            ip: &'static IntegrationPoint,
+        );
+
+        fn ElementTransformation_Weight(trans: Pin<&mut ElementTransformation>) -> f64;
+
+        fn ElementTransformation_Transform(
+            trans: Pin<&mut ElementTransformation>,
+            ip: &IntegrationPoint,
+            x: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        ////////////////////////////////
+        // FaceElementTransformations //
+        ////////////////////////////////
+
+        type FaceElementTransformations;
+
+        fn FaceElementTransformations_Elem1No(trans: &FaceElementTransformations) -> i32;
+        fn FaceElementTransformations_Elem2No(trans: &FaceElementTransformations) -> i32;
+        fn FaceElementTransformations_GetGeometryType(trans: &FaceElementTransformations) -> i32;
+
+        fn FaceElementTransformations_SetAllIntPoints(
+            trans: Pin<&mut FaceElementTransformations>,
+            face_ip: &'static IntegrationPoint,
+        );
+
+        fn FaceElementTransformations_GetElement1IntPoint(
+            trans: &FaceElementTransformations,
+        ) -> &IntegrationPoint;
+
+        fn FaceElementTransformations_GetElement2IntPoint(
+            trans: &FaceElementTransformations,
+        ) -> &IntegrationPoint;
+
+        fn FaceElementTransformations_as_mut_ElementTransformation(
+            trans: Pin<&mut FaceElementTransformations>,
+        ) -> Pin<&mut ElementTransformation>;
+
+        fn FaceElementTransformations_Normal(
+            trans: Pin<&mut FaceElementTransformations>,
+            nor: Pin<&mut Vector>,
+        ) -> Result<()>;
+
         //////////
         // Mesh //
         //////////
 
+        type ElementType;
+
         type Mesh;
 
         #[cxx_name = "construct_unique"]
         fn Mesh_ctor() -> UniquePtr<Mesh>;
 
-        #[cxx_name = "construct_unique"]
         fn Mesh_ctor_file(
             filename: &CxxString,
             generate_edges: i32,
             refine: i32,
             fix_orientation: bool,
-        ) -> UniquePtr<Mesh>;
+        ) -> Result<UniquePtr<Mesh>>;
+
+        #[cxx_name = "construct_unique"]
+        fn Mesh_ctor_sizes(
+            dim: i32,
+            num_vert: i32,
+            num_elem: i32,
+            num_bdr_elem: i32,
+            space_dim: i32,
+        ) -> Result<UniquePtr<Mesh>>;
+
+        fn Mesh_MakeCartesian1D(n: i32, sx: f64) -> Result<UniquePtr<Mesh>>;
+
+        #[allow(clippy::too_many_arguments)]
+        fn Mesh_MakeCartesian2D(
+            nx: i32,
+            ny: i32,
+            elem_type: ElementType,
+            generate_edges: bool,
+            sx: f64,
+            sy: f64,
+            sfc_ordering: bool,
+        ) -> Result<UniquePtr<Mesh>>;
+
+        #[allow(clippy::too_many_arguments)]
+        fn Mesh_MakeCartesian3D(
+            nx: i32,
+            ny: i32,
+            nz: i32,
+            elem_type: ElementType,
+            sx: f64,
+            sy: f64,
+            sz: f64,
+            sfc_ordering: bool,
+        ) -> Result<UniquePtr<Mesh>>;
+
+        fn Mesh_AddVertex(mesh: Pin<&mut Mesh>, coords: &[f64]) -> Result<i32>;
+        fn Mesh_AddSegment(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddTriangle(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddQuad(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddTet(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddWedge(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddHex(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddBdrPoint(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddBdrSegment(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddBdrTriangle(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn Mesh_AddBdrQuad(mesh: Pin<&mut Mesh>, vi: &[i32], attr: i32) -> Result<i32>;
+        fn FinalizeTopology(self: Pin<&mut Mesh>, generate_bdr: bool) -> Result<()>;
+        fn Finalize(self: Pin<&mut Mesh>, refine: bool, fix_orientation: bool) -> Result<()>;
 
         fn Dimension(self: &Mesh) -> i32;
+        fn SpaceDimension(self: &Mesh) -> i32;
+        fn GetNV(self: &Mesh) -> i32;
+        fn GetNBE(self: &Mesh) -> i32;
         fn GetNE(self: &Mesh) -> i32;
-        fn UniformRefinement(self: Pin<&mut Mesh>, ref_algo: i32);
+        fn UniformRefinement(self: Pin<&mut Mesh>, ref_algo: i32) -> Result<()>;
         fn Mesh_GetNodes(mesh: &Mesh) -> Result<&GridFunction>;
+        fn Mesh_attributes(mesh: &Mesh) -> &ArrayInt;
         fn Mesh_bdr_attributes(mesh: &Mesh) -> &ArrayInt;
-        fn Save(self: &Mesh, fname: &CxxString, precision: i32);
+        fn Mesh_GetBoundingBox(
+            mesh: &Mesh,
+            min: Pin<&mut Vector>,
+            max: Pin<&mut Vector>,
+            ref_: i32,
+        ) -> Result<()>;
+        fn Mesh_bdr_marker(mesh: &Mesh, bdr_attrs: &[i32]) -> Result<UniquePtr<ArrayInt>>;
+        fn Save(self: &Mesh, fname: &CxxString, precision: i32) -> Result<()>;
+        fn Mesh_ctor_reader(
+            reader: &mut RustRead,
+            generate_edges: i32,
+            refine: i32,
+            fix_orientation: bool,
+        ) -> Result<UniquePtr<Mesh>>;
+        fn Mesh_Print(mesh: &Mesh, writer: &mut RustWrite, precision: i32) -> Result<()>;
 
         ////////////////////////
         // FiniteElementSpace //
@@ -162,7 +602,7 @@ pub mod ffi {
             fec: &'fec FiniteElementCollection,
             vdim: i32,
             ordering: OrderingType,
-        ) -> UniquePtr<FiniteElementSpace<'mesh, 'fec>>;
+        ) -> Result<UniquePtr<FiniteElementSpace<'mesh, 'fec>>>;
 
         fn GetTrueVSize(self: &FiniteElementSpace) -> i32;
 
@@ -172,7 +612,7 @@ pub mod ffi {
             bdr_attr_is_ess: &ArrayInt,
             ess_tdof_list: Pin<&mut ArrayInt>,
             component: i32,
-        );
+        ) -> Result<()>;
 
         //////////////////
         // GridFunction //
@@ -188,16 +628,40 @@ pub mod ffi {
 
         fn GridFunction_ctor_fes<'fes>(
             fespace: &'fes FiniteElementSpace,
-        ) -> UniquePtr<GridFunction<'fes>>;
-
-        fn GridFunction_ProjectCoefficient(grid_func: Pin<&mut GridFunction>, coeff: &Coefficient);
+        ) -> Result<UniquePtr<GridFunction<'fes>>>;
+
+        fn GridFunction_ProjectCoefficient(
+            grid_func: Pin<&mut GridFunction>,
+            coeff: &Coefficient,
+        ) -> Result<()>;
+        fn GridFunction_ProjectVectorCoefficient(
+            grid_func: Pin<&mut GridFunction>,
+            coeff: &VectorCoefficient,
+        ) -> Result<()>;
+        fn GridFunction_ComputeL2Error(
+            grid_func: &GridFunction,
+            exsol: &Coefficient,
+        ) -> Result<f64>;
+        fn GridFunction_ComputeL2Error_vector(
+            grid_func: &GridFunction,
+            exsol: &VectorCoefficient,
+        ) -> Result<f64>;
 
         fn GridFunction_OwnFEC<'a>(
             grid_func: &'a GridFunction,
         ) -> Result<&'a FiniteElementCollection>;
         fn GridFunction_SetAll(grid_func: Pin<&mut GridFunction>, value: f64);
 
-        fn GridFunction_Save(grid_func: &GridFunction, fname: &CxxString, precision: i32);
+        fn GridFunction_Save(
+            grid_func: &GridFunction,
+            fname: &CxxString,
+            precision: i32,
+        ) -> Result<()>;
+        fn GridFunction_Print(
+            grid_func: &GridFunction,
+            writer: &mut RustWrite,
+            precision: i32,
+        ) -> Result<()>;
 
         ////////////////
         // LinearForm //
@@ -211,14 +675,43 @@ pub mod ffi {
 
         fn LinearForm_ctor_fes<'fes>(
             fespace: &'fes FiniteElementSpace,
-        ) -> UniquePtr<LinearForm<'fes>>;
+        ) -> Result<UniquePtr<LinearForm<'fes>>>;
 
         fn LinearForm_AddDomainIntegrator(
             lf: Pin<&mut LinearForm>,
             lfi: UniquePtr<LinearFormIntegrator>,
-        );
+        ) -> Result<()>;
+
+        fn LinearForm_AddBoundaryIntegrator(
+            lf: Pin<&mut LinearForm>,
+            lfi: UniquePtr<LinearFormIntegrator>,
+        ) -> Result<()>;
 
-        fn Assemble(self: Pin<&mut LinearForm>);
+        // NOTE: The returned marker must outlive `lf`, as it refers to it.
+        fn LinearForm_AddBoundaryIntegrator_attrs(
+            lf: Pin<&mut LinearForm>,
+            lfi: UniquePtr<LinearFormIntegrator>,
+            bdr_attrs: &[i32],
+        ) -> Result<UniquePtr<ArrayInt>>;
+
+        fn LinearForm_AddInteriorFaceIntegrator(
+            lf: Pin<&mut LinearForm>,
+            lfi: UniquePtr<LinearFormIntegrator>,
+        ) -> Result<()>;
+
+        fn LinearForm_AddBdrFaceIntegrator(
+            lf: Pin<&mut LinearForm>,
+            lfi: UniquePtr<LinearFormIntegrator>,
+        ) -> Result<()>;
+
+        // NOTE: The returned marker must outlive `lf`, as it refers to it.
+        fn LinearForm_AddBdrFaceIntegrator_attrs(
+            lf: Pin<&mut LinearForm>,
+            lfi: UniquePtr<LinearFormIntegrator>,
+            bdr_attrs: &[i32],
+        ) -> Result<UniquePtr<ArrayInt>>;
+
+        fn Assemble(self: Pin<&mut LinearForm>) -> Result<()>;
 
         /////////////////
         // Coefficient //
@@ -226,6 +719,9 @@ pub mod ffi {
 
         type Coefficient;
 
+        fn Coefficient_SetTime(coeff: &Coefficient, t: f64);
+        fn Coefficient_GetTime(coeff: &Coefficient) -> f64;
+
         /////////////////////////
         // ConstantCoefficient //
         /////////////////////////
@@ -237,117 +733,1255 @@ pub mod ffi {
 
         fn ConstantCoefficient_as_Coeff(coeff: &ConstantCoefficient) -> &Coefficient;
 
-        //////////////////////////
-        // LinearFormIntegrator //
-        //////////////////////////
+        /////////////////////////////
+        // RustFunctionCoefficient //
+        /////////////////////////////
 
-        type LinearFormIntegrator;
+        type RustFunctionCoefficient<'func>;
+
+        fn RustFunctionCoefficient_ctor<'func>(
+            func: &'func RustFunction,
+        ) -> UniquePtr<RustFunctionCoefficient<'func>>;
+
+        fn RustFunctionCoefficient_as_Coeff<'func, 'a>(
+            coeff: &'a RustFunctionCoefficient<'func>,
+        ) -> &'a Coefficient;
 
         ////////////////////////
-        // DomainLFIntegrator //
+        // PWConstCoefficient //
         ////////////////////////
 
-        type DomainLFIntegrator<'coeff>;
-
-        fn DomainLFIntegrator_ctor_ab<'coeff>(
-            coeff: &'coeff Coefficient,
-            a: i32,
-            b: i32,
-        ) -> UniquePtr<DomainLFIntegrator<'coeff>>;
+        type PWConstCoefficient;
 
-        fn DomainLFIntegrator_as_LFI<'coeff, 'a>(
-            domain_lfi: &'a DomainLFIntegrator<'coeff>,
-        ) -> &'a LinearFormIntegrator;
+        fn PWConstCoefficient_ctor(c: &[f64]) -> UniquePtr<PWConstCoefficient>;
 
-        fn DomainLFIntegrator_into_LFI<'coeff>(
-            domain_lfi: UniquePtr<DomainLFIntegrator<'coeff>>,
-        ) -> UniquePtr<LinearFormIntegrator>;
+        fn PWConstCoefficient_as_Coeff(coeff: &PWConstCoefficient) -> &Coefficient;
 
-        //////////////////
-        // BilinearForm //
-        //////////////////
+        ///////////////////
+        // PWCoefficient //
+        ///////////////////
 
-        type BilinearForm<'fes>;
+        type PWCoefficient<'coeff>;
 
-        fn BilinearForm_ctor_fes<'fes>(
-            fespace: &'fes FiniteElementSpace,
-        ) -> UniquePtr<BilinearForm<'fes>>;
+        #[cxx_name = "construct_unique"]
+        fn PWCoefficient_ctor<'coeff>() -> UniquePtr<PWCoefficient<'coeff>>;
 
-        fn BilinearForm_AddDomainIntegrator(
-            bf: Pin<&mut BilinearForm>,
-            bfi: UniquePtr<BilinearFormIntegrator>,
+        fn PWCoefficient_UpdateCoefficient<'coeff>(
+            pw_coeff: Pin<&mut PWCoefficient<'coeff>>,
+            attr: i32,
+            coeff: &'coeff Coefficient,
         );
 
-        fn Assemble(self: Pin<&mut BilinearForm>, skip_zeros: i32);
+        fn PWCoefficient_as_Coeff<'coeff, 'a>(coeff: &'a PWCoefficient<'coeff>) -> &'a Coefficient;
 
-        fn BilinearForm_FormLinearSystem(
-            a: &BilinearForm,
-            ess_tdof_list: &ArrayInt,
-            x: &Vector,
-            b: &Vector,
-            a_mat: Pin<&mut OperatorHandle>,
-            x_vec: Pin<&mut Vector>,
-            b_vec: Pin<&mut Vector>,
-        );
+        ////////////////////
+        // SumCoefficient //
+        ////////////////////
 
-        fn RecoverFEMSolution(
-            self: Pin<&mut BilinearForm>,
-            x_vec: &Vector,
-            b_vec: &Vector,
-            x: Pin<&mut Vector>,
-        );
+        type SumCoefficient<'coeff>;
 
-        ////////////////////////////
-        // BilinearFormIntegrator //
-        ////////////////////////////
+        fn SumCoefficient_ctor<'coeff>(
+            a: &'coeff Coefficient,
+            b: &'coeff Coefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> UniquePtr<SumCoefficient<'coeff>>;
 
-        type BilinearFormIntegrator;
+        fn SumCoefficient_ctor_const<'coeff>(
+            a: f64,
+            b: &'coeff Coefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> UniquePtr<SumCoefficient<'coeff>>;
 
-        /////////////////////////
-        // DiffusionIntegrator //
-        /////////////////////////
+        fn SumCoefficient_as_Coeff<'coeff, 'a>(
+            coeff: &'a SumCoefficient<'coeff>,
+        ) -> &'a Coefficient;
 
-        type DiffusionIntegrator<'coeff>;
+        ////////////////////////
+        // ProductCoefficient //
+        ////////////////////////
 
-        fn DiffusionIntegrator_ctor<'coeff>(
-            coeff: &'coeff Coefficient,
-        ) -> UniquePtr<DiffusionIntegrator<'coeff>>;
+        type ProductCoefficient<'coeff>;
 
-        fn DiffusionIntegrator_as_BFI<'coeff, 'a>(
-            diffusion_int: &'a DiffusionIntegrator<'coeff>,
-        ) -> &'a BilinearFormIntegrator;
+        fn ProductCoefficient_ctor<'coeff>(
+            a: &'coeff Coefficient,
+            b: &'coeff Coefficient,
+        ) -> UniquePtr<ProductCoefficient<'coeff>>;
 
-        fn DiffusionIntegrator_into_BFI<'coeff>(
-            diffusion_int: UniquePtr<DiffusionIntegrator<'coeff>>,
-        ) -> UniquePtr<BilinearFormIntegrator>;
+        fn ProductCoefficient_ctor_const<'coeff>(
+            a: f64,
+            b: &'coeff Coefficient,
+        ) -> UniquePtr<ProductCoefficient<'coeff>>;
 
-        ////////////////////
-        // OperatorHandle //
-        ////////////////////
+        fn ProductCoefficient_as_Coeff<'coeff, 'a>(
+            coeff: &'a ProductCoefficient<'coeff>,
+        ) -> &'a Coefficient;
 
-        type OperatorHandle;
+        //////////////////////
+        // RatioCoefficient //
+        //////////////////////
 
-        #[cxx_name = "construct_unique"]
-        fn OperatorHandle_ctor() -> UniquePtr<OperatorHandle>;
+        type RatioCoefficient<'coeff>;
 
-        fn Type(self: &OperatorHandle) -> OperatorType;
-        // TODO(mkovaxx): Detect when the pointer inside is nullptr
-        fn OperatorHandle_as_ref(handle: &OperatorHandle) -> &Operator;
-        fn OperatorHandle_try_as_SparseMatrix(handle: &OperatorHandle) -> Result<&SparseMatrix>;
+        fn RatioCoefficient_ctor<'coeff>(
+            a: &'coeff Coefficient,
+            b: &'coeff Coefficient,
+        ) -> UniquePtr<RatioCoefficient<'coeff>>;
 
-        //////////////////
-        // OperatorType //
-        //////////////////
+        fn RatioCoefficient_ctor_const<'coeff>(
+            a: f64,
+            b: &'coeff Coefficient,
+        ) -> UniquePtr<RatioCoefficient<'coeff>>;
 
-        type OperatorType;
+        fn RatioCoefficient_as_Coeff<'coeff, 'a>(
+            coeff: &'a RatioCoefficient<'coeff>,
+        ) -> &'a Coefficient;
+
+        //////////////////////
+        // PowerCoefficient //
+        //////////////////////
+
+        type PowerCoefficient<'coeff>;
+
+        fn PowerCoefficient_ctor<'coeff>(
+            a: &'coeff Coefficient,
+            p: f64,
+        ) -> UniquePtr<PowerCoefficient<'coeff>>;
+
+        fn PowerCoefficient_as_Coeff<'coeff, 'a>(
+            coeff: &'a PowerCoefficient<'coeff>,
+        ) -> &'a Coefficient;
+
+        ////////////////////////////////
+        // RustTransformedCoefficient //
+        ////////////////////////////////
+
+        type RustTransformedCoefficient<'coeff>;
+
+        fn RustTransformedCoefficient_ctor<'coeff>(
+            q: &'coeff Coefficient,
+            func: &'coeff RustTransform,
+        ) -> UniquePtr<RustTransformedCoefficient<'coeff>>;
+
+        fn RustTransformedCoefficient_ctor2<'coeff>(
+            q1: &'coeff Coefficient,
+            q2: &'coeff Coefficient,
+            func: &'coeff RustTransform,
+        ) -> UniquePtr<RustTransformedCoefficient<'coeff>>;
+
+        fn RustTransformedCoefficient_as_Coeff<'coeff, 'a>(
+            coeff: &'a RustTransformedCoefficient<'coeff>,
+        ) -> &'a Coefficient;
+
+        ///////////////////////
+        // VectorCoefficient //
+        ///////////////////////
+
+        type VectorCoefficient;
+
+        fn VectorCoefficient_GetVDim(coeff: &VectorCoefficient) -> i32;
+        fn VectorCoefficient_SetTime(coeff: &VectorCoefficient, t: f64);
+        fn VectorCoefficient_GetTime(coeff: &VectorCoefficient) -> f64;
+
+        ///////////////////////////////
+        // VectorConstantCoefficient //
+        ///////////////////////////////
+
+        type VectorConstantCoefficient;
+
+        fn VectorConstantCoefficient_ctor(v: &[f64]) -> UniquePtr<VectorConstantCoefficient>;
+
+        fn VectorConstantCoefficient_as_VectorCoeff(
+            coeff: &VectorConstantCoefficient,
+        ) -> &VectorCoefficient;
+
+        ///////////////////////////////////
+        // RustVectorFunctionCoefficient //
+        ///////////////////////////////////
+
+        type RustVectorFunctionCoefficient<'func>;
+
+        fn RustVectorFunctionCoefficient_ctor<'func>(
+            vdim: i32,
+            func: &'func RustVectorFunction,
+        ) -> UniquePtr<RustVectorFunctionCoefficient<'func>>;
+
+        fn RustVectorFunctionCoefficient_as_VectorCoeff<'func, 'a>(
+            coeff: &'a RustVectorFunctionCoefficient<'func>,
+        ) -> &'a VectorCoefficient;
+
+        //////////////////////////
+        // VectorSumCoefficient //
+        //////////////////////////
+
+        type VectorSumCoefficient<'coeff>;
+
+        fn VectorSumCoefficient_ctor<'coeff>(
+            a: &'coeff VectorCoefficient,
+            b: &'coeff VectorCoefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> Result<UniquePtr<VectorSumCoefficient<'coeff>>>;
+
+        fn VectorSumCoefficient_as_VectorCoeff<'coeff, 'a>(
+            coeff: &'a VectorSumCoefficient<'coeff>,
+        ) -> &'a VectorCoefficient;
+
+        ////////////////////////////////////
+        // ScalarVectorProductCoefficient //
+        ////////////////////////////////////
+
+        type ScalarVectorProductCoefficient<'coeff>;
+
+        fn ScalarVectorProductCoefficient_ctor<'coeff>(
+            a: &'coeff Coefficient,
+            b: &'coeff VectorCoefficient,
+        ) -> UniquePtr<ScalarVectorProductCoefficient<'coeff>>;
+
+        fn ScalarVectorProductCoefficient_ctor_const<'coeff>(
+            a: f64,
+            b: &'coeff VectorCoefficient,
+        ) -> UniquePtr<ScalarVectorProductCoefficient<'coeff>>;
+
+        fn ScalarVectorProductCoefficient_as_VectorCoeff<'coeff, 'a>(
+            coeff: &'a ScalarVectorProductCoefficient<'coeff>,
+        ) -> &'a VectorCoefficient;
+
+        /////////////////////////////
+        // InnerProductCoefficient //
+        /////////////////////////////
+
+        type InnerProductCoefficient<'coeff>;
+
+        fn InnerProductCoefficient_ctor<'coeff>(
+            a: &'coeff VectorCoefficient,
+            b: &'coeff VectorCoefficient,
+        ) -> Result<UniquePtr<InnerProductCoefficient<'coeff>>>;
+
+        fn InnerProductCoefficient_as_Coeff<'coeff, 'a>(
+            coeff: &'a InnerProductCoefficient<'coeff>,
+        ) -> &'a Coefficient;
+
+        ///////////////////////
+        // MatrixCoefficient //
+        ///////////////////////
+
+        type MatrixCoefficient;
+
+        fn MatrixCoefficient_GetHeight(coeff: &MatrixCoefficient) -> i32;
+        fn MatrixCoefficient_GetWidth(coeff: &MatrixCoefficient) -> i32;
+        fn MatrixCoefficient_SetTime(coeff: &MatrixCoefficient, t: f64);
+        fn MatrixCoefficient_GetTime(coeff: &MatrixCoefficient) -> f64;
+
+        ///////////////////////////////
+        // MatrixConstantCoefficient //
+        ///////////////////////////////
+
+        type MatrixConstantCoefficient;
+
+        fn MatrixConstantCoefficient_ctor(
+            height: i32,
+            width: i32,
+            data: &[f64],
+        ) -> Result<UniquePtr<MatrixConstantCoefficient>>;
+
+        fn MatrixConstantCoefficient_as_MatrixCoeff(
+            coeff: &MatrixConstantCoefficient,
+        ) -> &MatrixCoefficient;
+
+        ///////////////////////////////////
+        // RustMatrixFunctionCoefficient //
+        ///////////////////////////////////
+
+        type RustMatrixFunctionCoefficient<'func>;
+
+        fn RustMatrixFunctionCoefficient_ctor<'func>(
+            height: i32,
+            width: i32,
+            func: &'func RustMatrixFunction,
+        ) -> UniquePtr<RustMatrixFunctionCoefficient<'func>>;
+
+        fn RustMatrixFunctionCoefficient_as_MatrixCoeff<'func, 'a>(
+            coeff: &'a RustMatrixFunctionCoefficient<'func>,
+        ) -> &'a MatrixCoefficient;
+
+        //////////////////////////
+        // MatrixSumCoefficient //
+        //////////////////////////
+
+        type MatrixSumCoefficient<'coeff>;
+
+        fn MatrixSumCoefficient_ctor<'coeff>(
+            a: &'coeff MatrixCoefficient,
+            b: &'coeff MatrixCoefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> Result<UniquePtr<MatrixSumCoefficient<'coeff>>>;
+
+        fn MatrixSumCoefficient_as_MatrixCoeff<'coeff, 'a>(
+            coeff: &'a MatrixSumCoefficient<'coeff>,
+        ) -> &'a MatrixCoefficient;
+
+        ////////////////////////////////////
+        // ScalarMatrixProductCoefficient //
+        ////////////////////////////////////
+
+        type ScalarMatrixProductCoefficient<'coeff>;
+
+        fn ScalarMatrixProductCoefficient_ctor<'coeff>(
+            a: &'coeff Coefficient,
+            b: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<ScalarMatrixProductCoefficient<'coeff>>;
+
+        fn ScalarMatrixProductCoefficient_ctor_const<'coeff>(
+            a: f64,
+            b: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<ScalarMatrixProductCoefficient<'coeff>>;
+
+        fn ScalarMatrixProductCoefficient_as_MatrixCoeff<'coeff, 'a>(
+            coeff: &'a ScalarMatrixProductCoefficient<'coeff>,
+        ) -> &'a MatrixCoefficient;
+
+        ////////////////////////////////////
+        // MatrixVectorProductCoefficient //
+        ////////////////////////////////////
+
+        type MatrixVectorProductCoefficient<'coeff>;
+
+        fn MatrixVectorProductCoefficient_ctor<'coeff>(
+            a: &'coeff MatrixCoefficient,
+            b: &'coeff VectorCoefficient,
+        ) -> Result<UniquePtr<MatrixVectorProductCoefficient<'coeff>>>;
+
+        fn MatrixVectorProductCoefficient_as_VectorCoeff<'coeff, 'a>(
+            coeff: &'a MatrixVectorProductCoefficient<'coeff>,
+        ) -> &'a VectorCoefficient;
+
+        //////////////////////////
+        // LinearFormIntegrator //
+        //////////////////////////
+
+        type LinearFormIntegrator;
+
+        ////////////////////////
+        // DomainLFIntegrator //
+        ////////////////////////
+
+        type DomainLFIntegrator<'coeff>;
+
+        fn DomainLFIntegrator_ctor_ab<'coeff>(
+            coeff: &'coeff Coefficient,
+            a: i32,
+            b: i32,
+        ) -> UniquePtr<DomainLFIntegrator<'coeff>>;
+
+        fn DomainLFIntegrator_as_LFI<'coeff, 'a>(
+            domain_lfi: &'a DomainLFIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn DomainLFIntegrator_into_LFI<'coeff>(
+            domain_lfi: UniquePtr<DomainLFIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        //////////////////////////
+        // BoundaryLFIntegrator //
+        //////////////////////////
+
+        type BoundaryLFIntegrator<'coeff>;
+
+        fn BoundaryLFIntegrator_ctor_ab<'coeff>(
+            coeff: &'coeff Coefficient,
+            a: i32,
+            b: i32,
+        ) -> UniquePtr<BoundaryLFIntegrator<'coeff>>;
+
+        fn BoundaryLFIntegrator_as_LFI<'coeff, 'a>(
+            boundary_lfi: &'a BoundaryLFIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn BoundaryLFIntegrator_into_LFI<'coeff>(
+            boundary_lfi: UniquePtr<BoundaryLFIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        ////////////////////////////////
+        // BoundaryNormalLFIntegrator //
+        ////////////////////////////////
+
+        type BoundaryNormalLFIntegrator<'coeff>;
+
+        fn BoundaryNormalLFIntegrator_ctor_ab<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+            a: i32,
+            b: i32,
+        ) -> UniquePtr<BoundaryNormalLFIntegrator<'coeff>>;
+
+        fn BoundaryNormalLFIntegrator_as_LFI<'coeff, 'a>(
+            boundary_normal_lfi: &'a BoundaryNormalLFIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn BoundaryNormalLFIntegrator_into_LFI<'coeff>(
+            boundary_normal_lfi: UniquePtr<BoundaryNormalLFIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        ////////////////////////////////
+        // VectorBoundaryLFIntegrator //
+        ////////////////////////////////
+
+        type VectorBoundaryLFIntegrator<'coeff>;
+
+        fn VectorBoundaryLFIntegrator_ctor<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<VectorBoundaryLFIntegrator<'coeff>>;
+
+        fn VectorBoundaryLFIntegrator_as_LFI<'coeff, 'a>(
+            vector_boundary_lfi: &'a VectorBoundaryLFIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn VectorBoundaryLFIntegrator_into_LFI<'coeff>(
+            vector_boundary_lfi: UniquePtr<VectorBoundaryLFIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        /////////////////////////////
+        // DGDirichletLFIntegrator //
+        /////////////////////////////
+
+        type DGDirichletLFIntegrator<'coeff>;
+
+        fn DGDirichletLFIntegrator_ctor<'coeff>(
+            u_d: &'coeff Coefficient,
+            sigma: f64,
+            kappa: f64,
+        ) -> UniquePtr<DGDirichletLFIntegrator<'coeff>>;
+
+        fn DGDirichletLFIntegrator_ctor_q<'coeff>(
+            u_d: &'coeff Coefficient,
+            q: &'coeff Coefficient,
+            sigma: f64,
+            kappa: f64,
+        ) -> UniquePtr<DGDirichletLFIntegrator<'coeff>>;
+
+        fn DGDirichletLFIntegrator_as_LFI<'coeff, 'a>(
+            dg_dirichlet_lfi: &'a DGDirichletLFIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn DGDirichletLFIntegrator_into_LFI<'coeff>(
+            dg_dirichlet_lfi: UniquePtr<DGDirichletLFIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        ////////////////////////////
+        // BoundaryFlowIntegrator //
+        ////////////////////////////
+
+        type BoundaryFlowIntegrator<'coeff>;
+
+        fn BoundaryFlowIntegrator_ctor<'coeff>(
+            f: &'coeff Coefficient,
+            u: &'coeff VectorCoefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> UniquePtr<BoundaryFlowIntegrator<'coeff>>;
+
+        fn BoundaryFlowIntegrator_as_LFI<'coeff, 'a>(
+            boundary_flow_lfi: &'a BoundaryFlowIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn BoundaryFlowIntegrator_into_LFI<'coeff>(
+            boundary_flow_lfi: UniquePtr<BoundaryFlowIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        ////////////////////////////////
+        // VectorFEDomainLFIntegrator //
+        ////////////////////////////////
+
+        type VectorFEDomainLFIntegrator<'coeff>;
+
+        fn VectorFEDomainLFIntegrator_ctor<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<VectorFEDomainLFIntegrator<'coeff>>;
+
+        fn VectorFEDomainLFIntegrator_as_LFI<'coeff, 'a>(
+            vector_fe_domain_lfi: &'a VectorFEDomainLFIntegrator<'coeff>,
+        ) -> &'a LinearFormIntegrator;
+
+        fn VectorFEDomainLFIntegrator_into_LFI<'coeff>(
+            vector_fe_domain_lfi: UniquePtr<VectorFEDomainLFIntegrator<'coeff>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        //////////////////////////////
+        // RustLinearFormIntegrator //
+        //////////////////////////////
+
+        type RustLinearFormIntegrator<'a>;
+
+        /// Takes ownership of `integ`, which must come from `Box::into_raw`.
+        unsafe fn RustLinearFormIntegrator_ctor<'a>(
+            integ: *mut RustLinearIntegrator<'a>,
+        ) -> UniquePtr<RustLinearFormIntegrator<'a>>;
+
+        fn RustLinearFormIntegrator_as_LFI<'a, 'b>(
+            rust_lfi: &'b RustLinearFormIntegrator<'a>,
+        ) -> &'b LinearFormIntegrator;
+
+        fn RustLinearFormIntegrator_into_LFI<'a>(
+            rust_lfi: UniquePtr<RustLinearFormIntegrator<'a>>,
+        ) -> UniquePtr<LinearFormIntegrator>;
+
+        //////////////////
+        // BilinearForm //
+        //////////////////
+
+        type BilinearForm<'fes>;
+
+        fn BilinearForm_ctor_fes<'fes>(
+            fespace: &'fes FiniteElementSpace,
+        ) -> Result<UniquePtr<BilinearForm<'fes>>>;
+
+        fn BilinearForm_AddDomainIntegrator(
+            bf: Pin<&mut BilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> Result<()>;
+
+        fn BilinearForm_AddBoundaryIntegrator(
+            bf: Pin<&mut BilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> Result<()>;
+
+        // NOTE: The returned marker must outlive `bf`, as it refers to it.
+        fn BilinearForm_AddBoundaryIntegrator_attrs(
+            bf: Pin<&mut BilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+            bdr_attrs: &[i32],
+        ) -> Result<UniquePtr<ArrayInt>>;
+
+        fn BilinearForm_AddInteriorFaceIntegrator(
+            bf: Pin<&mut BilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> Result<()>;
+
+        fn BilinearForm_AddBdrFaceIntegrator(
+            bf: Pin<&mut BilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> Result<()>;
+
+        // NOTE: The returned marker must outlive `bf`, as it refers to it.
+        fn BilinearForm_AddBdrFaceIntegrator_attrs(
+            bf: Pin<&mut BilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+            bdr_attrs: &[i32],
+        ) -> Result<UniquePtr<ArrayInt>>;
+
+        fn Assemble(self: Pin<&mut BilinearForm>, skip_zeros: i32) -> Result<()>;
+
+        fn BilinearForm_Update(bf: Pin<&mut BilinearForm>);
+
+        fn Finalize(self: Pin<&mut BilinearForm>, skip_zeros: i32) -> Result<()>;
+
+        fn BilinearForm_Mult(bf: &BilinearForm, x: &Vector, y: Pin<&mut Vector>) -> Result<()>;
+
+        fn BilinearForm_SpMat<'a>(bf: &'a BilinearForm) -> Result<&'a SparseMatrix>;
+
+        fn BilinearForm_FormLinearSystem(
+            a: &BilinearForm,
+            ess_tdof_list: &ArrayInt,
+            x: &Vector,
+            b: &Vector,
+            a_mat: Pin<&mut OperatorHandle>,
+            x_vec: Pin<&mut Vector>,
+            b_vec: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn RecoverFEMSolution(
+            self: Pin<&mut BilinearForm>,
+            x_vec: &Vector,
+            b_vec: &Vector,
+            x: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn BilinearForm_RecoverFEMSolution(
+            bf: Pin<&mut BilinearForm>,
+            x_vec: &Vector,
+            b_vec: &Vector,
+            x: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        ///////////////////////
+        // MixedBilinearForm //
+        ///////////////////////
+
+        type MixedBilinearForm<'trial, 'test>;
+
+        fn MixedBilinearForm_ctor_fes<'trial, 'test>(
+            trial_fespace: &'trial FiniteElementSpace,
+            test_fespace: &'test FiniteElementSpace,
+        ) -> Result<UniquePtr<MixedBilinearForm<'trial, 'test>>>;
+
+        fn MixedBilinearForm_AddDomainIntegrator(
+            mbf: Pin<&mut MixedBilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> Result<()>;
+
+        fn MixedBilinearForm_AddBoundaryIntegrator(
+            mbf: Pin<&mut MixedBilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> Result<()>;
+
+        // NOTE: The returned marker must outlive `mbf`, as it refers to it.
+        fn MixedBilinearForm_AddBoundaryIntegrator_attrs(
+            mbf: Pin<&mut MixedBilinearForm>,
+            bfi: UniquePtr<BilinearFormIntegrator>,
+            bdr_attrs: &[i32],
+        ) -> Result<UniquePtr<ArrayInt>>;
+
+        fn Assemble(self: Pin<&mut MixedBilinearForm>, skip_zeros: i32) -> Result<()>;
+
+        fn MixedBilinearForm_Update(mbf: Pin<&mut MixedBilinearForm>);
+
+        fn Finalize(self: Pin<&mut MixedBilinearForm>, skip_zeros: i32) -> Result<()>;
+
+        fn MixedBilinearForm_Mult(
+            mbf: &MixedBilinearForm,
+            x: &Vector,
+            y: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn MixedBilinearForm_MultTranspose(
+            mbf: &MixedBilinearForm,
+            x: &Vector,
+            y: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn MixedBilinearForm_SpMat<'a>(mbf: &'a MixedBilinearForm) -> Result<&'a SparseMatrix>;
+
+        #[allow(clippy::too_many_arguments)]
+        fn MixedBilinearForm_FormRectangularLinearSystem(
+            a: &MixedBilinearForm,
+            trial_tdof_list: &ArrayInt,
+            test_tdof_list: &ArrayInt,
+            x: &Vector,
+            b: &Vector,
+            a_mat: Pin<&mut OperatorHandle>,
+            x_vec: Pin<&mut Vector>,
+            b_vec: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        ////////////////////////////
+        // DiscreteLinearOperator //
+        ////////////////////////////
+
+        type DiscreteLinearOperator<'domain, 'range>;
+
+        fn DiscreteLinearOperator_ctor_fes<'domain, 'range>(
+            domain_fespace: &'domain FiniteElementSpace,
+            range_fespace: &'range FiniteElementSpace,
+        ) -> Result<UniquePtr<DiscreteLinearOperator<'domain, 'range>>>;
+
+        fn DiscreteLinearOperator_AddDomainInterpolator(
+            dlo: Pin<&mut DiscreteLinearOperator>,
+            di: UniquePtr<DiscreteInterpolator>,
+        ) -> Result<()>;
+
+        fn Assemble(self: Pin<&mut DiscreteLinearOperator>, skip_zeros: i32) -> Result<()>;
+
+        fn Finalize(self: Pin<&mut DiscreteLinearOperator>, skip_zeros: i32) -> Result<()>;
+
+        fn DiscreteLinearOperator_as_MixedBilinearForm<'domain, 'range, 'a>(
+            dlo: &'a DiscreteLinearOperator<'domain, 'range>,
+        ) -> &'a MixedBilinearForm<'domain, 'range>;
+
+        ///////////////////
+        // NonlinearForm //
+        ///////////////////
+
+        type NonlinearForm<'fes>;
+
+        fn NonlinearForm_ctor_fes<'fes>(
+            fespace: &'fes FiniteElementSpace,
+        ) -> Result<UniquePtr<NonlinearForm<'fes>>>;
+
+        fn NonlinearForm_AddDomainIntegrator(
+            nlf: Pin<&mut NonlinearForm>,
+            nlfi: UniquePtr<NonlinearFormIntegrator>,
+        ) -> Result<()>;
+
+        fn SetEssentialTrueDofs(
+            self: Pin<&mut NonlinearForm>,
+            ess_tdof_list: &ArrayInt,
+        ) -> Result<()>;
+
+        fn NonlinearForm_Mult(nlf: &NonlinearForm, x: &Vector, y: Pin<&mut Vector>) -> Result<()>;
+
+        fn NonlinearForm_GetGradient<'a>(
+            nlf: Pin<&'a mut NonlinearForm>,
+            x: &Vector,
+        ) -> Result<&'a SparseMatrix>;
+
+        fn NonlinearForm_GetEnergy(nlf: &NonlinearForm, x: &Vector) -> Result<f64>;
+
+        fn NonlinearForm_as_Operator<'a>(nlf: &'a NonlinearForm) -> &'a Operator;
+
+        ////////////////////////////
+        // BilinearFormIntegrator //
+        ////////////////////////////
+
+        type BilinearFormIntegrator;
+
+        /////////////////////////
+        // DiffusionIntegrator //
+        /////////////////////////
+
+        type DiffusionIntegrator<'coeff>;
+
+        fn DiffusionIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<DiffusionIntegrator<'coeff>>;
+
+        fn DiffusionIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<DiffusionIntegrator<'coeff>>;
+
+        fn DiffusionIntegrator_as_BFI<'coeff, 'a>(
+            diffusion_int: &'a DiffusionIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn DiffusionIntegrator_into_BFI<'coeff>(
+            diffusion_int: UniquePtr<DiffusionIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ////////////////////
+        // MassIntegrator //
+        ////////////////////
+
+        type MassIntegrator<'coeff>;
+
+        fn MassIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<MassIntegrator<'coeff>>;
+
+        fn MassIntegrator_as_BFI<'coeff, 'a>(
+            mass_int: &'a MassIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn MassIntegrator_into_BFI<'coeff>(
+            mass_int: UniquePtr<MassIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        //////////////////////////
+        // ConvectionIntegrator //
+        //////////////////////////
+
+        type ConvectionIntegrator<'coeff>;
+
+        fn ConvectionIntegrator_ctor<'coeff>(
+            velocity: &'coeff VectorCoefficient,
+            alpha: f64,
+        ) -> UniquePtr<ConvectionIntegrator<'coeff>>;
+
+        fn ConvectionIntegrator_as_BFI<'coeff, 'a>(
+            convection_int: &'a ConvectionIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn ConvectionIntegrator_into_BFI<'coeff>(
+            convection_int: UniquePtr<ConvectionIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        //////////////////////////
+        // ElasticityIntegrator //
+        //////////////////////////
+
+        type ElasticityIntegrator<'coeff>;
+
+        fn ElasticityIntegrator_ctor<'coeff>(
+            lambda: &'coeff Coefficient,
+            mu: &'coeff Coefficient,
+        ) -> UniquePtr<ElasticityIntegrator<'coeff>>;
+
+        fn ElasticityIntegrator_as_BFI<'coeff, 'a>(
+            elasticity_int: &'a ElasticityIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn ElasticityIntegrator_into_BFI<'coeff>(
+            elasticity_int: UniquePtr<ElasticityIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ///////////////////////////////
+        // VectorDiffusionIntegrator //
+        ///////////////////////////////
+
+        type VectorDiffusionIntegrator<'coeff>;
+
+        fn VectorDiffusionIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<VectorDiffusionIntegrator<'coeff>>;
+
+        fn VectorDiffusionIntegrator_ctor_vector<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<VectorDiffusionIntegrator<'coeff>>;
+
+        fn VectorDiffusionIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<VectorDiffusionIntegrator<'coeff>>;
+
+        fn VectorDiffusionIntegrator_as_BFI<'coeff, 'a>(
+            vector_diffusion_int: &'a VectorDiffusionIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn VectorDiffusionIntegrator_into_BFI<'coeff>(
+            vector_diffusion_int: UniquePtr<VectorDiffusionIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        //////////////////////////
+        // VectorMassIntegrator //
+        //////////////////////////
+
+        type VectorMassIntegrator<'coeff>;
+
+        fn VectorMassIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<VectorMassIntegrator<'coeff>>;
+
+        fn VectorMassIntegrator_ctor_vector<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<VectorMassIntegrator<'coeff>>;
+
+        fn VectorMassIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<VectorMassIntegrator<'coeff>>;
+
+        fn VectorMassIntegrator_as_BFI<'coeff, 'a>(
+            vector_mass_int: &'a VectorMassIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn VectorMassIntegrator_into_BFI<'coeff>(
+            vector_mass_int: UniquePtr<VectorMassIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ////////////////////////
+        // CurlCurlIntegrator //
+        ////////////////////////
+
+        type CurlCurlIntegrator<'coeff>;
+
+        fn CurlCurlIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<CurlCurlIntegrator<'coeff>>;
+
+        fn CurlCurlIntegrator_ctor_vector<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<CurlCurlIntegrator<'coeff>>;
+
+        fn CurlCurlIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<CurlCurlIntegrator<'coeff>>;
+
+        fn CurlCurlIntegrator_as_BFI<'coeff, 'a>(
+            curl_curl_int: &'a CurlCurlIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn CurlCurlIntegrator_into_BFI<'coeff>(
+            curl_curl_int: UniquePtr<CurlCurlIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        //////////////////////
+        // DivDivIntegrator //
+        //////////////////////
+
+        type DivDivIntegrator<'coeff>;
+
+        fn DivDivIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<DivDivIntegrator<'coeff>>;
+
+        fn DivDivIntegrator_as_BFI<'coeff, 'a>(
+            div_div_int: &'a DivDivIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn DivDivIntegrator_into_BFI<'coeff>(
+            div_div_int: UniquePtr<DivDivIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ////////////////////////////
+        // VectorFEMassIntegrator //
+        ////////////////////////////
+
+        type VectorFEMassIntegrator<'coeff>;
+
+        fn VectorFEMassIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<VectorFEMassIntegrator<'coeff>>;
+
+        fn VectorFEMassIntegrator_ctor_vector<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<VectorFEMassIntegrator<'coeff>>;
+
+        fn VectorFEMassIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<VectorFEMassIntegrator<'coeff>>;
+
+        fn VectorFEMassIntegrator_as_BFI<'coeff, 'a>(
+            vector_femass_int: &'a VectorFEMassIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn VectorFEMassIntegrator_into_BFI<'coeff>(
+            vector_femass_int: UniquePtr<VectorFEMassIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ////////////////////////////
+        // BoundaryMassIntegrator //
+        ////////////////////////////
+
+        type BoundaryMassIntegrator<'coeff>;
+
+        fn BoundaryMassIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<BoundaryMassIntegrator<'coeff>>;
+
+        fn BoundaryMassIntegrator_as_BFI<'coeff, 'a>(
+            boundary_mass_int: &'a BoundaryMassIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn BoundaryMassIntegrator_into_BFI<'coeff>(
+            boundary_mass_int: UniquePtr<BoundaryMassIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ///////////////////////////
+        // DGDiffusionIntegrator //
+        ///////////////////////////
+
+        type DGDiffusionIntegrator<'coeff>;
+
+        fn DGDiffusionIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+            sigma: f64,
+            kappa: f64,
+        ) -> UniquePtr<DGDiffusionIntegrator<'coeff>>;
+
+        fn DGDiffusionIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+            sigma: f64,
+            kappa: f64,
+        ) -> UniquePtr<DGDiffusionIntegrator<'coeff>>;
+
+        fn DGDiffusionIntegrator_as_BFI<'coeff, 'a>(
+            dgdiffusion_int: &'a DGDiffusionIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn DGDiffusionIntegrator_into_BFI<'coeff>(
+            dgdiffusion_int: UniquePtr<DGDiffusionIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ///////////////////////
+        // DGTraceIntegrator //
+        ///////////////////////
+
+        type DGTraceIntegrator<'coeff>;
+
+        fn DGTraceIntegrator_ctor<'coeff>(
+            u: &'coeff VectorCoefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> UniquePtr<DGTraceIntegrator<'coeff>>;
+
+        fn DGTraceIntegrator_ctor_rho<'coeff>(
+            rho: &'coeff Coefficient,
+            u: &'coeff VectorCoefficient,
+            alpha: f64,
+            beta: f64,
+        ) -> UniquePtr<DGTraceIntegrator<'coeff>>;
+
+        fn DGTraceIntegrator_as_BFI<'coeff, 'a>(
+            dgtrace_int: &'a DGTraceIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn DGTraceIntegrator_into_BFI<'coeff>(
+            dgtrace_int: UniquePtr<DGTraceIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        /////////////////////////
+        // TransposeIntegrator //
+        /////////////////////////
+
+        type TransposeIntegrator<'coeff>;
+
+        fn TransposeIntegrator_ctor<'coeff>(
+            bfi: UniquePtr<BilinearFormIntegrator>,
+        ) -> UniquePtr<TransposeIntegrator<'coeff>>;
+
+        fn TransposeIntegrator_as_BFI<'coeff, 'a>(
+            transpose_int: &'a TransposeIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn TransposeIntegrator_into_BFI<'coeff>(
+            transpose_int: UniquePtr<TransposeIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        /////////////////////////////////////
+        // MixedScalarDivergenceIntegrator //
+        /////////////////////////////////////
+
+        type MixedScalarDivergenceIntegrator<'coeff>;
+
+        fn MixedScalarDivergenceIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<MixedScalarDivergenceIntegrator<'coeff>>;
+
+        fn MixedScalarDivergenceIntegrator_as_BFI<'coeff, 'a>(
+            mixed_scalar_divergence_int: &'a MixedScalarDivergenceIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn MixedScalarDivergenceIntegrator_into_BFI<'coeff>(
+            mixed_scalar_divergence_int: UniquePtr<MixedScalarDivergenceIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        //////////////////////////////////
+        // VectorFEDivergenceIntegrator //
+        //////////////////////////////////
+
+        type VectorFEDivergenceIntegrator<'coeff>;
+
+        fn VectorFEDivergenceIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<VectorFEDivergenceIntegrator<'coeff>>;
+
+        fn VectorFEDivergenceIntegrator_as_BFI<'coeff, 'a>(
+            vector_fedivergence_int: &'a VectorFEDivergenceIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn VectorFEDivergenceIntegrator_into_BFI<'coeff>(
+            vector_fedivergence_int: UniquePtr<VectorFEDivergenceIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ////////////////////////
+        // GradientIntegrator //
+        ////////////////////////
+
+        type GradientIntegrator<'coeff>;
+
+        fn GradientIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<GradientIntegrator<'coeff>>;
+
+        fn GradientIntegrator_as_BFI<'coeff, 'a>(
+            gradient_int: &'a GradientIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn GradientIntegrator_into_BFI<'coeff>(
+            gradient_int: UniquePtr<GradientIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        ///////////////////////////////////
+        // MixedVectorGradientIntegrator //
+        ///////////////////////////////////
+
+        type MixedVectorGradientIntegrator<'coeff>;
+
+        fn MixedVectorGradientIntegrator_ctor<'coeff>(
+            coeff: &'coeff Coefficient,
+        ) -> UniquePtr<MixedVectorGradientIntegrator<'coeff>>;
+
+        fn MixedVectorGradientIntegrator_ctor_vector<'coeff>(
+            coeff: &'coeff VectorCoefficient,
+        ) -> UniquePtr<MixedVectorGradientIntegrator<'coeff>>;
+
+        fn MixedVectorGradientIntegrator_ctor_matrix<'coeff>(
+            coeff: &'coeff MatrixCoefficient,
+        ) -> UniquePtr<MixedVectorGradientIntegrator<'coeff>>;
+
+        fn MixedVectorGradientIntegrator_as_BFI<'coeff, 'a>(
+            mixed_vector_gradient_int: &'a MixedVectorGradientIntegrator<'coeff>,
+        ) -> &'a BilinearFormIntegrator;
+
+        fn MixedVectorGradientIntegrator_into_BFI<'coeff>(
+            mixed_vector_gradient_int: UniquePtr<MixedVectorGradientIntegrator<'coeff>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        //////////////////////////
+        // DiscreteInterpolator //
+        //////////////////////////
+
+        type DiscreteInterpolator;
+
+        //////////////////////////
+        // GradientInterpolator //
+        //////////////////////////
+
+        type GradientInterpolator;
+
+        fn GradientInterpolator_ctor() -> UniquePtr<GradientInterpolator>;
+
+        fn GradientInterpolator_as_DI<'a>(
+            gradient_interp: &'a GradientInterpolator,
+        ) -> &'a DiscreteInterpolator;
+
+        fn GradientInterpolator_into_DI(
+            gradient_interp: UniquePtr<GradientInterpolator>,
+        ) -> UniquePtr<DiscreteInterpolator>;
+
+        //////////////////////
+        // CurlInterpolator //
+        //////////////////////
+
+        type CurlInterpolator;
+
+        fn CurlInterpolator_ctor() -> UniquePtr<CurlInterpolator>;
+
+        fn CurlInterpolator_as_DI<'a>(
+            curl_interp: &'a CurlInterpolator,
+        ) -> &'a DiscreteInterpolator;
+
+        fn CurlInterpolator_into_DI(
+            curl_interp: UniquePtr<CurlInterpolator>,
+        ) -> UniquePtr<DiscreteInterpolator>;
+
+        ////////////////////////////
+        // DivergenceInterpolator //
+        ////////////////////////////
+
+        type DivergenceInterpolator;
+
+        fn DivergenceInterpolator_ctor() -> UniquePtr<DivergenceInterpolator>;
+
+        fn DivergenceInterpolator_as_DI<'a>(
+            divergence_interp: &'a DivergenceInterpolator,
+        ) -> &'a DiscreteInterpolator;
+
+        fn DivergenceInterpolator_into_DI(
+            divergence_interp: UniquePtr<DivergenceInterpolator>,
+        ) -> UniquePtr<DiscreteInterpolator>;
+
+        //////////////////////////
+        // IdentityInterpolator //
+        //////////////////////////
+
+        type IdentityInterpolator;
+
+        fn IdentityInterpolator_ctor() -> UniquePtr<IdentityInterpolator>;
+
+        fn IdentityInterpolator_as_DI<'a>(
+            identity_interp: &'a IdentityInterpolator,
+        ) -> &'a DiscreteInterpolator;
+
+        fn IdentityInterpolator_into_DI(
+            identity_interp: UniquePtr<IdentityInterpolator>,
+        ) -> UniquePtr<DiscreteInterpolator>;
+
+        ////////////////////////////////
+        // RustBilinearFormIntegrator //
+        ////////////////////////////////
+
+        type RustBilinearFormIntegrator<'a>;
+
+        /// Takes ownership of `integ`, which must come from `Box::into_raw`.
+        unsafe fn RustBilinearFormIntegrator_ctor<'a>(
+            integ: *mut RustBilinearIntegrator<'a>,
+        ) -> UniquePtr<RustBilinearFormIntegrator<'a>>;
+
+        fn RustBilinearFormIntegrator_as_BFI<'a, 'b>(
+            rust_int: &'b RustBilinearFormIntegrator<'a>,
+        ) -> &'b BilinearFormIntegrator;
+
+        fn RustBilinearFormIntegrator_into_BFI<'a>(
+            rust_int: UniquePtr<RustBilinearFormIntegrator<'a>>,
+        ) -> UniquePtr<BilinearFormIntegrator>;
+
+        /////////////////////////////
+        // NonlinearFormIntegrator //
+        /////////////////////////////
+
+        type NonlinearFormIntegrator;
+
+        ///////////////////////
+        // HyperelasticModel //
+        ///////////////////////
+
+        type HyperelasticModel;
+
+        /////////////////////
+        // NeoHookeanModel //
+        /////////////////////
+
+        type NeoHookeanModel<'coeff>;
+
+        #[cxx_name = "construct_unique"]
+        fn NeoHookeanModel_ctor<'coeff>(mu: f64, k: f64) -> UniquePtr<NeoHookeanModel<'coeff>>;
+
+        fn NeoHookeanModel_ctor_coeff<'coeff>(
+            mu: &'coeff Coefficient,
+            k: &'coeff Coefficient,
+        ) -> UniquePtr<NeoHookeanModel<'coeff>>;
+
+        fn NeoHookeanModel_as_HM<'coeff, 'a>(
+            neo_hookean_model: &'a NeoHookeanModel<'coeff>,
+        ) -> &'a HyperelasticModel;
+
+        ///////////////////////////////
+        // HyperelasticNLFIntegrator //
+        ///////////////////////////////
+
+        type HyperelasticNLFIntegrator<'model>;
+
+        fn HyperelasticNLFIntegrator_ctor<'model>(
+            model: &'model HyperelasticModel,
+        ) -> UniquePtr<HyperelasticNLFIntegrator<'model>>;
+
+        fn HyperelasticNLFIntegrator_as_NLFI<'model, 'a>(
+            hyperelastic_nlfi: &'a HyperelasticNLFIntegrator<'model>,
+        ) -> &'a NonlinearFormIntegrator;
+
+        fn HyperelasticNLFIntegrator_into_NLFI<'model>(
+            hyperelastic_nlfi: UniquePtr<HyperelasticNLFIntegrator<'model>>,
+        ) -> UniquePtr<NonlinearFormIntegrator>;
+
+        ////////////////////
+        // OperatorHandle //
+        ////////////////////
+
+        type OperatorHandle;
+
+        #[cxx_name = "construct_unique"]
+        fn OperatorHandle_ctor() -> UniquePtr<OperatorHandle>;
+
+        fn Type(self: &OperatorHandle) -> OperatorType;
+        // TODO(mkovaxx): Detect when the pointer inside is nullptr
+        fn OperatorHandle_as_ref(handle: &OperatorHandle) -> &Operator;
+        fn OperatorHandle_try_as_SparseMatrix(handle: &OperatorHandle) -> Result<&SparseMatrix>;
+
+        fn OperatorHandle_into_SparseMatrix(
+            handle: UniquePtr<OperatorHandle>,
+        ) -> Result<UniquePtr<SparseMatrix>>;
+
+        //////////////////
+        // OperatorType //
+        //////////////////
+
+        type OperatorType;
 
         //////////////
         // Operator //
         //////////////
 
-        type Operator;
+        type Operator;
+
+        fn Height(self: &Operator) -> i32;
+        fn Width(self: &Operator) -> i32;
+        fn Operator_Mult(op: &Operator, x: &Vector, y: Pin<&mut Vector>) -> Result<()>;
+        fn Operator_MultTranspose(op: &Operator, x: &Vector, y: Pin<&mut Vector>) -> Result<()>;
+        fn Operator_try_as_SparseMatrix(op: &Operator) -> Result<&SparseMatrix>;
+
+        //////////////////
+        // RustOperator //
+        //////////////////
+
+        type RustOperator<'a>;
 
-        fn Height(self: &Operator) -> i32;
+        /// Takes ownership of `mult`, which must come from `Box::into_raw`.
+        unsafe fn RustOperator_ctor<'a>(
+            height: i32,
+            width: i32,
+            mult: *mut RustMult<'a>,
+        ) -> UniquePtr<RustOperator<'a>>;
+
+        fn RustOperator_as_Operator<'a, 'b>(op: &'b RustOperator<'a>) -> &'b Operator;
 
         //////////////////
         // SparseMatrix //
@@ -358,12 +1992,157 @@ pub mod ffi {
         #[cxx_name = "construct_unique"]
         fn SparseMatrix_ctor() -> UniquePtr<SparseMatrix>;
 
+        fn SparseMatrix_as_Operator(mat: &SparseMatrix) -> &Operator;
+        fn SparseMatrix_GetI(mat: &SparseMatrix) -> Result<&[i32]>;
+        fn SparseMatrix_GetJ(mat: &SparseMatrix) -> Result<&[i32]>;
+        fn SparseMatrix_GetData(mat: &SparseMatrix) -> Result<&[f64]>;
+
+        ///////////////////
+        // BlockOperator //
+        ///////////////////
+
+        type BlockOperator<'a>;
+
+        fn BlockOperator_ctor_row_col_offsets<'a>(
+            row_offsets: &ArrayInt,
+            col_offsets: &ArrayInt,
+        ) -> UniquePtr<BlockOperator<'a>>;
+
+        fn NumRowBlocks(self: &BlockOperator) -> i32;
+        fn NumColBlocks(self: &BlockOperator) -> i32;
+
+        fn BlockOperator_SetBlock<'a>(
+            bop: Pin<&mut BlockOperator<'a>>,
+            i: i32,
+            j: i32,
+            op: &'a Operator,
+            c: f64,
+        ) -> Result<()>;
+
+        fn BlockOperator_as_Operator<'a, 'b>(bop: &'b BlockOperator<'a>) -> &'b Operator;
+
+        /////////////////////
+        // ProductOperator //
+        /////////////////////
+
+        type ProductOperator<'a>;
+
+        fn ProductOperator_ctor<'a>(
+            a: &'a Operator,
+            b: &'a Operator,
+        ) -> Result<UniquePtr<ProductOperator<'a>>>;
+
+        fn ProductOperator_as_Operator<'a, 'b>(op: &'b ProductOperator<'a>) -> &'b Operator;
+
+        ///////////////////////
+        // TransposeOperator //
+        ///////////////////////
+
+        type TransposeOperator<'a>;
+
+        fn TransposeOperator_ctor<'a>(a: &'a Operator) -> UniquePtr<TransposeOperator<'a>>;
+
+        fn TransposeOperator_as_Operator<'a, 'b>(op: &'b TransposeOperator<'a>) -> &'b Operator;
+
+        /////////////////
+        // SumOperator //
+        /////////////////
+
+        type SumOperator<'a>;
+
+        fn SumOperator_ctor<'a>(
+            a: &'a Operator,
+            alpha: f64,
+            b: &'a Operator,
+            beta: f64,
+        ) -> Result<UniquePtr<SumOperator<'a>>>;
+
+        fn SumOperator_as_Operator<'a, 'b>(op: &'b SumOperator<'a>) -> &'b Operator;
+
+        ////////////////////
+        // ScaledOperator //
+        ////////////////////
+
+        type ScaledOperator<'a>;
+
+        fn ScaledOperator_ctor<'a>(a: &'a Operator, alpha: f64) -> UniquePtr<ScaledOperator<'a>>;
+
+        fn ScaledOperator_as_Operator<'a, 'b>(op: &'b ScaledOperator<'a>) -> &'b Operator;
+
+        /////////////////
+        // RAPOperator //
+        /////////////////
+
+        type RAPOperator<'a>;
+
+        fn RAPOperator_ctor<'a>(
+            rt: &'a Operator,
+            a: &'a Operator,
+            p: &'a Operator,
+        ) -> Result<UniquePtr<RAPOperator<'a>>>;
+
+        fn RAPOperator_as_Operator<'a, 'b>(op: &'b RAPOperator<'a>) -> &'b Operator;
+
+        ///////////////////////////
+        // TripleProductOperator //
+        ///////////////////////////
+
+        type TripleProductOperator<'a>;
+
+        fn TripleProductOperator_ctor<'a>(
+            a: &'a Operator,
+            b: &'a Operator,
+            c: &'a Operator,
+        ) -> Result<UniquePtr<TripleProductOperator<'a>>>;
+
+        fn TripleProductOperator_as_Operator<'a, 'b>(
+            op: &'b TripleProductOperator<'a>,
+        ) -> &'b Operator;
+
+        /////////////////////////
+        // ConstrainedOperator //
+        /////////////////////////
+
+        type DiagonalPolicy;
+
+        type ConstrainedOperator<'a>;
+
+        fn ConstrainedOperator_ctor<'a>(
+            a: &'a Operator,
+            list: &ArrayInt,
+            diag_policy: DiagonalPolicy,
+        ) -> Result<UniquePtr<ConstrainedOperator<'a>>>;
+
+        fn ConstrainedOperator_EliminateRHS(
+            cop: &ConstrainedOperator,
+            x: &Vector,
+            b: Pin<&mut Vector>,
+        ) -> Result<()>;
+
+        fn ConstrainedOperator_as_Operator<'a, 'b>(op: &'b ConstrainedOperator<'a>)
+            -> &'b Operator;
+
         ////////////
         // Solver //
         ////////////
 
         type Solver;
 
+        ////////////////
+        // RustSolver //
+        ////////////////
+
+        type RustSolver<'a>;
+
+        /// Takes ownership of `solve`, which must come from `Box::into_raw`.
+        unsafe fn RustSolver_ctor<'a>(solve: *mut RustSolve<'a>) -> UniquePtr<RustSolver<'a>>;
+
+        fn RustSolver_as_Operator<'a, 'b>(solver: &'b RustSolver<'a>) -> &'b Operator;
+
+        fn RustSolver_as_mut_Solver<'a, 'b>(
+            solver: Pin<&'b mut RustSolver<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
         ////////////////
         // GSSmoother //
         ////////////////
@@ -375,10 +2154,214 @@ pub mod ffi {
             a: &'mat SparseMatrix,
             t: i32,
             it: i32,
-        ) -> UniquePtr<GSSmoother<'mat>>;
+        ) -> Result<UniquePtr<GSSmoother<'mat>>>;
 
         fn GSSmoother_as_mut_Solver<'a>(smoother: Pin<&'a mut GSSmoother>) -> Pin<&'a mut Solver>;
 
+        fn GSSmoother_ctor_type<'mat>(t: i32, it: i32) -> UniquePtr<GSSmoother<'mat>>;
+
+        fn GSSmoother_as_Operator<'mat, 'a>(smoother: &'a GSSmoother<'mat>) -> &'a Operator;
+
+        /////////////////////////////////
+        // BlockDiagonalPreconditioner //
+        /////////////////////////////////
+
+        type BlockDiagonalPreconditioner<'a>;
+
+        fn BlockDiagonalPreconditioner_ctor_offsets<'a>(
+            offsets: &ArrayInt,
+        ) -> UniquePtr<BlockDiagonalPreconditioner<'a>>;
+
+        fn NumBlocks(self: &BlockDiagonalPreconditioner) -> i32;
+
+        fn BlockDiagonalPreconditioner_SetDiagonalBlock<'a>(
+            bdp: Pin<&mut BlockDiagonalPreconditioner<'a>>,
+            i: i32,
+            op: &'a Operator,
+        ) -> Result<()>;
+
+        fn BlockDiagonalPreconditioner_as_Operator<'a, 'b>(
+            bdp: &'b BlockDiagonalPreconditioner<'a>,
+        ) -> &'b Operator;
+
+        fn BlockDiagonalPreconditioner_as_mut_Solver<'a, 'b>(
+            bdp: Pin<&'b mut BlockDiagonalPreconditioner<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
+        /////////////////////
+        // IterativeSolver //
+        /////////////////////
+
+        type IterativeSolver;
+
+        fn SetRelTol(self: Pin<&mut IterativeSolver>, rtol: f64);
+        fn SetAbsTol(self: Pin<&mut IterativeSolver>, atol: f64);
+        fn SetMaxIter(self: Pin<&mut IterativeSolver>, max_it: i32);
+        fn IterativeSolver_SetPrintLevel(solver: Pin<&mut IterativeSolver>, print_lvl: i32);
+        fn IterativeSolver_SetIterativeMode(
+            solver: Pin<&mut IterativeSolver>,
+            iterative_mode: bool,
+        );
+        fn IterativeSolver_SetPreconditioner(
+            solver: Pin<&mut IterativeSolver>,
+            prec: Pin<&mut Solver>,
+        );
+        fn IterativeSolver_SetOperator(
+            solver: Pin<&mut IterativeSolver>,
+            op: &Operator,
+        ) -> Result<()>;
+        fn IterativeSolver_Mult(
+            solver: &IterativeSolver,
+            b: &Vector,
+            x: Pin<&mut Vector>,
+        ) -> Result<()>;
+        fn IterativeSolver_SetMonitor(
+            solver: Pin<&mut IterativeSolver>,
+            monitor: Pin<&mut RustIterativeSolverMonitor>,
+        );
+        fn GetConverged(self: &IterativeSolver) -> bool;
+        fn GetNumIterations(self: &IterativeSolver) -> i32;
+        fn GetFinalNorm(self: &IterativeSolver) -> f64;
+        fn GetInitialNorm(self: &IterativeSolver) -> f64;
+
+        ////////////////////////////////
+        // RustIterativeSolverMonitor //
+        ////////////////////////////////
+
+        type RustIterativeSolverMonitor<'a>;
+
+        /// Takes ownership of `monitor`, which must come from `Box::into_raw`.
+        unsafe fn RustIterativeSolverMonitor_ctor<'a>(
+            monitor: *mut RustSolverMonitor<'a>,
+        ) -> UniquePtr<RustIterativeSolverMonitor<'a>>;
+
+        //////////////
+        // CGSolver //
+        //////////////
+
+        type CGSolver<'a>;
+
+        #[cxx_name = "construct_unique"]
+        fn CGSolver_ctor<'a>() -> UniquePtr<CGSolver<'a>>;
+
+        fn CGSolver_as_IterativeSolver<'a, 'b>(solver: &'b CGSolver<'a>) -> &'b IterativeSolver;
+
+        fn CGSolver_as_mut_IterativeSolver<'a, 'b>(
+            solver: Pin<&'b mut CGSolver<'a>>,
+        ) -> Pin<&'b mut IterativeSolver>;
+
+        fn CGSolver_as_mut_Solver<'a, 'b>(solver: Pin<&'b mut CGSolver<'a>>)
+            -> Pin<&'b mut Solver>;
+
+        /////////////////
+        // GMRESSolver //
+        /////////////////
+
+        type GMRESSolver<'a>;
+
+        #[cxx_name = "construct_unique"]
+        fn GMRESSolver_ctor<'a>() -> UniquePtr<GMRESSolver<'a>>;
+
+        fn GMRESSolver_as_IterativeSolver<'a, 'b>(
+            solver: &'b GMRESSolver<'a>,
+        ) -> &'b IterativeSolver;
+
+        fn GMRESSolver_as_mut_IterativeSolver<'a, 'b>(
+            solver: Pin<&'b mut GMRESSolver<'a>>,
+        ) -> Pin<&'b mut IterativeSolver>;
+
+        fn GMRESSolver_as_mut_Solver<'a, 'b>(
+            solver: Pin<&'b mut GMRESSolver<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
+        fn SetKDim(self: Pin<&mut GMRESSolver>, dim: i32);
+
+        //////////////////
+        // FGMRESSolver //
+        //////////////////
+
+        type FGMRESSolver<'a>;
+
+        #[cxx_name = "construct_unique"]
+        fn FGMRESSolver_ctor<'a>() -> UniquePtr<FGMRESSolver<'a>>;
+
+        fn FGMRESSolver_as_IterativeSolver<'a, 'b>(
+            solver: &'b FGMRESSolver<'a>,
+        ) -> &'b IterativeSolver;
+
+        fn FGMRESSolver_as_mut_IterativeSolver<'a, 'b>(
+            solver: Pin<&'b mut FGMRESSolver<'a>>,
+        ) -> Pin<&'b mut IterativeSolver>;
+
+        fn FGMRESSolver_as_mut_Solver<'a, 'b>(
+            solver: Pin<&'b mut FGMRESSolver<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
+        fn SetKDim(self: Pin<&mut FGMRESSolver>, dim: i32);
+
+        ////////////////////
+        // BiCGSTABSolver //
+        ////////////////////
+
+        type BiCGSTABSolver<'a>;
+
+        #[cxx_name = "construct_unique"]
+        fn BiCGSTABSolver_ctor<'a>() -> UniquePtr<BiCGSTABSolver<'a>>;
+
+        fn BiCGSTABSolver_as_IterativeSolver<'a, 'b>(
+            solver: &'b BiCGSTABSolver<'a>,
+        ) -> &'b IterativeSolver;
+
+        fn BiCGSTABSolver_as_mut_IterativeSolver<'a, 'b>(
+            solver: Pin<&'b mut BiCGSTABSolver<'a>>,
+        ) -> Pin<&'b mut IterativeSolver>;
+
+        fn BiCGSTABSolver_as_mut_Solver<'a, 'b>(
+            solver: Pin<&'b mut BiCGSTABSolver<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
+        //////////////////
+        // MINRESSolver //
+        //////////////////
+
+        type MINRESSolver<'a>;
+
+        #[cxx_name = "construct_unique"]
+        fn MINRESSolver_ctor<'a>() -> UniquePtr<MINRESSolver<'a>>;
+
+        fn MINRESSolver_as_IterativeSolver<'a, 'b>(
+            solver: &'b MINRESSolver<'a>,
+        ) -> &'b IterativeSolver;
+
+        fn MINRESSolver_as_mut_IterativeSolver<'a, 'b>(
+            solver: Pin<&'b mut MINRESSolver<'a>>,
+        ) -> Pin<&'b mut IterativeSolver>;
+
+        fn MINRESSolver_as_mut_Solver<'a, 'b>(
+            solver: Pin<&'b mut MINRESSolver<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
+        //////////////////
+        // NewtonSolver //
+        //////////////////
+
+        type NewtonSolver<'a>;
+
+        #[cxx_name = "construct_unique"]
+        fn NewtonSolver_ctor<'a>() -> UniquePtr<NewtonSolver<'a>>;
+
+        fn NewtonSolver_as_IterativeSolver<'a, 'b>(
+            solver: &'b NewtonSolver<'a>,
+        ) -> &'b IterativeSolver;
+
+        fn NewtonSolver_as_mut_IterativeSolver<'a, 'b>(
+            solver: Pin<&'b mut NewtonSolver<'a>>,
+        ) -> Pin<&'b mut IterativeSolver>;
+
+        fn NewtonSolver_as_mut_Solver<'a, 'b>(
+            solver: Pin<&'b mut NewtonSolver<'a>>,
+        ) -> Pin<&'b mut Solver>;
+
         /////////
         // PCG //
         /////////
@@ -393,6 +2376,452 @@ pub mod ffi {
             max_num_iter: i32,
             rtolerance: f64,
             atolerance: f64,
-        );
+        ) -> Result<()>;
+    }
+}
+
+//////////////
+// RustRead //
+//////////////
+
+/// Lets C++ read from a [`std::io::Read`] through a `std::istream`.
+pub struct RustRead<'a> {
+    inner: &'a mut dyn std::io::Read,
+    error: Option<std::io::Error>,
+}
+
+impl<'a> RustRead<'a> {
+    pub fn new(inner: &'a mut dyn std::io::Read) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Take the first I/O error that the C++ side ran into, if any.
+    pub fn take_error(&mut self) -> Option<std::io::Error> {
+        self.error.take()
+    }
+}
+
+/// Returns 0 on end of stream as well as on error, which C++ treats as EOF.
+#[allow(non_snake_case)]
+fn RustRead_read(reader: &mut RustRead, buf: &mut [u8]) -> usize {
+    if reader.error.is_some() {
+        return 0;
+    }
+    loop {
+        match catch_io_panic("RustRead", || reader.inner.read(buf)) {
+            Ok(n) => return n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                reader.error = Some(e);
+                return 0;
+            }
+        }
+    }
+}
+
+///////////////
+// RustWrite //
+///////////////
+
+/// Lets C++ write to a [`std::io::Write`] through a `std::ostream`.
+pub struct RustWrite<'a> {
+    inner: &'a mut dyn std::io::Write,
+    error: Option<std::io::Error>,
+}
+
+impl<'a> RustWrite<'a> {
+    pub fn new(inner: &'a mut dyn std::io::Write) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Take the first I/O error that the C++ side ran into, if any.
+    pub fn take_error(&mut self) -> Option<std::io::Error> {
+        self.error.take()
+    }
+}
+
+/// Returns `false` on error, which C++ treats as a bad stream.
+#[allow(non_snake_case)]
+fn RustWrite_write_all(writer: &mut RustWrite, buf: &[u8]) -> bool {
+    if writer.error.is_some() {
+        return false;
+    }
+    match catch_io_panic("RustWrite", || writer.inner.write_all(buf)) {
+        Ok(()) => true,
+        Err(e) => {
+            writer.error = Some(e);
+            false
+        }
+    }
+}
+
+/// Returns `false` on error, which C++ treats as a bad stream.
+#[allow(non_snake_case)]
+fn RustWrite_flush(writer: &mut RustWrite) -> bool {
+    if writer.error.is_some() {
+        return false;
+    }
+    match catch_io_panic("RustWrite", || writer.inner.flush()) {
+        Ok(()) => true,
+        Err(e) => {
+            writer.error = Some(e);
+            false
+        }
+    }
+}
+
+/// Panics are caught here and recorded as I/O errors instead of unwinding across FFI.
+fn catch_io_panic<T>(what: &str, f: impl FnOnce() -> std::io::Result<T>) -> std::io::Result<T> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(std::io::Error::other(panic_message(what, payload))))
+}
+
+//////////////////
+// RustFunction //
+//////////////////
+
+/// A scalar function of space and time that C++ can evaluate, see `RustFunctionCoefficient`.
+pub struct RustFunction<'a>(Box<ScalarFn<'a>>);
+
+type ScalarFn<'a> = dyn Fn(&[f64], f64) -> f64 + 'a;
+
+impl<'a> RustFunction<'a> {
+    pub fn new(func: impl Fn(&[f64], f64) -> f64 + 'a) -> Self {
+        Self(Box::new(func))
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustFunction_eval(func: &RustFunction, x: &[f64], t: f64) -> Result<f64, String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (func.0)(x, t)))
+        .map_err(|payload| panic_message("RustFunction", payload))
+}
+
+////////////////////////
+// RustVectorFunction //
+////////////////////////
+
+/// A vector function of space and time that C++ can evaluate, see `RustVectorFunctionCoefficient`.
+pub struct RustVectorFunction<'a>(Box<VectorFn<'a>>);
+
+type VectorFn<'a> = dyn Fn(&[f64], f64, &mut [f64]) + 'a;
+
+impl<'a> RustVectorFunction<'a> {
+    pub fn new(func: impl Fn(&[f64], f64, &mut [f64]) + 'a) -> Self {
+        Self(Box::new(func))
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustVectorFunction_eval(
+    func: &RustVectorFunction,
+    x: &[f64],
+    t: f64,
+    v: &mut [f64],
+) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (func.0)(x, t, v)))
+        .map_err(|payload| panic_message("RustVectorFunction", payload))
+}
+
+////////////////////////
+// RustMatrixFunction //
+////////////////////////
+
+/// A matrix function of space and time that C++ can evaluate, see `RustMatrixFunctionCoefficient`.
+///
+/// The matrix entries are passed in column-major order, as stored by `DenseMatrix`.
+pub struct RustMatrixFunction<'a>(Box<MatrixFn<'a>>);
+
+type MatrixFn<'a> = dyn Fn(&[f64], f64, &mut [f64]) + 'a;
+
+impl<'a> RustMatrixFunction<'a> {
+    pub fn new(func: impl Fn(&[f64], f64, &mut [f64]) + 'a) -> Self {
+        Self(Box::new(func))
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustMatrixFunction_eval(
+    func: &RustMatrixFunction,
+    x: &[f64],
+    t: f64,
+    k: &mut [f64],
+) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (func.0)(x, t, k)))
+        .map_err(|payload| panic_message("RustMatrixFunction", payload))
+}
+
+///////////////////
+// RustTransform //
+///////////////////
+
+/// A function of one or two coefficient values that C++ can evaluate,
+/// see `RustTransformedCoefficient`.
+pub enum RustTransform {
+    Unary(fn(f64) -> f64),
+    Binary(fn(f64, f64) -> f64),
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustTransform_eval(func: &RustTransform, v1: f64, v2: f64) -> Result<f64, String> {
+    std::panic::catch_unwind(|| match *func {
+        RustTransform::Unary(func) => func(v1),
+        RustTransform::Binary(func) => func(v1, v2),
+    })
+    .map_err(|payload| panic_message("RustTransform", payload))
+}
+
+////////////////////////////
+// RustBilinearIntegrator //
+////////////////////////////
+
+/// Element and face matrix assembly that C++ can call, see `RustBilinearFormIntegrator`.
+pub struct RustBilinearIntegrator<'a> {
+    element: Box<ElementMatrixFn<'a>>,
+    face: Box<FaceMatrixFn<'a>>,
+}
+
+type ElementMatrixFn<'a> = dyn Fn(&ffi::FiniteElement, Pin<&mut ffi::ElementTransformation>, Pin<&mut ffi::DenseMatrix>)
+    + 'a;
+
+type FaceMatrixFn<'a> = dyn Fn(
+        &ffi::FiniteElement,
+        &ffi::FiniteElement,
+        Pin<&mut ffi::FaceElementTransformations>,
+        Pin<&mut ffi::DenseMatrix>,
+    ) + 'a;
+
+impl<'a> RustBilinearIntegrator<'a> {
+    pub fn new(
+        element: impl Fn(
+                &ffi::FiniteElement,
+                Pin<&mut ffi::ElementTransformation>,
+                Pin<&mut ffi::DenseMatrix>,
+            ) + 'a,
+        face: impl Fn(
+                &ffi::FiniteElement,
+                &ffi::FiniteElement,
+                Pin<&mut ffi::FaceElementTransformations>,
+                Pin<&mut ffi::DenseMatrix>,
+            ) + 'a,
+    ) -> Self {
+        Self {
+            element: Box::new(element),
+            face: Box::new(face),
+        }
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustBilinearIntegrator_AssembleElementMatrix(
+    integ: &RustBilinearIntegrator,
+    el: &ffi::FiniteElement,
+    trans: Pin<&mut ffi::ElementTransformation>,
+    elmat: Pin<&mut ffi::DenseMatrix>,
+) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (integ.element)(el, trans, elmat)
+    }))
+    .map_err(|payload| panic_message("RustBilinearIntegrator", payload))
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustBilinearIntegrator_AssembleFaceMatrix(
+    integ: &RustBilinearIntegrator,
+    el1: &ffi::FiniteElement,
+    el2: &ffi::FiniteElement,
+    trans: Pin<&mut ffi::FaceElementTransformations>,
+    elmat: Pin<&mut ffi::DenseMatrix>,
+) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (integ.face)(el1, el2, trans, elmat)
+    }))
+    .map_err(|payload| panic_message("RustBilinearIntegrator", payload))
+}
+
+/// Called by the destructor of the owning C++ `RustBilinearFormIntegrator`.
+#[allow(non_snake_case)]
+unsafe fn RustBilinearIntegrator_drop(integ: *mut RustBilinearIntegrator) {
+    drop(Box::from_raw(integ));
+}
+
+//////////////////////////
+// RustLinearIntegrator //
+//////////////////////////
+
+/// Element vector assembly that C++ can call, see `RustLinearFormIntegrator`.
+pub struct RustLinearIntegrator<'a>(Box<ElementVectorFn<'a>>);
+
+type ElementVectorFn<'a> =
+    dyn Fn(&ffi::FiniteElement, Pin<&mut ffi::ElementTransformation>, &mut [f64]) + 'a;
+
+impl<'a> RustLinearIntegrator<'a> {
+    pub fn new(
+        func: impl Fn(&ffi::FiniteElement, Pin<&mut ffi::ElementTransformation>, &mut [f64]) + 'a,
+    ) -> Self {
+        Self(Box::new(func))
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustLinearIntegrator_AssembleRHSElementVect(
+    integ: &RustLinearIntegrator,
+    el: &ffi::FiniteElement,
+    trans: Pin<&mut ffi::ElementTransformation>,
+    elvect: &mut [f64],
+) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (integ.0)(el, trans, elvect)
+    }))
+    .map_err(|payload| panic_message("RustLinearIntegrator", payload))
+}
+
+/// Called by the destructor of the owning C++ `RustLinearFormIntegrator`.
+#[allow(non_snake_case)]
+unsafe fn RustLinearIntegrator_drop(integ: *mut RustLinearIntegrator) {
+    drop(Box::from_raw(integ));
+}
+
+///////////////////////
+// RustSolverMonitor //
+///////////////////////
+
+/// Residual monitoring that C++ can call, see `RustIterativeSolverMonitor`.
+///
+/// The function returns whether to stop the solver.
+pub struct RustSolverMonitor<'a>(Box<SolverMonitorFn<'a>>);
+
+type SolverMonitorFn<'a> = dyn FnMut(i32, f64, &ffi::Vector, bool) -> bool + 'a;
+
+impl<'a> RustSolverMonitor<'a> {
+    pub fn new(func: impl FnMut(i32, f64, &ffi::Vector, bool) -> bool + 'a) -> Self {
+        Self(Box::new(func))
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustSolverMonitor_MonitorResidual(
+    monitor: &mut RustSolverMonitor,
+    it: i32,
+    norm: f64,
+    r: &ffi::Vector,
+    is_final: bool,
+) -> Result<bool, String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (monitor.0)(it, norm, r, is_final)
+    }))
+    .map_err(|payload| panic_message("RustSolverMonitor", payload))
+}
+
+/// Called by the destructor of the owning C++ `RustIterativeSolverMonitor`.
+#[allow(non_snake_case)]
+unsafe fn RustSolverMonitor_drop(monitor: *mut RustSolverMonitor) {
+    drop(Box::from_raw(monitor));
+}
+
+//////////////
+// RustMult //
+//////////////
+
+/// Operator multiplication that C++ can call, see `RustOperator`.
+pub struct RustMult<'a> {
+    mult: Box<MultFn<'a>>,
+    mult_transpose: Box<MultFn<'a>>,
+}
+
+type MultFn<'a> = dyn Fn(&[f64], &mut [f64]) + 'a;
+
+impl<'a> RustMult<'a> {
+    pub fn new(
+        mult: impl Fn(&[f64], &mut [f64]) + 'a,
+        mult_transpose: impl Fn(&[f64], &mut [f64]) + 'a,
+    ) -> Self {
+        Self {
+            mult: Box::new(mult),
+            mult_transpose: Box::new(mult_transpose),
+        }
+    }
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustMult_Mult(mult: &RustMult, x: &[f64], y: &mut [f64]) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (mult.mult)(x, y)))
+        .map_err(|payload| panic_message("RustMult", payload))
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustMult_MultTranspose(mult: &RustMult, x: &[f64], y: &mut [f64]) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (mult.mult_transpose)(x, y)))
+        .map_err(|payload| panic_message("RustMult", payload))
+}
+
+/// Called by the destructor of the owning C++ `RustOperator`.
+#[allow(non_snake_case)]
+unsafe fn RustMult_drop(mult: *mut RustMult) {
+    drop(Box::from_raw(mult));
+}
+
+///////////////
+// RustSolve //
+///////////////
+
+/// Solver callbacks that C++ can call, see `RustSolver`.
+pub struct RustSolve<'a> {
+    set_operator: Box<SetOperatorFn<'a>>,
+    mult: Box<MultFn<'a>>,
+}
+
+type SetOperatorFn<'a> = dyn Fn(&ffi::Operator) + 'a;
+
+impl<'a> RustSolve<'a> {
+    pub fn new(
+        set_operator: impl Fn(&ffi::Operator) + 'a,
+        mult: impl Fn(&[f64], &mut [f64]) + 'a,
+    ) -> Self {
+        Self {
+            set_operator: Box::new(set_operator),
+            mult: Box::new(mult),
+        }
     }
 }
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustSolve_SetOperator(solve: &RustSolve, op: &ffi::Operator) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (solve.set_operator)(op)))
+        .map_err(|payload| panic_message("RustSolve", payload))
+}
+
+/// Panics are caught here and thrown in C++ as exceptions instead of unwinding across FFI.
+#[allow(non_snake_case)]
+fn RustSolve_Mult(solve: &RustSolve, x: &[f64], y: &mut [f64]) -> Result<(), String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (solve.mult)(x, y)))
+        .map_err(|payload| panic_message("RustSolve", payload))
+}
+
+/// Called by the destructor of the owning C++ `RustSolver`.
+#[allow(non_snake_case)]
+unsafe fn RustSolve_drop(solve: *mut RustSolve) {
+    drop(Box::from_raw(solve));
+}
+
+fn panic_message(what: &str, payload: Box<dyn std::any::Any + Send>) -> String {
+    let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.as_str()
+    } else {
+        "Box<dyn Any>"
+    };
+    format!("{what} panicked: {msg}")
+}
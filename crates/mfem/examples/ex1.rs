@@ -17,9 +17,9 @@
 #[derive(Parser)]
 #[command(version)]
 struct Args {
-    /// Mesh file to use.
+    /// Mesh file to use. Defaults to a quadrilateral mesh of the unit square.
     #[arg(short, long = "mesh", value_name = "FILE")]
-    mesh_file: String,
+    mesh_file: Option<String>,
 
     /// Finite element order (polynomial degree) or -1 for isoparametric space.
     #[arg(short, long, default_value_t = 1)]
@@ -40,7 +40,10 @@ fn main() -> anyhow::Result<()> {
     // 3. Read the mesh from the given mesh file. We can handle triangular,
     //    quadrilateral, tetrahedral, hexahedral, surface and volume meshes with
     //    the same code.
-    let mut mesh = Mesh::from_file(&args.mesh_file)?;
+    let mut mesh = match &args.mesh_file {
+        Some(mesh_file) => Mesh::from_file(mesh_file)?,
+        None => Mesh::cartesian_2d(8, 8, ElementType::QUADRILATERAL, true, [1.0, 1.0], true)?,
+    };
     let dim = mesh.dimension();
     dbg!(dim);
     dbg!(mesh.get_num_elems());
@@ -52,7 +55,7 @@ fn main() -> anyhow::Result<()> {
     let ref_levels =
         f64::floor(f64::log2(50000.0 / mesh.get_num_elems() as f64) / dim as f64) as u32;
     for _ in 0..ref_levels {
-        mesh.uniform_refinement(RefAlgo::A);
+        mesh.uniform_refinement(RefAlgo::A)?;
     }
     dbg!(mesh.get_num_elems());
 
@@ -64,9 +67,9 @@ fn main() -> anyhow::Result<()> {
             args.order,
             dim,
             BasisType::GaussLobatto,
-        ))
+        )?)
     } else if mesh.get_nodes().is_none() {
-        Some(H1FeCollection::new(1, dim, BasisType::GaussLobatto))
+        Some(H1FeCollection::new(1, dim, BasisType::GaussLobatto)?)
     } else {
         None
     };
@@ -85,7 +88,7 @@ fn main() -> anyhow::Result<()> {
 
     dbg!(fec.get_name());
 
-    let fespace = FiniteElementSpace::new(&mesh, fec, 1, OrderingType::byNODES);
+    let fespace = FiniteElementSpace::new(&mesh, fec, 1, OrderingType::byNODES)?;
     println!(
         "Number of finite element unknowns: {}",
         fespace.get_true_vsize(),
@@ -97,43 +100,43 @@ fn main() -> anyhow::Result<()> {
     //    converting them to a list of true dofs.
     let mut ess_tdof_list = ArrayInt::new();
     if let Some(max_bdr_attr) = mesh.get_bdr_attributes().iter().max() {
-        let mut ess_bdr = ArrayInt::with_len(*max_bdr_attr as usize);
+        let mut ess_bdr = ArrayInt::with_len(*max_bdr_attr as usize)?;
         ess_bdr.set_all(1);
-        fespace.get_essential_true_dofs(&ess_bdr, &mut ess_tdof_list, None);
+        fespace.get_essential_true_dofs(&ess_bdr, &mut ess_tdof_list, None)?;
     }
 
     // 7. Set up the linear form b(.) which corresponds to the right-hand side of
     //    the FEM linear system, which in this case is (1,phi_i) where phi_i are
     //    the basis functions in the finite element fespace.
-    let mut b = LinearForm::new(&fespace);
     let one = ConstantCoefficient::new(1.0);
+    let mut b = LinearForm::new(&fespace)?;
     let integrator = DomainLFIntegrator::new(&one, 2, 0);
-    b.add_domain_integrator(integrator);
-    b.assemble();
+    b.add_domain_integrator(integrator)?;
+    b.assemble()?;
 
     // 8. Define the solution vector x as a finite element grid function
     //    corresponding to fespace. Initialize x with initial guess of zero,
     //    which satisfies the boundary conditions.
-    let mut x = GridFunction::new(&fespace);
+    let mut x = GridFunction::new(&fespace)?;
     x.set_all(0.0);
 
     // 9. Set up the bilinear form a(.,.) on the finite element space
     //    corresponding to the Laplacian operator -Delta, by adding the Diffusion
     //    domain integrator.
-    let mut a = BilinearForm::new(&fespace);
+    let mut a = BilinearForm::new(&fespace)?;
     let bf_integrator = DiffusionIntegrator::new(&one);
-    a.add_domain_integrator(bf_integrator);
+    a.add_domain_integrator(bf_integrator)?;
 
     // 10. Assemble the bilinear form and the corresponding linear system,
     //     applying any necessary transformations such as: eliminating boundary
     //     conditions, applying conforming constraints for non-conforming AMR,
     //     static condensation, etc.
-    a.assemble(true);
+    a.assemble(true)?;
 
     let mut a_mat = OperatorHandle::new();
     let mut b_vec = Vector::new();
     let mut x_vec = Vector::new();
-    a.form_linear_system(&ess_tdof_list, &x, &b, &mut a_mat, &mut x_vec, &mut b_vec);
+    a.form_linear_system(&ess_tdof_list, &x, &b, &mut a_mat, &mut x_vec, &mut b_vec)?;
 
     println!("Size of linear system: {}", a_mat.height());
     dbg!(a_mat.get_type());
@@ -141,16 +144,23 @@ fn main() -> anyhow::Result<()> {
     // 11. Solve the linear system A X = B.
     // Use a simple symmetric Gauss-Seidel preconditioner with PCG.
     let a_sparse = SparseMatrixRef::try_from(&a_mat).expect("Operator is a SparseMatrix");
-    let mut m_mat = GsSmoother::new(&a_sparse, 0, 1);
-    solve_with_pcg(&a_mat, &mut m_mat, &b_vec, &mut x_vec, 1, 200, 1e-12, 0.0);
+    let mut m_mat = GsSmoother::new(&a_sparse, 0, 1)?;
+    let mut pcg = CgSolver::new();
+    pcg.preconditioner(&mut m_mat)
+        .print_level(1)
+        .max_iter(200)
+        .rel_tol(1e-6)
+        .abs_tol(0.0);
+    pcg.set_operator(&a_mat)?;
+    pcg.mult(&b_vec, &mut x_vec)?;
 
     // 12. Recover the solution as a finite element grid function.
-    a.recover_fem_solution(&x_vec, &b, &mut x);
+    a.recover_fem_solution(&x_vec, &b, &mut x)?;
 
     // 13. Save the refined mesh and the solution. This output can be viewed later
     //     using GLVis: "glvis -m refined.mesh -g sol.gf".
-    mesh.save_to_file("refined.mesh", 8);
-    x.save_to_file("sol.gf", 8);
+    mesh.save_to_file("refined.mesh", 8)?;
+    x.save_to_file("sol.gf", 8)?;
 
     Ok(())
 }
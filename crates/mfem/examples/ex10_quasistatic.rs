@@ -0,0 +1,216 @@
+/// Quasi-static hyperelastic beam, based on MFEM Example 10
+///
+/// This is not a port of MFEM Example 10, which is time-dependent.
+/// It solves a nonlinear elasticity problem for a hyperelastic beam,
+/// using the Neo-Hookean material model. The beam is clamped at one
+/// end (boundary attribute 1), while the other end (boundary
+/// attribute 2) is moved up by a prescribed displacement, which is
+/// applied in a number of load steps.
+///
+/// The unknown is the deformed configuration x, i.e. the current
+/// positions of the nodes, so that the equilibrium equation is
+/// H(x) = 0, where H is the nonlinear hyperelastic operator. Each
+/// load step is solved with Newton's method, where the linear
+/// systems with the Jacobian dH/dx are solved with MINRES,
+/// preconditioned by symmetric Gauss-Seidel.
+///
+/// Unlike the original example, there is no time evolution (and so
+/// no velocity or viscosity): the beam is loaded quasi-statically.
+/// A port of the original still needs bindings for:
+/// - `TimeDependentOperator` and the implicit `ODESolver`s, e.g.
+///   `BackwardEulerSolver`, to integrate the velocity and displacement,
+/// - `GridFunction`s that view the blocks of a `BlockVector` state,
+/// - the viscosity term, which needs a `BilinearForm` of the velocity
+///   combined with the nonlinear form in a `TimeDependentOperator`,
+/// - GLVis visualization over a socket stream.
+///
+/// The example demonstrates the use of nonlinear operators, given by
+/// a nonlinear form with a hyperelastic integrator, and Newton's
+/// method with an inner linear solver and preconditioner.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Mesh file to use. Defaults to a quadrilateral mesh of the beam [0, 8] x [0, 1].
+    #[arg(short, long = "mesh", value_name = "FILE")]
+    mesh_file: Option<String>,
+
+    /// Number of times to refine the mesh uniformly.
+    #[arg(short, long = "refine", default_value_t = 2)]
+    ref_levels: u32,
+
+    /// Order (degree) of the finite elements.
+    #[arg(short, long, default_value_t = 2)]
+    order: i32,
+
+    /// Shear modulus in the Neo-Hookean hyperelastic model.
+    #[arg(long = "shear-modulus", default_value_t = 0.25)]
+    mu: f64,
+
+    /// Bulk modulus in the Neo-Hookean hyperelastic model.
+    #[arg(long = "bulk-modulus", default_value_t = 5.0)]
+    k: f64,
+
+    /// Prescribed vertical displacement of the free end of the beam.
+    #[arg(long = "tip-displacement", default_value_t = 3.0)]
+    tip: f64,
+
+    /// Number of load steps to reach the full displacement.
+    #[arg(long = "load-steps", default_value_t = 10)]
+    load_steps: u32,
+
+    /// Relative tolerance for the Newton solve.
+    #[arg(long = "newton-rel-tol", default_value_t = 1e-4)]
+    newton_rel_tol: f64,
+
+    /// Absolute tolerance for the Newton solve.
+    #[arg(long = "newton-abs-tol", default_value_t = 1e-10)]
+    newton_abs_tol: f64,
+}
+
+use anyhow::Context;
+use clap::Parser;
+use mfem::*;
+
+fn main() -> anyhow::Result<()> {
+    // 1. Parse command-line options.
+    let args = Args::parse();
+
+    // 2. Read the mesh from the given mesh file. We can handle triangular,
+    //    quadrilateral, tetrahedral and hexahedral meshes with the same code.
+    //    The default mesh is built in memory, like MFEM's beam-quad.mesh.
+    let mut mesh = match &args.mesh_file {
+        Some(mesh_file) => Mesh::from_file(mesh_file)?,
+        None => beam_quad_mesh()?,
+    };
+    let dim = mesh.dimension();
+
+    // 3. Refine the mesh to increase the resolution. In this example we do
+    //    'ref_levels' of uniform refinement, where 'ref_levels' is a
+    //    command-line parameter.
+    for _ in 0..args.ref_levels {
+        mesh.uniform_refinement(RefAlgo::A)?;
+    }
+    let (bb_min, bb_max) = mesh.get_bounding_box(1)?;
+    let (x_min, length) = (bb_min[0], bb_max[0] - bb_min[0]);
+
+    // 4. Define the vector finite element space representing the
+    //    deformed configuration.
+    let fec = H1FeCollection::new(args.order, dim, BasisType::GaussLobatto)?;
+    let fespace = FiniteElementSpace::new(&mesh, &fec, dim, OrderingType::byVDIM)?;
+    println!(
+        "Number of finite element unknowns: {}",
+        fespace.get_true_vsize(),
+    );
+
+    // 5. Determine the list of true essential boundary dofs: the clamped end
+    //    (attribute 1) and the moved end (attribute 2) of the beam.
+    let ess_bdr = mesh.bdr_marker(&[1, 2])?;
+    let mut ess_tdof_list = ArrayInt::new();
+    fespace.get_essential_true_dofs(&ess_bdr, &mut ess_tdof_list, None)?;
+
+    // 6. Define the nonlinear form H(x) given by the Neo-Hookean hyperelastic
+    //    integrator. The rows of the essential dofs are eliminated, so Newton's
+    //    method keeps their values from the initial guess.
+    let model = NeoHookeanModel::new(args.mu, args.k);
+    let mut h = NonlinearForm::new(&fespace)?;
+    h.add_domain_integrator(HyperelasticNLFIntegrator::new(&model))?;
+    h.set_essential_true_dofs(&ess_tdof_list)?;
+
+    // 7. Set the initial conditions: the reference configuration x_ref, and
+    //    the load increment dx, which bends the beam so that its free end
+    //    moves up by 'tip' over all the load steps.
+    let identity = VectorFunctionCoefficient::new(dim, |x, y| y.copy_from_slice(x));
+    let mut x_ref = GridFunction::new(&fespace)?;
+    x_ref.project_vector_coefficient(&identity)?;
+
+    let load_step = args.tip / args.load_steps as f64;
+    let increment = VectorFunctionCoefficient::new(dim, |x, dx| {
+        let s = (x[0] - x_min) / length;
+        dx.fill(0.0);
+        dx[1] = load_step * s * s;
+    });
+    let mut dx = GridFunction::new(&fespace)?;
+    dx.project_vector_coefficient(&increment)?;
+
+    let mut x = GridFunction::new(&fespace)?;
+    x.set_all(0.0);
+    x += &x_ref;
+
+    // 8. Set up the Newton solver for H(x) = 0. The Jacobian systems are
+    //    solved with MINRES, preconditioned by symmetric Gauss-Seidel, whose
+    //    matrix is set to the Jacobian at every Newton step.
+    let mut prec = GsSmoother::without_matrix(0, 1);
+    let mut j_solver = MinresSolver::new();
+    j_solver
+        .rel_tol(1e-8)
+        .abs_tol(0.0)
+        .max_iter(300)
+        .print_level(-1)
+        .preconditioner(&mut prec);
+
+    let mut newton = NewtonSolver::new();
+    newton
+        .rel_tol(args.newton_rel_tol)
+        .abs_tol(args.newton_abs_tol)
+        .max_iter(10)
+        .print_level(0)
+        .iterative_mode(true)
+        .linear_solver(&mut j_solver);
+    newton.set_operator(&h)?;
+
+    // 9. Perform the load steps. Each step moves the guess by dx, which
+    //    exactly sets the prescribed positions of the essential dofs, and
+    //    then solves for the equilibrium.
+    let mut zero = Vector::with_len(fespace.get_true_vsize() as usize);
+    zero.set_all(0.0);
+    for step in 1..=args.load_steps {
+        x += &dx;
+        let report = newton
+            .solve(&zero, &mut x)
+            .with_context(|| format!("Newton solver failed in load step {step}"))?;
+        println!(
+            "step {step:3}, tip displacement = {:.4}, Newton iterations = {:2}, strain energy = {:.6e}",
+            step as f64 * load_step,
+            report.iterations,
+            h.get_energy(&x)?,
+        );
+    }
+
+    // 10. Save the deformed mesh nodes and the displacement x - x_ref. This
+    //     output can be viewed later using GLVis:
+    //     "glvis -m ex10.mesh -g ex10-displacement.gf".
+    let mut displacement = GridFunction::new(&fespace)?;
+    displacement.set_all(0.0);
+    displacement += &x;
+    displacement -= &x_ref;
+    mesh.save_to_file("ex10.mesh", 8)?;
+    x.save_to_file("ex10-deformed.gf", 8)?;
+    displacement.save_to_file("ex10-displacement.gf", 8)?;
+
+    Ok(())
+}
+
+/// A beam of 8 unit squares along the x-axis, with boundary attribute 1 on
+/// the left end, 2 on the right end and 3 on the top and bottom.
+fn beam_quad_mesh() -> Result<Mesh, MfemError> {
+    const NX: i32 = 8;
+    let mut builder = MeshBuilder::new(2, 2);
+    for j in 0..=1 {
+        for i in 0..=NX {
+            builder.add_vertex(&[i as f64, j as f64]);
+        }
+    }
+    let v = |i: i32, j: i32| j * (NX + 1) + i;
+    for i in 0..NX {
+        builder.add_element(
+            ElementType::QUADRILATERAL,
+            &[v(i, 0), v(i + 1, 0), v(i + 1, 1), v(i, 1)],
+            1,
+        );
+        builder.add_boundary_element(ElementType::SEGMENT, &[v(i, 0), v(i + 1, 0)], 3);
+        builder.add_boundary_element(ElementType::SEGMENT, &[v(i + 1, 1), v(i, 1)], 3);
+    }
+    builder.add_boundary_element(ElementType::SEGMENT, &[v(0, 1), v(0, 0)], 1);
+    builder.add_boundary_element(ElementType::SEGMENT, &[v(NX, 0), v(NX, 1)], 2);
+    builder.build()
+}
@@ -0,0 +1,169 @@
+/// MFEM Example 3
+///
+/// This example code solves a simple electromagnetic diffusion
+/// problem corresponding to the second order definite Maxwell
+/// equation curl curl E + E = f with boundary condition
+/// E x n = <given tangential field>. Here, we use a given exact
+/// solution E and compute the corresponding r.h.s. f.
+/// We discretize with Nedelec finite elements in 2D or 3D.
+///
+/// The example demonstrates the use of H(curl) finite element
+/// spaces with the curl-curl and the (vector finite element) mass
+/// bilinear form, as well as the computation of discretization
+/// error when the exact solution is known.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Mesh file to use. Defaults to a hexahedral mesh of the unit cube.
+    #[arg(short, long = "mesh", value_name = "FILE")]
+    mesh_file: Option<String>,
+
+    /// Finite element order (polynomial degree).
+    #[arg(short, long, default_value_t = 1)]
+    order: i32,
+
+    /// Frequency (in units of pi) of the exact solution.
+    #[arg(short, long, default_value_t = 1.0)]
+    freq: f64,
+}
+
+use clap::Parser;
+use mfem::*;
+
+fn main() -> anyhow::Result<()> {
+    // 1. Parse command-line options.
+    let args = Args::parse();
+    let kappa = args.freq * std::f64::consts::PI;
+
+    // 2. Read the mesh from the given mesh file. We can handle triangular,
+    //    quadrilateral, tetrahedral, hexahedral, surface and volume meshes with
+    //    the same code.
+    let mut mesh = match &args.mesh_file {
+        Some(mesh_file) => Mesh::from_file(mesh_file)?,
+        None => Mesh::cartesian_3d(4, 4, 4, ElementType::HEXAHEDRON, [1.0, 1.0, 1.0], true)?,
+    };
+    let dim = mesh.dimension();
+    let sdim = mesh.space_dimension();
+
+    // 3. Refine the mesh to increase the resolution. In this example we do
+    //    'ref_levels' of uniform refinement. We choose 'ref_levels' to be the
+    //    largest number that gives a final mesh with no more than 50,000
+    //    elements.
+    let ref_levels =
+        f64::floor(f64::log2(50000.0 / mesh.get_num_elems() as f64) / dim as f64) as u32;
+    for _ in 0..ref_levels {
+        mesh.uniform_refinement(RefAlgo::A)?;
+    }
+
+    // 4. Define a finite element space on the mesh. Here we use the Nedelec
+    //    finite elements of the specified order.
+    let fec = NdFeCollection::new(
+        args.order,
+        dim,
+        BasisType::GaussLobatto,
+        BasisType::GaussLegendre,
+    )?;
+    let fespace = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES)?;
+    println!(
+        "Number of finite element unknowns: {}",
+        fespace.get_true_vsize(),
+    );
+
+    // 5. Determine the list of true (i.e. conforming) essential boundary dofs.
+    //    In this example, the boundary conditions are defined by marking all
+    //    the boundary attributes from the mesh as essential (Dirichlet) and
+    //    converting them to a list of true dofs.
+    let mut ess_tdof_list = ArrayInt::new();
+    if let Some(max_bdr_attr) = mesh.get_bdr_attributes().iter().max() {
+        let mut ess_bdr = ArrayInt::with_len(*max_bdr_attr as usize)?;
+        ess_bdr.set_all(1);
+        fespace.get_essential_true_dofs(&ess_bdr, &mut ess_tdof_list, None)?;
+    }
+
+    // 6. Set up the linear form b(.) which corresponds to the right-hand side
+    //    of the FEM linear system, which in this case is (f,phi_i) where f is
+    //    given by the function f_exact and phi_i are the basis functions in the
+    //    finite element fespace.
+    let f = VectorFunctionCoefficient::new(sdim, |x, f| f_exact(kappa, x, f));
+    let mut b = LinearForm::new(&fespace)?;
+    b.add_domain_integrator(VectorFEDomainLFIntegrator::new(&f))?;
+    b.assemble()?;
+
+    // 7. Define the solution vector x as a finite element grid function
+    //    corresponding to fespace. Initialize x by projecting the exact
+    //    solution. Note that only values from the boundary edges will be used
+    //    when eliminating the non-homogeneous boundary condition to modify the
+    //    r.h.s. vector b.
+    let e = VectorFunctionCoefficient::new(sdim, |x, e| e_exact(kappa, x, e));
+    let mut x = GridFunction::new(&fespace)?;
+    x.project_vector_coefficient(&e)?;
+
+    // 8. Set up the bilinear form corresponding to the EM diffusion operator
+    //    curl muinv curl + sigma I, by adding the curl-curl and the mass domain
+    //    integrators.
+    let muinv = ConstantCoefficient::new(1.0);
+    let sigma = ConstantCoefficient::new(1.0);
+    let mut a = BilinearForm::new(&fespace)?;
+    a.add_domain_integrator(CurlCurlIntegrator::new(&muinv))?;
+    a.add_domain_integrator(VectorFEMassIntegrator::new(&sigma))?;
+
+    // 9. Assemble the bilinear form and the corresponding linear system,
+    //    applying any necessary transformations such as: eliminating boundary
+    //    conditions, applying conforming constraints for non-conforming AMR,
+    //    static condensation, etc.
+    a.assemble(true)?;
+
+    let mut a_mat = OperatorHandle::new();
+    let mut b_vec = Vector::new();
+    let mut x_vec = Vector::new();
+    a.form_linear_system(&ess_tdof_list, &x, &b, &mut a_mat, &mut x_vec, &mut b_vec)?;
+
+    println!("Size of linear system: {}", a_mat.height());
+
+    // 10. Solve the linear system A X = B.
+    // Use a simple symmetric Gauss-Seidel preconditioner with PCG.
+    let a_sparse = SparseMatrixRef::try_from(&a_mat).expect("Operator is a SparseMatrix");
+    let mut m_mat = GsSmoother::new(&a_sparse, 0, 1)?;
+    let mut pcg = CgSolver::new();
+    pcg.preconditioner(&mut m_mat)
+        .print_level(1)
+        .max_iter(500)
+        .rel_tol(1e-6)
+        .abs_tol(0.0);
+    pcg.set_operator(&a_mat)?;
+    pcg.mult(&b_vec, &mut x_vec)?;
+
+    // 11. Recover the solution as a finite element grid function.
+    a.recover_fem_solution(&x_vec, &b, &mut x)?;
+
+    // 12. Compute and print the L^2 norm of the error.
+    println!("|| E_h - E ||_{{L^2}} = {}", x.compute_vector_l2_error(&e)?,);
+
+    // 13. Save the refined mesh and the solution. This output can be viewed
+    //     later using GLVis: "glvis -m refined.mesh -g sol.gf".
+    mesh.save_to_file("refined.mesh", 8)?;
+    x.save_to_file("sol.gf", 8)?;
+
+    Ok(())
+}
+
+fn e_exact(kappa: f64, x: &[f64], e: &mut [f64]) {
+    if x.len() == 3 {
+        e[0] = f64::sin(kappa * x[1]);
+        e[1] = f64::sin(kappa * x[2]);
+        e[2] = f64::sin(kappa * x[0]);
+    } else {
+        e[0] = f64::sin(kappa * x[1]);
+        e[1] = f64::sin(kappa * x[0]);
+        if e.len() == 3 {
+            e[2] = 0.0;
+        }
+    }
+}
+
+fn f_exact(kappa: f64, x: &[f64], f: &mut [f64]) {
+    e_exact(kappa, x, f);
+    for f in f.iter_mut() {
+        *f *= 1.0 + kappa * kappa;
+    }
+}
@@ -0,0 +1,384 @@
+/// MFEM Example 9
+///
+/// This example code solves the time-dependent advection equation
+/// du/dt + v.grad(u) = 0, where v is a given fluid velocity, and
+/// u0(x)=u(0,x) is a given initial condition.
+///
+/// The example demonstrates the use of Discontinuous Galerkin (DG)
+/// bilinear forms in MFEM (face integrators), and of explicit
+/// Runge-Kutta time integrators. Unlike the original example, the
+/// default mesh isn't periodic, so the solution flows out of the
+/// domain, and it's saved to files instead of being sent to GLVis.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Mesh file to use. Defaults to a quadrilateral mesh of the square [0, 2]^2.
+    #[arg(short, long = "mesh", value_name = "FILE")]
+    mesh_file: Option<String>,
+
+    /// Problem setup to use. See options in velocity_function().
+    #[arg(short, long, default_value_t = 0)]
+    problem: i32,
+
+    /// Number of times to refine the mesh uniformly.
+    #[arg(short, long = "refine", default_value_t = 2)]
+    ref_levels: u32,
+
+    /// Order (degree) of the finite elements.
+    #[arg(short, long, default_value_t = 3)]
+    order: i32,
+
+    /// ODE solver: 1 - Forward Euler, 2 - RK2, 3 - RK3 SSP, 4 - RK4.
+    #[arg(short = 's', long = "ode-solver", default_value_t = 4)]
+    ode_solver_type: i32,
+
+    /// Final time; start time is 0.
+    #[arg(long = "t-final", default_value_t = 10.0)]
+    t_final: f64,
+
+    /// Time step.
+    #[arg(long = "time-step", default_value_t = 0.01)]
+    dt: f64,
+
+    /// Print the progress every n-th time step.
+    #[arg(long = "vis-steps", default_value_t = 5)]
+    vis_steps: usize,
+}
+
+use clap::Parser;
+use mfem::*;
+
+fn main() -> anyhow::Result<()> {
+    // 1. Parse command-line options.
+    let args = Args::parse();
+
+    // 2. Read the mesh from the given mesh file. We can handle geometrically
+    //    periodic meshes in this code.
+    let mut mesh = match &args.mesh_file {
+        Some(mesh_file) => Mesh::from_file(mesh_file)?,
+        None => Mesh::cartesian_2d(16, 16, ElementType::QUADRILATERAL, true, [2.0, 2.0], true)?,
+    };
+    let dim = mesh.dimension();
+
+    // 3. Define the ODE solver used for time integration. Several explicit
+    //    Runge-Kutta methods are available.
+    let ode_solver = match args.ode_solver_type {
+        1 => ButcherTableau::forward_euler(),
+        2 => ButcherTableau::rk2(2.0 / 3.0),
+        3 => ButcherTableau::rk3_ssp(),
+        4 => ButcherTableau::rk4(),
+        other => anyhow::bail!("Unknown ODE solver type: {other}"),
+    };
+
+    // 4. Refine the mesh to increase the resolution. In this example we do
+    //    'ref_levels' of uniform refinement, where 'ref_levels' is a
+    //    command-line parameter.
+    for _ in 0..args.ref_levels {
+        mesh.uniform_refinement(RefAlgo::A)?;
+    }
+    let (bb_min, bb_max) = mesh.get_bounding_box(args.order.max(1))?;
+
+    // 5. Define the discontinuous DG finite element space of the given
+    //    polynomial order on the refined mesh.
+    let fec = L2FeCollection::new(args.order, dim, BasisType::GaussLobatto)?;
+    let fes = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES)?;
+    println!("Number of unknowns: {}", fes.get_true_vsize());
+
+    // 6. Set up and assemble the bilinear and linear forms corresponding to the
+    //    DG discretization. The DGTraceIntegrator involves integrals over mesh
+    //    interior faces.
+    let domain = Domain { bb_min, bb_max };
+    let problem = args.problem;
+    let velocity = VectorFunctionCoefficient::new(dim, |x, v| domain.velocity(problem, x, v));
+    let inflow = FunctionCoefficient::new(|x| domain.inflow(problem, x));
+    let u0 = FunctionCoefficient::new(|x| domain.u0(problem, x));
+
+    let one = ConstantCoefficient::new(1.0);
+    let mut m = BilinearForm::new(&fes)?;
+    m.add_domain_integrator(MassIntegrator::new(&one))?;
+
+    let mut k = BilinearForm::new(&fes)?;
+    k.add_domain_integrator(ConvectionIntegrator::new(&velocity, -1.0))?;
+    k.add_interior_face_integrator(TransposeIntegrator::new(DGTraceIntegrator::new(
+        &velocity, 1.0, -0.5,
+    )))?;
+    k.add_bdr_face_integrator(
+        TransposeIntegrator::new(DGTraceIntegrator::new(&velocity, 1.0, -0.5)),
+        None,
+    )?;
+
+    let mut b = LinearForm::new(&fes)?;
+    b.add_bdr_face_integrator(
+        BoundaryFlowIntegrator::new(&inflow, &velocity, -1.0, -0.5),
+        None,
+    )?;
+
+    m.assemble(true)?;
+    m.finalize(true)?;
+    k.assemble(false)?;
+    k.finalize(false)?;
+    b.assemble()?;
+
+    // 7. Define the initial conditions, save the corresponding grid function to
+    //    a file to be viewed later using GLVis: "glvis -m ex9.mesh -g ex9-init.gf".
+    let mut u = GridFunction::new(&fes)?;
+    u.project_coefficient(&u0)?;
+
+    mesh.save_to_file("ex9.mesh", 8)?;
+    u.save_to_file("ex9-init.gf", 8)?;
+
+    // 8. Define the time-dependent evolution operator describing the ODE
+    //    right-hand side, and perform time-integration (looping over the time
+    //    iterations, ti, with a time-step dt).
+    let m_sparse = m.sp_mat()?;
+    let mut m_prec = GsSmoother::new(&m_sparse, 0, 1)?;
+    let mut m_solver = CgSolver::new();
+    m_solver
+        .preconditioner(&mut m_prec)
+        .iterative_mode(false)
+        .rel_tol(1e-9)
+        .abs_tol(0.0)
+        .max_iter(100)
+        .print_level(0);
+    m_solver.set_operator(&m_sparse)?;
+    let mut adv = FeEvolution {
+        m_solver,
+        k: &k,
+        b: &b,
+        z: Vector::with_len(u.len()),
+    };
+
+    let mut t = 0.0;
+    let mut ti = 0;
+    let mut done = false;
+    while !done {
+        let dt_real = args.dt.min(args.t_final - t);
+        ode_solver.step(&mut adv, &mut u, dt_real)?;
+        t += dt_real;
+        ti += 1;
+
+        done = t >= args.t_final - 1e-8 * args.dt;
+
+        if done || ti % args.vis_steps == 0 {
+            println!("time step: {ti}, time: {t}");
+        }
+    }
+
+    // 9. Save the final solution. This output can be viewed later using GLVis:
+    //    "glvis -m ex9.mesh -g ex9-final.gf".
+    u.save_to_file("ex9-final.gf", 8)?;
+
+    Ok(())
+}
+
+/// A time-dependent operator for the right-hand side of the ODE. The DG weak
+/// form of du/dt = -v.grad(u) is M du/dt = K u + b, where M and K are the mass
+/// and advection matrices, and b describes the flow on the boundary. This can
+/// be written as a general ODE, du/dt = M^{-1} (K u + b).
+struct FeEvolution<'a, 'fes> {
+    m_solver: CgSolver<'a>,
+    k: &'a BilinearForm<'fes>,
+    b: &'a LinearForm<'fes>,
+    z: Vector,
+}
+
+impl<'a, 'fes> FeEvolution<'a, 'fes> {
+    /// y = M^{-1} (K x + b)
+    fn mult(&mut self, x: &Vector, y: &mut Vector) -> Result<(), MfemError> {
+        self.k.mult(x, &mut self.z)?;
+        self.z += self.b;
+        self.m_solver.mult(&self.z, y)
+    }
+}
+
+/// An explicit Runge-Kutta method.
+struct ButcherTableau {
+    a: Vec<Vec<f64>>,
+    b: Vec<f64>,
+}
+
+impl ButcherTableau {
+    fn forward_euler() -> Self {
+        Self {
+            a: vec![vec![]],
+            b: vec![1.0],
+        }
+    }
+
+    fn rk2(a: f64) -> Self {
+        Self {
+            a: vec![vec![], vec![a]],
+            b: vec![1.0 - 1.0 / (2.0 * a), 1.0 / (2.0 * a)],
+        }
+    }
+
+    fn rk3_ssp() -> Self {
+        Self {
+            a: vec![vec![], vec![1.0], vec![0.25, 0.25]],
+            b: vec![1.0 / 6.0, 1.0 / 6.0, 2.0 / 3.0],
+        }
+    }
+
+    fn rk4() -> Self {
+        Self {
+            a: vec![vec![], vec![0.5], vec![0.0, 0.5], vec![0.0, 0.0, 1.0]],
+            b: vec![1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0],
+        }
+    }
+
+    /// Advance `u` by `dt`.
+    fn step(&self, f: &mut FeEvolution, u: &mut GridFunction, dt: f64) -> Result<(), MfemError> {
+        let n = u.len();
+        let mut ks: Vec<Vector> = Vec::with_capacity(self.b.len());
+        let mut x = Vector::with_len(n);
+        for a_row in &self.a {
+            x.as_mut_slice().copy_from_slice(u.as_slice());
+            for (a_ij, k_j) in a_row.iter().zip(&ks) {
+                for (x, k) in x.as_mut_slice().iter_mut().zip(k_j.as_slice()) {
+                    *x += dt * a_ij * k;
+                }
+            }
+            let mut k_i = Vector::with_len(n);
+            f.mult(&x, &mut k_i)?;
+            ks.push(k_i);
+        }
+        for (b_i, k_i) in self.b.iter().zip(&ks) {
+            for (u, k) in u.as_mut_slice().iter_mut().zip(k_i.as_slice()) {
+                *u += dt * b_i * k;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The problem data, defined on the bounding box mapped to [-1, 1]^dim.
+struct Domain {
+    bb_min: Vec<f64>,
+    bb_max: Vec<f64>,
+}
+
+impl Domain {
+    fn map_to_reference(&self, x: &[f64]) -> Vec<f64> {
+        x.iter()
+            .zip(self.bb_min.iter().zip(&self.bb_max))
+            .map(|(x, (min, max))| {
+                let center = (min + max) * 0.5;
+                2.0 * (x - center) / (max - min)
+            })
+            .collect()
+    }
+
+    /// Velocity coefficient
+    fn velocity(&self, problem: i32, x: &[f64], v: &mut [f64]) {
+        let x = self.map_to_reference(x);
+        let dim = x.len();
+        match problem {
+            // Translations in 1D, 2D, and 3D
+            0 | 1 => match dim {
+                1 => v[0] = 1.0,
+                2 => {
+                    v[0] = f64::sqrt(2.0 / 3.0);
+                    v[1] = f64::sqrt(1.0 / 3.0);
+                }
+                _ => {
+                    v[0] = f64::sqrt(3.0 / 6.0);
+                    v[1] = f64::sqrt(2.0 / 6.0);
+                    v[2] = f64::sqrt(1.0 / 6.0);
+                }
+            },
+            // Clockwise rotation in 2D around the origin
+            2 => {
+                let w = std::f64::consts::FRAC_PI_2;
+                match dim {
+                    1 => v[0] = 1.0,
+                    _ => {
+                        v[0] = w * x[1];
+                        v[1] = -w * x[0];
+                        if dim == 3 {
+                            v[2] = 0.0;
+                        }
+                    }
+                }
+            }
+            // Clockwise twisting rotation in 2D around the origin
+            _ => {
+                let w = std::f64::consts::FRAC_PI_2;
+                let d = f64::max((x[0] + 1.0) * (1.0 - x[0]), 0.0)
+                    * f64::max((x[1] + 1.0) * (1.0 - x[1]), 0.0);
+                let d = d * d;
+                match dim {
+                    1 => v[0] = 1.0,
+                    _ => {
+                        v[0] = d * w * x[1];
+                        v[1] = -d * w * x[0];
+                        if dim == 3 {
+                            v[2] = 0.0;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Initial condition
+    fn u0(&self, problem: i32, x: &[f64]) -> f64 {
+        let x = self.map_to_reference(x);
+        let dim = x.len();
+        match problem {
+            0 | 1 => match dim {
+                1 => f64::exp(-40.0 * (x[0] - 0.5).powi(2)),
+                _ => {
+                    let (mut rx, mut ry) = (0.45, 0.25);
+                    let (cx, cy) = (0.0, -0.2);
+                    let w = 10.0;
+                    if dim == 3 {
+                        let s = 1.0 + 0.25 * f64::cos(2.0 * std::f64::consts::PI * x[2]);
+                        rx *= s;
+                        ry *= s;
+                    }
+                    (erfc(w * (x[0] - cx - rx))
+                        * erfc(-w * (x[0] - cx + rx))
+                        * erfc(w * (x[1] - cy - ry))
+                        * erfc(-w * (x[1] - cy + ry)))
+                        / 16.0
+                }
+            },
+            2 => {
+                let rho = f64::hypot(x[0], x[1]);
+                let phi = f64::atan2(x[1], x[0]);
+                f64::sin(std::f64::consts::PI * rho).powi(2) * f64::sin(3.0 * phi)
+            }
+            _ => {
+                let f = std::f64::consts::PI;
+                f64::sin(f * x[0]) * f64::sin(f * x[1])
+            }
+        }
+    }
+
+    /// Inflow boundary condition (zero for the problems considered in this example)
+    fn inflow(&self, _problem: i32, _x: &[f64]) -> f64 {
+        0.0
+    }
+}
+
+/// The complementary error function, with a fractional error below 1.2e-7.
+///
+/// See "Numerical Recipes in C", 2nd edition, section 6.2.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851973 + t * (-0.82215223 + t * 0.17087277))))))));
+    let ans = t * poly.exp();
+    if x >= 0.0 {
+        ans
+    } else {
+        2.0 - ans
+    }
+}
@@ -1,5 +1,8 @@
 use cxx::memory::UniquePtrTarget;
 use cxx::{let_cxx_string, UniquePtr};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::ops::ControlFlow;
 use thiserror::Error;
 
 trait AsBase<T> {
@@ -59,8 +62,13 @@ impl ArrayInt {
         Self { inner }
     }
 
-    pub fn with_len(len: usize) -> Self {
-        let inner = mfem_sys::ffi::ArrayInt_ctor_size(len as i32);
+    pub fn with_len(len: usize) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::ArrayInt_ctor_size(len as i32)?;
+        Ok(Self { inner })
+    }
+
+    pub fn from_slice(values: &[i32]) -> Self {
+        let inner = mfem_sys::ffi::ArrayInt_ctor_slice(values);
         Self { inner }
     }
 
@@ -85,8 +93,97 @@ impl<'a> ArrayIntRef<'a> {
 // VectorLike //
 ////////////////
 
-pub trait VectorLike: AsBase<mfem_sys::ffi::Vector> + AsBaseMut<mfem_sys::ffi::Vector> {
-    // TODO(mkovaxx)
+/// The read-only operations of all vectors, including views like [`VectorRef`].
+/// Functions that only read a vector accept any `VectorRead`.
+pub trait VectorRead: AsBase<mfem_sys::ffi::Vector> {
+    fn len(&self) -> usize {
+        self.as_base().Size() as usize
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// View the entries without copying.
+    fn as_slice(&self) -> &[f64] {
+        let data = self.as_base().GetData();
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(data, len) }
+    }
+
+    fn dot<V: VectorRead>(&self, other: &V) -> Result<f64, MfemError> {
+        Ok(mfem_sys::ffi::Vector_Dot(self.as_base(), other.as_base())?)
+    }
+
+    fn norm_l2(&self) -> f64 {
+        self.as_base().Norml2()
+    }
+
+    fn norm_linf(&self) -> f64 {
+        self.as_base().Normlinf()
+    }
+}
+
+/// The operations of vectors that can be modified.
+pub trait VectorLike: VectorRead + AsBaseMut<mfem_sys::ffi::Vector> {
+    /// View the entries mutably without copying.
+    fn as_mut_slice(&mut self) -> &mut [f64] {
+        let len = self.len();
+        if len == 0 {
+            return &mut [];
+        }
+        let data = self.as_base_mut().GetData();
+        unsafe { std::slice::from_raw_parts_mut(data, len) }
+    }
+
+    fn set_all(&mut self, value: f64) {
+        mfem_sys::ffi::Vector_SetAll(self.as_base_mut(), value);
+    }
+}
+
+// Implements `std::ops` traits on a `VectorLike` type.
+// These panic on size mismatch, like indexing out of bounds does.
+macro_rules! impl_vector_ops {
+    ($ty:ident $(<$lt:lifetime>)?) => {
+        impl<$($lt,)? Rhs: VectorRead> std::ops::AddAssign<&Rhs> for $ty$(<$lt>)? {
+            fn add_assign(&mut self, rhs: &Rhs) {
+                if let Err(e) = mfem_sys::ffi::Vector_AddAssign(self.as_base_mut(), rhs.as_base()) {
+                    panic!("{}", MfemError::from(e));
+                }
+            }
+        }
+
+        impl<$($lt,)? Rhs: VectorRead> std::ops::SubAssign<&Rhs> for $ty$(<$lt>)? {
+            fn sub_assign(&mut self, rhs: &Rhs) {
+                if let Err(e) = mfem_sys::ffi::Vector_SubAssign(self.as_base_mut(), rhs.as_base()) {
+                    panic!("{}", MfemError::from(e));
+                }
+            }
+        }
+
+        impl$(<$lt>)? std::ops::MulAssign<f64> for $ty$(<$lt>)? {
+            fn mul_assign(&mut self, rhs: f64) {
+                mfem_sys::ffi::Vector_MulAssign(self.as_base_mut(), rhs);
+            }
+        }
+
+        impl$(<$lt>)? std::ops::Index<usize> for $ty$(<$lt>)? {
+            type Output = f64;
+
+            fn index(&self, index: usize) -> &f64 {
+                &self.as_slice()[index]
+            }
+        }
+
+        impl$(<$lt>)? std::ops::IndexMut<usize> for $ty$(<$lt>)? {
+            fn index_mut(&mut self, index: usize) -> &mut f64 {
+                &mut self.as_mut_slice()[index]
+            }
+        }
+    };
 }
 
 ////////////
@@ -102,10 +199,25 @@ impl Vector {
         let inner = mfem_sys::ffi::Vector_ctor();
         Self { inner }
     }
+
+    pub fn with_len(len: usize) -> Self {
+        let inner = mfem_sys::ffi::Vector_ctor_size(len as i32);
+        Self { inner }
+    }
+
+    pub fn from_slice(values: &[f64]) -> Self {
+        let mut vector = Self::with_len(values.len());
+        vector.as_mut_slice().copy_from_slice(values);
+        vector
+    }
 }
 
+impl VectorRead for Vector {}
+
 impl VectorLike for Vector {}
 
+impl_vector_ops!(Vector);
+
 impl AsBase<mfem_sys::ffi::Vector> for Vector {
     fn as_base(&self) -> &mfem_sys::ffi::Vector {
         &self.inner
@@ -118,6 +230,285 @@ impl AsBaseMut<mfem_sys::ffi::Vector> for Vector {
     }
 }
 
+/// A [`Vector`] owned by something else, e.g. a block of a [`BlockVector`].
+pub struct VectorRef<'a> {
+    inner: &'a mfem_sys::ffi::Vector,
+}
+
+/// A mutable [`Vector`] owned by something else, e.g. a block of a [`BlockVector`].
+pub struct VectorMut<'a> {
+    inner: std::pin::Pin<&'a mut mfem_sys::ffi::Vector>,
+}
+
+impl<'a> VectorRef<'a> {
+    /// View the entries without copying, for as long as the owner is borrowed.
+    pub fn as_slice(&self) -> &'a [f64] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.inner.GetData(), len) }
+    }
+}
+
+impl<'a> VectorRead for VectorRef<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Vector> for VectorRef<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Vector {
+        self.inner
+    }
+}
+
+impl<'a> VectorRead for VectorMut<'a> {}
+
+impl<'a> VectorLike for VectorMut<'a> {}
+
+impl_vector_ops!(VectorMut<'a>);
+
+impl<'a> AsBase<mfem_sys::ffi::Vector> for VectorMut<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Vector {
+        &self.inner
+    }
+}
+
+impl<'a> AsBaseMut<mfem_sys::ffi::Vector> for VectorMut<'a> {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Vector> {
+        self.inner.as_mut()
+    }
+}
+
+/////////////////
+// BlockVector //
+/////////////////
+
+/// A [`Vector`] made of consecutive blocks, where block `i` is the range `offsets[i]..offsets[i + 1]`,
+/// e.g. the velocity and the pressure in a saddle-point system.
+pub struct BlockVector {
+    inner: UniquePtr<mfem_sys::ffi::BlockVector>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    offsets: UniquePtr<mfem_sys::ffi::ArrayInt>,
+}
+
+impl BlockVector {
+    /// A vector of zeros, given the block offsets, which start at 0 and end at the total length.
+    pub fn new(offsets: &[i32]) -> Result<Self, MfemError> {
+        let offsets = block_offsets(offsets)?;
+        let inner = mfem_sys::ffi::BlockVector_ctor_offsets(&offsets);
+        let mut vector = Self { inner, offsets };
+        vector.set_all(0.0);
+        Ok(vector)
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.inner.NumBlocks() as usize
+    }
+
+    pub fn offsets(&self) -> ArrayIntRef<'_> {
+        ArrayIntRef {
+            inner: &self.offsets,
+        }
+    }
+
+    /// View block `i` without copying.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn block(&self, i: usize) -> VectorRef<'_> {
+        self.check_block_index(i);
+        let inner = mfem_sys::ffi::BlockVector_GetBlock(&self.inner, i as i32);
+        VectorRef { inner }
+    }
+
+    /// View block `i` mutably without copying.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn block_mut(&mut self, i: usize) -> VectorMut<'_> {
+        self.check_block_index(i);
+        let inner = mfem_sys::ffi::BlockVector_GetBlock_mut(self.inner.pin_mut(), i as i32);
+        VectorMut { inner }
+    }
+
+    fn check_block_index(&self, i: usize) {
+        let num_blocks = self.num_blocks();
+        assert!(
+            i < num_blocks,
+            "block index {i} is out of bounds for {num_blocks} blocks",
+        );
+    }
+}
+
+impl VectorRead for BlockVector {}
+
+impl VectorLike for BlockVector {}
+
+impl_vector_ops!(BlockVector);
+
+impl AsBase<mfem_sys::ffi::Vector> for BlockVector {
+    fn as_base(&self) -> &mfem_sys::ffi::Vector {
+        mfem_sys::ffi::BlockVector_as_Vector(&self.inner)
+    }
+}
+
+impl AsBaseMut<mfem_sys::ffi::Vector> for BlockVector {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Vector> {
+        mfem_sys::ffi::BlockVector_as_mut_Vector(self.inner.pin_mut())
+    }
+}
+
+// MFEM doesn't check the offsets, so this does.
+fn block_offsets(offsets: &[i32]) -> Result<UniquePtr<mfem_sys::ffi::ArrayInt>, MfemError> {
+    if offsets.first() != Some(&0) || offsets.windows(2).any(|w| w[0] > w[1]) {
+        return Err(MfemError::InvalidArgument(format!(
+            "block offsets must start at 0 and must not decrease, got {offsets:?}",
+        )));
+    }
+    Ok(mfem_sys::ffi::ArrayInt_ctor_slice(offsets))
+}
+
+/////////////////////
+// DenseMatrixLike //
+/////////////////////
+
+/// Operations shared by [`DenseMatrix`] and [`DenseMatrixMut`].
+///
+/// The entries are stored in column-major order.
+pub trait DenseMatrixLike:
+    AsBase<mfem_sys::ffi::DenseMatrix> + AsBaseMut<mfem_sys::ffi::DenseMatrix>
+{
+    fn height(&self) -> usize {
+        self.as_base().Height() as usize
+    }
+
+    fn width(&self) -> usize {
+        self.as_base().Width() as usize
+    }
+
+    fn set_all(&mut self, value: f64) {
+        mfem_sys::ffi::DenseMatrix_SetAll(self.as_base_mut(), value);
+    }
+
+    /// View the entries without copying.
+    fn as_slice(&self) -> &[f64] {
+        let data = self.as_base().Data();
+        let len = self.height() * self.width();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(data, len) }
+    }
+
+    /// View the entries mutably without copying.
+    fn as_mut_slice(&mut self) -> &mut [f64] {
+        let len = self.height() * self.width();
+        if len == 0 {
+            return &mut [];
+        }
+        let data = self.as_base_mut().Data();
+        unsafe { std::slice::from_raw_parts_mut(data, len) }
+    }
+
+    /// `self += a * m * m^T`, e.g. for stiffness matrices from the shape function gradients `m`.
+    fn add_mult_a_aat<M: DenseMatrixLike>(&mut self, a: f64, m: &M) -> Result<(), MfemError> {
+        mfem_sys::ffi::DenseMatrix_AddMult_a_AAt(a, m.as_base(), self.as_base_mut())?;
+        Ok(())
+    }
+
+    /// `self += a * v * v^T`, e.g. for mass matrices from the shape functions `v`.
+    fn add_mult_a_vvt<V: VectorRead>(&mut self, a: f64, v: &V) -> Result<(), MfemError> {
+        mfem_sys::ffi::DenseMatrix_AddMult_a_VVt(a, v.as_base(), self.as_base_mut())?;
+        Ok(())
+    }
+}
+
+// Implements `std::ops` traits on a `DenseMatrixLike` type.
+// Indexing is by `(row, column)` and panics when out of bounds.
+macro_rules! impl_dense_matrix_ops {
+    ($ty:ident $(<$lt:lifetime>)?) => {
+        impl$(<$lt>)? std::ops::Index<(usize, usize)> for $ty$(<$lt>)? {
+            type Output = f64;
+
+            fn index(&self, (i, j): (usize, usize)) -> &f64 {
+                let (height, width) = (self.height(), self.width());
+                assert!(i < height && j < width, "index ({i}, {j}) out of bounds for {height}x{width} matrix");
+                &self.as_slice()[i + j * height]
+            }
+        }
+
+        impl$(<$lt>)? std::ops::IndexMut<(usize, usize)> for $ty$(<$lt>)? {
+            fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+                let (height, width) = (self.height(), self.width());
+                assert!(i < height && j < width, "index ({i}, {j}) out of bounds for {height}x{width} matrix");
+                &mut self.as_mut_slice()[i + j * height]
+            }
+        }
+    };
+}
+
+/////////////////
+// DenseMatrix //
+/////////////////
+
+pub struct DenseMatrix {
+    inner: UniquePtr<mfem_sys::ffi::DenseMatrix>,
+}
+
+/// A [`DenseMatrix`] owned by MFEM, e.g. the element matrix in
+/// [`CustomBilinearFormIntegrator::assemble_element_matrix`].
+pub struct DenseMatrixMut<'a> {
+    inner: std::pin::Pin<&'a mut mfem_sys::ffi::DenseMatrix>,
+}
+
+impl DenseMatrix {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::DenseMatrix_ctor();
+        Self { inner }
+    }
+
+    /// A `height` x `width` matrix of zeros.
+    pub fn with_size(height: usize, width: usize) -> Self {
+        let mut matrix = Self::new();
+        matrix.set_size(height, width);
+        matrix.set_all(0.0);
+        matrix
+    }
+
+    /// Resize to `height` x `width`. The entries are left uninitialized, see [`DenseMatrixLike::set_all`].
+    pub fn set_size(&mut self, height: usize, width: usize) {
+        mfem_sys::ffi::DenseMatrix_SetSize(self.inner.pin_mut(), height as i32, width as i32);
+    }
+}
+
+impl DenseMatrixLike for DenseMatrix {}
+
+impl_dense_matrix_ops!(DenseMatrix);
+
+impl AsBase<mfem_sys::ffi::DenseMatrix> for DenseMatrix {
+    fn as_base(&self) -> &mfem_sys::ffi::DenseMatrix {
+        &self.inner
+    }
+}
+
+impl AsBaseMut<mfem_sys::ffi::DenseMatrix> for DenseMatrix {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::DenseMatrix> {
+        self.inner.pin_mut()
+    }
+}
+
+impl<'a> DenseMatrixLike for DenseMatrixMut<'a> {}
+
+impl_dense_matrix_ops!(DenseMatrixMut<'a>);
+
+impl<'a> AsBase<mfem_sys::ffi::DenseMatrix> for DenseMatrixMut<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::DenseMatrix {
+        &self.inner
+    }
+}
+
+impl<'a> AsBaseMut<mfem_sys::ffi::DenseMatrix> for DenseMatrixMut<'a> {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::DenseMatrix> {
+        self.inner.as_mut()
+    }
+}
+
 //////////
 // Mesh //
 //////////
@@ -133,12 +524,87 @@ impl Mesh {
     }
 
     pub fn from_file(path: &str) -> Result<Self, MfemError> {
-        let generate_edges = 1;
-        let refine = 1;
-        let fix_orientation = true;
+        Self::from_file_with_options(path, MeshLoadOptions::default())
+    }
+
+    pub fn from_file_with_options(path: &str, options: MeshLoadOptions) -> Result<Self, MfemError> {
         let_cxx_string!(mesh_path = path);
+        let inner = mfem_sys::ffi::Mesh_ctor_file(
+            &mesh_path,
+            options.generate_edges as i32,
+            options.refine as i32,
+            options.fix_orientation,
+        )?;
+        Ok(Self { inner })
+    }
+
+    /// Read a mesh in any format supported by MFEM from `reader`.
+    ///
+    /// The reader is consumed sequentially, so it doesn't need to support seeking.
+    pub fn from_reader<R: Read>(
+        mut reader: R,
+        options: MeshLoadOptions,
+    ) -> Result<Self, MfemError> {
+        let mut rust_read = mfem_sys::RustRead::new(&mut reader);
+        let result = mfem_sys::ffi::Mesh_ctor_reader(
+            &mut rust_read,
+            options.generate_edges as i32,
+            options.refine as i32,
+            options.fix_orientation,
+        );
+        // A failed read usually makes MFEM fail too, but the I/O error is more to the point
+        if let Some(err) = rust_read.take_error() {
+            return Err(MfemError::Io(err));
+        }
+        Ok(Self { inner: result? })
+    }
+
+    /// Create a segment `[0, sx]` divided into `n` equal elements.
+    pub fn cartesian_1d(n: i32, sx: f64) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::Mesh_MakeCartesian1D(n, sx)?;
+        Ok(Self { inner })
+    }
+
+    /// Create a rectangle `[0, sx] x [0, sy]` divided into `nx x ny` quadrilaterals,
+    /// or `2 x nx x ny` triangles if `elem_type` is [`ElementType::TRIANGLE`].
+    ///
+    /// If `sfc_ordering` is `true`, the elements are ordered along a space-filling curve
+    /// instead of row-by-row.
+    pub fn cartesian_2d(
+        nx: i32,
+        ny: i32,
+        elem_type: ElementType,
+        generate_edges: bool,
+        [sx, sy]: [f64; 2],
+        sfc_ordering: bool,
+    ) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::Mesh_MakeCartesian2D(
+            nx,
+            ny,
+            elem_type,
+            generate_edges,
+            sx,
+            sy,
+            sfc_ordering,
+        )?;
+        Ok(Self { inner })
+    }
+
+    /// Create a box `[0, sx] x [0, sy] x [0, sz]` divided into `nx x ny x nz` hexahedra,
+    /// or the corresponding number of tetrahedra or wedges depending on `elem_type`.
+    ///
+    /// If `sfc_ordering` is `true`, the elements are ordered along a space-filling curve
+    /// instead of row-by-row.
+    pub fn cartesian_3d(
+        nx: i32,
+        ny: i32,
+        nz: i32,
+        elem_type: ElementType,
+        [sx, sy, sz]: [f64; 3],
+        sfc_ordering: bool,
+    ) -> Result<Self, MfemError> {
         let inner =
-            mfem_sys::ffi::Mesh_ctor_file(&mesh_path, generate_edges, refine, fix_orientation);
+            mfem_sys::ffi::Mesh_MakeCartesian3D(nx, ny, nz, elem_type, sx, sy, sz, sfc_ordering)?;
         Ok(Self { inner })
     }
 
@@ -146,28 +612,101 @@ impl Mesh {
         self.inner.Dimension()
     }
 
+    pub fn space_dimension(&self) -> i32 {
+        self.inner.SpaceDimension()
+    }
+
+    pub fn get_num_vertices(&self) -> i32 {
+        self.inner.GetNV()
+    }
+
     pub fn get_num_elems(&self) -> i32 {
         self.inner.GetNE()
     }
 
+    pub fn get_num_bdr_elems(&self) -> i32 {
+        self.inner.GetNBE()
+    }
+
     pub fn get_nodes<'fes, 'a: 'fes>(&'a self) -> Option<GridFunctionRef<'fes, 'a>> {
         mfem_sys::ffi::Mesh_GetNodes(&self.inner)
             .ok()
             .map(|grid_func| GridFunctionRef { inner: grid_func })
     }
 
+    pub fn get_attributes<'a>(&'a self) -> ArrayIntRef<'a> {
+        let inner = mfem_sys::ffi::Mesh_attributes(&self.inner);
+        ArrayIntRef { inner }
+    }
+
     pub fn get_bdr_attributes<'a>(&'a self) -> ArrayIntRef<'a> {
         let inner = mfem_sys::ffi::Mesh_bdr_attributes(&self.inner);
         ArrayIntRef { inner }
     }
 
-    pub fn uniform_refinement(&mut self, ref_algo: RefAlgo) {
-        self.inner.pin_mut().UniformRefinement(ref_algo as i32);
+    /// A 0/1 marker array of the given boundary attributes,
+    /// e.g. for [`FiniteElementSpace::get_essential_true_dofs`].
+    pub fn bdr_marker(&self, bdr_attrs: &[i32]) -> Result<ArrayInt, MfemError> {
+        let inner = mfem_sys::ffi::Mesh_bdr_marker(&self.inner, bdr_attrs)?;
+        Ok(ArrayInt { inner })
+    }
+
+    /// The corners `(min, max)` of the axis-aligned bounding box.
+    ///
+    /// For curved meshes, each element is sampled at `refine + 1` points per direction.
+    pub fn get_bounding_box(&self, refine: i32) -> Result<(Vec<f64>, Vec<f64>), MfemError> {
+        let mut min = Vector::new();
+        let mut max = Vector::new();
+        mfem_sys::ffi::Mesh_GetBoundingBox(
+            &self.inner,
+            min.inner.pin_mut(),
+            max.inner.pin_mut(),
+            refine,
+        )?;
+        Ok((min.as_slice().to_vec(), max.as_slice().to_vec()))
+    }
+
+    pub fn uniform_refinement(&mut self, ref_algo: RefAlgo) -> Result<(), MfemError> {
+        self.inner.pin_mut().UniformRefinement(ref_algo as i32)?;
+        Ok(())
+    }
+
+    pub fn save_to_file(&self, path: &str, precision: i32) -> Result<(), MfemError> {
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        self.save_to(&mut writer, precision)?;
+        writer.flush()?;
+        Ok(())
     }
 
-    pub fn save_to_file(&self, path: &str, precision: i32) {
-        let_cxx_string!(fname = path);
-        self.inner.Save(&fname, precision);
+    /// Write the mesh in MFEM's own format to `writer`.
+    pub fn save_to<W: Write>(&self, mut writer: W, precision: i32) -> Result<(), MfemError> {
+        let mut rust_write = mfem_sys::RustWrite::new(&mut writer);
+        let result = mfem_sys::ffi::Mesh_Print(&self.inner, &mut rust_write, precision);
+        if let Some(err) = rust_write.take_error() {
+            return Err(MfemError::Io(err));
+        }
+        Ok(result?)
+    }
+}
+
+/// Options for loading a [`Mesh`] from a file or a reader.
+#[derive(Debug, Copy, Clone)]
+pub struct MeshLoadOptions {
+    /// Generate the edges of the mesh, defaults to `true`.
+    pub generate_edges: bool,
+    /// Mark tetrahedra for refinement, defaults to `true`.
+    pub refine: bool,
+    /// Fix the orientation of inverted elements, defaults to `true`.
+    pub fix_orientation: bool,
+}
+
+impl Default for MeshLoadOptions {
+    fn default() -> Self {
+        Self {
+            generate_edges: true,
+            refine: true,
+            fix_orientation: true,
+        }
     }
 }
 
@@ -183,6 +722,205 @@ pub enum RefAlgo {
     B = 1,
 }
 
+/////////////////
+// MeshBuilder //
+/////////////////
+
+pub use mfem_sys::ffi::ElementType;
+
+/// Builds a [`Mesh`] from vertex coordinates and element connectivity held in memory.
+///
+/// Vertices are numbered in the order they are added, starting from 0.
+/// Attributes must be positive, as required by MFEM.
+pub struct MeshBuilder {
+    dim: i32,
+    space_dim: i32,
+    vertices: Vec<f64>,
+    elements: Vec<MeshBuilderElement>,
+    bdr_elements: Vec<MeshBuilderElement>,
+    refine: bool,
+    fix_orientation: bool,
+}
+
+struct MeshBuilderElement {
+    elem_type: ElementType,
+    vertices: Vec<i32>,
+    attribute: i32,
+}
+
+impl MeshBuilder {
+    pub fn new(dim: i32, space_dim: i32) -> Self {
+        Self {
+            dim,
+            space_dim,
+            vertices: vec![],
+            elements: vec![],
+            bdr_elements: vec![],
+            refine: false,
+            fix_orientation: true,
+        }
+    }
+
+    /// Add a vertex with `space_dim` coordinates.
+    pub fn add_vertex(&mut self, coords: &[f64]) -> &mut Self {
+        self.vertices.extend_from_slice(coords);
+        self
+    }
+
+    /// Add an element of dimension `dim`, given by the indices of its vertices.
+    pub fn add_element(
+        &mut self,
+        elem_type: ElementType,
+        vertices: &[i32],
+        attribute: i32,
+    ) -> &mut Self {
+        self.elements.push(MeshBuilderElement {
+            elem_type,
+            vertices: vertices.to_vec(),
+            attribute,
+        });
+        self
+    }
+
+    /// Add a boundary element of dimension `dim - 1`, given by the indices of its vertices.
+    ///
+    /// If no boundary elements are added, they are generated from the elements.
+    pub fn add_boundary_element(
+        &mut self,
+        elem_type: ElementType,
+        vertices: &[i32],
+        attribute: i32,
+    ) -> &mut Self {
+        self.bdr_elements.push(MeshBuilderElement {
+            elem_type,
+            vertices: vertices.to_vec(),
+            attribute,
+        });
+        self
+    }
+
+    /// Passed on to `Mesh::Finalize()`, defaults to `false`.
+    pub fn refine(&mut self, refine: bool) -> &mut Self {
+        self.refine = refine;
+        self
+    }
+
+    /// Passed on to `Mesh::Finalize()`, defaults to `true`.
+    pub fn fix_orientation(&mut self, fix_orientation: bool) -> &mut Self {
+        self.fix_orientation = fix_orientation;
+        self
+    }
+
+    pub fn build(&self) -> Result<Mesh, MfemError> {
+        if !(1..=3).contains(&self.dim) || !(self.dim..=3).contains(&self.space_dim) {
+            return Err(MfemError::InvalidMesh(format!(
+                "unsupported dimensions: dim = {}, space_dim = {}",
+                self.dim, self.space_dim,
+            )));
+        }
+        let space_dim = self.space_dim as usize;
+        if !self.vertices.len().is_multiple_of(space_dim) {
+            return Err(MfemError::InvalidMesh(format!(
+                "{} vertex coordinates is not a multiple of space_dim = {}",
+                self.vertices.len(),
+                space_dim,
+            )));
+        }
+        let num_vert = self.vertices.len() / space_dim;
+
+        for (kind, elems, dim) in [
+            ("element", &self.elements, self.dim),
+            ("boundary element", &self.bdr_elements, self.dim - 1),
+        ] {
+            for (i, elem) in elems.iter().enumerate() {
+                elem.check(num_vert, dim)
+                    .map_err(|msg| MfemError::InvalidMesh(format!("{kind} {i}: {msg}")))?;
+            }
+        }
+
+        let mut inner = mfem_sys::ffi::Mesh_ctor_sizes(
+            self.dim,
+            num_vert as i32,
+            self.elements.len() as i32,
+            self.bdr_elements.len() as i32,
+            self.space_dim,
+        )?;
+        for coords in self.vertices.chunks(space_dim) {
+            mfem_sys::ffi::Mesh_AddVertex(inner.pin_mut(), coords)
+                .map_err(|e| MfemError::InvalidMesh(e.what().to_owned()))?;
+        }
+        for elem in &self.elements {
+            let add = match elem.elem_type {
+                ElementType::SEGMENT => mfem_sys::ffi::Mesh_AddSegment,
+                ElementType::TRIANGLE => mfem_sys::ffi::Mesh_AddTriangle,
+                ElementType::QUADRILATERAL => mfem_sys::ffi::Mesh_AddQuad,
+                ElementType::TETRAHEDRON => mfem_sys::ffi::Mesh_AddTet,
+                ElementType::WEDGE => mfem_sys::ffi::Mesh_AddWedge,
+                ElementType::HEXAHEDRON => mfem_sys::ffi::Mesh_AddHex,
+                _ => unreachable!("checked above"),
+            };
+            add(inner.pin_mut(), &elem.vertices, elem.attribute)
+                .map_err(|e| MfemError::InvalidMesh(e.what().to_owned()))?;
+        }
+        for elem in &self.bdr_elements {
+            let add = match elem.elem_type {
+                ElementType::POINT => mfem_sys::ffi::Mesh_AddBdrPoint,
+                ElementType::SEGMENT => mfem_sys::ffi::Mesh_AddBdrSegment,
+                ElementType::TRIANGLE => mfem_sys::ffi::Mesh_AddBdrTriangle,
+                ElementType::QUADRILATERAL => mfem_sys::ffi::Mesh_AddBdrQuad,
+                _ => unreachable!("checked above"),
+            };
+            add(inner.pin_mut(), &elem.vertices, elem.attribute)
+                .map_err(|e| MfemError::InvalidMesh(e.what().to_owned()))?;
+        }
+
+        inner.pin_mut().FinalizeTopology(true)?;
+        inner
+            .pin_mut()
+            .Finalize(self.refine, self.fix_orientation)?;
+        Ok(Mesh { inner })
+    }
+}
+
+impl MeshBuilderElement {
+    fn check(&self, num_vert: usize, dim: i32) -> Result<(), String> {
+        let (elem_dim, elem_num_vert) = match self.elem_type {
+            ElementType::POINT => (0, 1),
+            ElementType::SEGMENT => (1, 2),
+            ElementType::TRIANGLE => (2, 3),
+            ElementType::QUADRILATERAL => (2, 4),
+            ElementType::TETRAHEDRON => (3, 4),
+            ElementType::WEDGE => (3, 6),
+            ElementType::HEXAHEDRON => (3, 8),
+            _ => return Err(format!("unsupported element type {:?}", self.elem_type)),
+        };
+        if elem_dim != dim {
+            return Err(format!(
+                "{:?} does not have dimension {dim}",
+                self.elem_type
+            ));
+        }
+        if self.vertices.len() != elem_num_vert {
+            return Err(format!(
+                "{:?} needs {elem_num_vert} vertices, got {}",
+                self.elem_type,
+                self.vertices.len(),
+            ));
+        }
+        if let Some(v) = self
+            .vertices
+            .iter()
+            .find(|&&v| v < 0 || v as usize >= num_vert)
+        {
+            return Err(format!("vertex index {v} out of range 0..{num_vert}"));
+        }
+        if self.attribute < 1 {
+            return Err(format!("attribute {} is not positive", self.attribute));
+        }
+        Ok(())
+    }
+}
+
 pub use mfem_sys::ffi::BasisType;
 
 /////////////////////////////
@@ -209,9 +947,9 @@ pub struct H1FeCollection {
 }
 
 impl H1FeCollection {
-    pub fn new(p: i32, dim: i32, btype: BasisType) -> Self {
-        let inner = mfem_sys::ffi::H1_FECollection_ctor(p, dim, btype.repr);
-        Self { inner }
+    pub fn new(p: i32, dim: i32, btype: BasisType) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::H1_FECollection_ctor(p, dim, btype.repr)?;
+        Ok(Self { inner })
     }
 }
 
@@ -223,44 +961,448 @@ impl AsBase<mfem_sys::ffi::FiniteElementCollection> for H1FeCollection {
     }
 }
 
-////////////////////////
-// FiniteElementSpace //
-////////////////////////
-
-pub use mfem_sys::ffi::OrderingType;
+/////////////////////
+// L2_FECollection //
+/////////////////////
 
-pub struct FiniteElementSpace<'mesh, 'fec> {
-    inner: UniquePtr<mfem_sys::ffi::FiniteElementSpace<'mesh, 'fec>>,
+/// Discontinuous elements, e.g. for DG methods.
+pub struct L2FeCollection {
+    inner: UniquePtr<mfem_sys::ffi::L2_FECollection>,
 }
 
-impl<'mesh, 'fec> FiniteElementSpace<'mesh, 'fec> {
-    pub fn new(
-        mesh: &'mesh Mesh,
-        fec: &'fec dyn FiniteElementCollection,
-        vdim: i32,
-        ordering: OrderingType,
-    ) -> Self {
-        let inner =
-            mfem_sys::ffi::FiniteElementSpace_ctor(&mesh.inner, &fec.as_base(), vdim, ordering);
-        Self { inner }
+impl L2FeCollection {
+    pub fn new(p: i32, dim: i32, btype: BasisType) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::L2_FECollection_ctor(p, dim, btype.repr)?;
+        Ok(Self { inner })
     }
+}
 
-    pub fn get_true_vsize(&self) -> i32 {
-        self.inner.GetTrueVSize()
-    }
+impl FiniteElementCollection for L2FeCollection {}
 
-    pub fn get_essential_true_dofs(
+impl AsBase<mfem_sys::ffi::FiniteElementCollection> for L2FeCollection {
+    fn as_base(&self) -> &mfem_sys::ffi::FiniteElementCollection {
+        mfem_sys::ffi::L2_FECollection_as_FEC(&self.inner)
+    }
+}
+
+/////////////////////
+// ND_FECollection //
+/////////////////////
+
+/// Nédélec elements for H(curl), e.g. for electromagnetics.
+///
+/// `cb_type` is the basis type for the closed (continuous) and `ob_type` for the open
+/// (discontinuous) part, e.g. [`BasisType::GaussLobatto`] and [`BasisType::GaussLegendre`].
+pub struct NdFeCollection {
+    inner: UniquePtr<mfem_sys::ffi::ND_FECollection>,
+}
+
+impl NdFeCollection {
+    pub fn new(
+        p: i32,
+        dim: i32,
+        cb_type: BasisType,
+        ob_type: BasisType,
+    ) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::ND_FECollection_ctor(p, dim, cb_type.repr, ob_type.repr)?;
+        Ok(Self { inner })
+    }
+}
+
+impl FiniteElementCollection for NdFeCollection {}
+
+impl AsBase<mfem_sys::ffi::FiniteElementCollection> for NdFeCollection {
+    fn as_base(&self) -> &mfem_sys::ffi::FiniteElementCollection {
+        mfem_sys::ffi::ND_FECollection_as_FEC(&self.inner)
+    }
+}
+
+/////////////////////
+// RT_FECollection //
+/////////////////////
+
+/// Raviart-Thomas elements for H(div), e.g. for Darcy flow.
+///
+/// See [`NdFeCollection`] for the meaning of `cb_type` and `ob_type`.
+pub struct RtFeCollection {
+    inner: UniquePtr<mfem_sys::ffi::RT_FECollection>,
+}
+
+impl RtFeCollection {
+    pub fn new(
+        p: i32,
+        dim: i32,
+        cb_type: BasisType,
+        ob_type: BasisType,
+    ) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::RT_FECollection_ctor(p, dim, cb_type.repr, ob_type.repr)?;
+        Ok(Self { inner })
+    }
+}
+
+impl FiniteElementCollection for RtFeCollection {}
+
+impl AsBase<mfem_sys::ffi::FiniteElementCollection> for RtFeCollection {
+    fn as_base(&self) -> &mfem_sys::ffi::FiniteElementCollection {
+        mfem_sys::ffi::RT_FECollection_as_FEC(&self.inner)
+    }
+}
+
+///////////////////
+// FiniteElement //
+///////////////////
+
+/// The reference element of a mesh element, with its shape functions.
+///
+/// The `calc_*` methods resize their output to fit.
+pub struct FiniteElement<'a> {
+    inner: &'a mfem_sys::ffi::FiniteElement,
+}
+
+impl<'a> FiniteElement<'a> {
+    /// The dimension of the reference element.
+    pub fn dim(&self) -> i32 {
+        self.inner.GetDim()
+    }
+
+    /// The number of degrees of freedom, i.e. shape functions.
+    pub fn dof(&self) -> i32 {
+        self.inner.GetDof()
+    }
+
+    pub fn order(&self) -> i32 {
+        self.inner.GetOrder()
+    }
+
+    /// The values of the shape functions at `ip` in the reference element.
+    ///
+    /// `shape` is resized to [`FiniteElement::dof`].
+    pub fn calc_shape(&self, ip: &IntegrationPoint, shape: &mut Vector) -> Result<(), MfemError> {
+        mfem_sys::ffi::FiniteElement_CalcShape(self.inner, ip.inner, shape.inner.pin_mut())?;
+        Ok(())
+    }
+
+    /// The reference gradients of the shape functions at `ip`, one row per shape function.
+    pub fn calc_dshape(
+        &self,
+        ip: &IntegrationPoint,
+        dshape: &mut DenseMatrix,
+    ) -> Result<(), MfemError> {
+        mfem_sys::ffi::FiniteElement_CalcDShape(self.inner, ip.inner, dshape.inner.pin_mut())?;
+        Ok(())
+    }
+
+    /// Like [`FiniteElement::calc_shape`], at the integration point of `trans`.
+    ///
+    /// Panics if no integration point was set, see [`ElementTransformation::set_int_point`].
+    pub fn calc_phys_shape(
+        &self,
+        trans: &mut ElementTransformation,
+        shape: &mut Vector,
+    ) -> Result<(), MfemError> {
+        mfem_sys::ffi::FiniteElement_CalcPhysShape(
+            self.inner,
+            trans.with_int_point(),
+            shape.inner.pin_mut(),
+        )?;
+        Ok(())
+    }
+
+    /// The physical gradients of the shape functions at the integration point of `trans`,
+    /// one row per shape function.
+    ///
+    /// Panics if no integration point was set, see [`ElementTransformation::set_int_point`].
+    pub fn calc_phys_dshape(
+        &self,
+        trans: &mut ElementTransformation,
+        dshape: &mut DenseMatrix,
+    ) -> Result<(), MfemError> {
+        mfem_sys::ffi::FiniteElement_CalcPhysDShape(
+            self.inner,
+            trans.with_int_point(),
+            dshape.inner.pin_mut(),
+        )?;
+        Ok(())
+    }
+}
+
+//////////////////////
+// IntegrationPoint //
+//////////////////////
+
+/// A quadrature point in reference coordinates.
+#[derive(Clone, Copy)]
+pub struct IntegrationPoint<'a> {
+    inner: &'a mfem_sys::ffi::IntegrationPoint,
+}
+
+impl<'a> IntegrationPoint<'a> {
+    /// The reference coordinates, padded with zeros up to 3D.
+    pub fn coords(&self) -> [f64; 3] {
+        let mut p = [0.0; 3];
+        mfem_sys::ffi::IntegrationPoint_Get(self.inner, &mut p);
+        p
+    }
+
+    pub fn weight(&self) -> f64 {
+        mfem_sys::ffi::IntegrationPoint_weight(self.inner)
+    }
+}
+
+/////////////////////
+// IntegrationRule //
+/////////////////////
+
+/// A quadrature rule from MFEM's global table, which lives as long as the program.
+#[derive(Clone, Copy)]
+pub struct IntegrationRule {
+    inner: &'static mfem_sys::ffi::IntegrationRule,
+}
+
+impl IntegrationRule {
+    /// A rule for the geometry of `el` that is exact for polynomials of degree `order`.
+    pub fn for_element(el: &FiniteElement, order: i32) -> Result<Self, MfemError> {
+        let geom = mfem_sys::ffi::FiniteElement_GetGeomType(el.inner);
+        let inner = mfem_sys::ffi::IntRules_Get(geom, order)?;
+        Ok(Self { inner })
+    }
+
+    /// A rule for the face of `trans` that is exact for polynomials of degree `order`.
+    pub fn for_face(trans: &FaceElementTransformations, order: i32) -> Result<Self, MfemError> {
+        let geom = mfem_sys::ffi::FaceElementTransformations_GetGeometryType(&trans.inner);
+        let inner = mfem_sys::ffi::IntRules_Get(geom, order)?;
+        Ok(Self { inner })
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.GetNPoints() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Panics if `i` is out of bounds.
+    pub fn point(&self, i: usize) -> IntegrationPoint<'static> {
+        let len = self.len();
+        assert!(
+            i < len,
+            "index {i} out of bounds for {len} integration points"
+        );
+        let inner = mfem_sys::ffi::IntegrationRule_IntPoint(self.inner, i as i32);
+        IntegrationPoint { inner }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = IntegrationPoint<'static>> {
+        let rule = *self;
+        (0..self.len()).map(move |i| rule.point(i))
+    }
+}
+
+///////////////////////////
+// ElementTransformation //
+///////////////////////////
+
+/// The map from the reference element to a mesh element.
+pub struct ElementTransformation<'a> {
+    inner: std::pin::Pin<&'a mut mfem_sys::ffi::ElementTransformation>,
+    has_int_point: bool,
+}
+
+impl<'a> ElementTransformation<'a> {
+    fn new(inner: std::pin::Pin<&'a mut mfem_sys::ffi::ElementTransformation>) -> Self {
+        Self {
+            inner,
+            has_int_point: false,
+        }
+    }
+
+    // MFEM doesn't check this and would dereference a null pointer instead.
+    fn with_int_point(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::ElementTransformation> {
+        assert!(
+            self.has_int_point,
+            "ElementTransformation: set_int_point() must be called first"
+        );
+        self.inner.as_mut()
+    }
+
+    /// The index of the mesh element.
+    pub fn element_no(&self) -> i32 {
+        mfem_sys::ffi::ElementTransformation_ElementNo(&self.inner)
+    }
+
+    /// The attribute of the mesh element.
+    pub fn attribute(&self) -> i32 {
+        mfem_sys::ffi::ElementTransformation_Attribute(&self.inner)
+    }
+
+    pub fn space_dim(&self) -> i32 {
+        self.inner.GetSpaceDim()
+    }
+
+    /// The polynomial degree of [`ElementTransformation::weight`], for choosing an [`IntegrationRule`].
+    pub fn order_w(&self) -> i32 {
+        self.inner.OrderW()
+    }
+
+    /// Set the point at which [`ElementTransformation::weight`] and the `calc_phys_*` methods
+    /// of [`FiniteElement`] are evaluated.
+    pub fn set_int_point(&mut self, ip: &IntegrationPoint<'static>) {
+        mfem_sys::ffi::ElementTransformation_SetIntPoint(self.inner.as_mut(), ip.inner);
+        self.has_int_point = true;
+    }
+
+    /// The determinant of the Jacobian at the integration point, i.e. the volume element.
+    ///
+    /// Panics if no integration point was set, see [`ElementTransformation::set_int_point`].
+    pub fn weight(&mut self) -> f64 {
+        mfem_sys::ffi::ElementTransformation_Weight(self.with_int_point())
+    }
+
+    /// The physical coordinates of the reference point `ip`.
+    ///
+    /// `x` is resized to the space dimension.
+    pub fn transform(&mut self, ip: &IntegrationPoint, x: &mut Vector) -> Result<(), MfemError> {
+        mfem_sys::ffi::ElementTransformation_Transform(
+            self.inner.as_mut(),
+            ip.inner,
+            x.inner.pin_mut(),
+        )?;
+        Ok(())
+    }
+}
+
+////////////////////////////////
+// FaceElementTransformations //
+////////////////////////////////
+
+/// The map from a reference face to a mesh face, together with the elements on both sides.
+pub struct FaceElementTransformations<'a> {
+    inner: std::pin::Pin<&'a mut mfem_sys::ffi::FaceElementTransformations>,
+    has_int_point: bool,
+}
+
+impl<'a> FaceElementTransformations<'a> {
+    fn new(inner: std::pin::Pin<&'a mut mfem_sys::ffi::FaceElementTransformations>) -> Self {
+        Self {
+            inner,
+            has_int_point: false,
+        }
+    }
+
+    fn with_int_point(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::FaceElementTransformations> {
+        assert!(
+            self.has_int_point,
+            "FaceElementTransformations: set_all_int_points() must be called first"
+        );
+        self.inner.as_mut()
+    }
+
+    /// The index of the element on the first side of the face.
+    pub fn elem1_no(&self) -> i32 {
+        mfem_sys::ffi::FaceElementTransformations_Elem1No(&self.inner)
+    }
+
+    /// The index of the element on the second side of the face, or `None` on the boundary.
+    pub fn elem2_no(&self) -> Option<i32> {
+        let elem2_no = mfem_sys::ffi::FaceElementTransformations_Elem2No(&self.inner);
+        (elem2_no >= 0).then_some(elem2_no)
+    }
+
+    /// Set the point `face_ip` on the face, and the matching points in the elements on both sides,
+    /// see [`FaceElementTransformations::elem1_int_point`].
+    pub fn set_all_int_points(&mut self, face_ip: &IntegrationPoint<'static>) {
+        mfem_sys::ffi::FaceElementTransformations_SetAllIntPoints(
+            self.inner.as_mut(),
+            face_ip.inner,
+        );
+        self.has_int_point = true;
+    }
+
+    /// The integration point in the reference coordinates of the first element.
+    ///
+    /// Panics if no integration point was set, see [`FaceElementTransformations::set_all_int_points`].
+    pub fn elem1_int_point(&self) -> IntegrationPoint<'_> {
+        assert!(
+            self.has_int_point,
+            "FaceElementTransformations: set_all_int_points() must be called first"
+        );
+        let inner = mfem_sys::ffi::FaceElementTransformations_GetElement1IntPoint(&self.inner);
+        IntegrationPoint { inner }
+    }
+
+    /// Like [`FaceElementTransformations::elem1_int_point`], for the second element.
+    pub fn elem2_int_point(&self) -> IntegrationPoint<'_> {
+        assert!(
+            self.has_int_point,
+            "FaceElementTransformations: set_all_int_points() must be called first"
+        );
+        let inner = mfem_sys::ffi::FaceElementTransformations_GetElement2IntPoint(&self.inner);
+        IntegrationPoint { inner }
+    }
+
+    /// The area element of the face at the integration point.
+    ///
+    /// Panics if no integration point was set, see [`FaceElementTransformations::set_all_int_points`].
+    pub fn weight(&mut self) -> f64 {
+        let face = mfem_sys::ffi::FaceElementTransformations_as_mut_ElementTransformation(
+            self.with_int_point(),
+        );
+        mfem_sys::ffi::ElementTransformation_Weight(face)
+    }
+
+    /// The normal at the integration point, pointing from the first element to the second one,
+    /// and scaled by the area element. `nor` is resized to the space dimension.
+    ///
+    /// Panics if no integration point was set, see [`FaceElementTransformations::set_all_int_points`].
+    pub fn normal(&mut self, nor: &mut Vector) -> Result<(), MfemError> {
+        mfem_sys::ffi::FaceElementTransformations_Normal(
+            self.with_int_point(),
+            nor.inner.pin_mut(),
+        )?;
+        Ok(())
+    }
+}
+
+////////////////////////
+// FiniteElementSpace //
+////////////////////////
+
+pub use mfem_sys::ffi::OrderingType;
+
+pub struct FiniteElementSpace<'mesh, 'fec> {
+    inner: UniquePtr<mfem_sys::ffi::FiniteElementSpace<'mesh, 'fec>>,
+}
+
+impl<'mesh, 'fec> FiniteElementSpace<'mesh, 'fec> {
+    pub fn new(
+        mesh: &'mesh Mesh,
+        fec: &'fec dyn FiniteElementCollection,
+        vdim: i32,
+        ordering: OrderingType,
+    ) -> Result<Self, MfemError> {
+        let inner =
+            mfem_sys::ffi::FiniteElementSpace_ctor(&mesh.inner, &fec.as_base(), vdim, ordering)?;
+        Ok(Self { inner })
+    }
+
+    pub fn get_true_vsize(&self) -> i32 {
+        self.inner.GetTrueVSize()
+    }
+
+    pub fn get_essential_true_dofs(
         &self,
         bdr_attr_is_ess: &ArrayInt,
         ess_tdof_list: &mut ArrayInt,
         component: Option<usize>,
-    ) {
+    ) -> Result<(), MfemError> {
         mfem_sys::ffi::FiniteElementSpace_GetEssentialTrueDofs(
             &self.inner,
             &bdr_attr_is_ess.inner,
             ess_tdof_list.inner.pin_mut(),
             component.map(|c| c as i32).unwrap_or(-1),
-        );
+        )?;
+        Ok(())
     }
 }
 
@@ -277,9 +1419,9 @@ pub struct GridFunctionRef<'fes, 'a> {
 }
 
 impl<'fes> GridFunction<'fes> {
-    pub fn new(fespace: &'fes FiniteElementSpace) -> Self {
-        let inner = mfem_sys::ffi::GridFunction_ctor_fes(&fespace.inner);
-        Self { inner }
+    pub fn new(fespace: &'fes FiniteElementSpace) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::GridFunction_ctor_fes(&fespace.inner)?;
+        Ok(Self { inner })
     }
 
     /// Project `coeff` [`Coefficient`] to this [`GridFunction`].
@@ -287,17 +1429,59 @@ impl<'fes> GridFunction<'fes> {
     /// The projection computation depends on the choice of the [`FiniteElementSpace`] `fespace`.
     ///
     /// Note that this is usually interpolation at the degrees of freedom in each element (not L2 projection).
-    pub fn project_coefficient(&mut self, coeff: &dyn Coefficient) {
-        mfem_sys::ffi::GridFunction_ProjectCoefficient(self.inner.pin_mut(), coeff.as_base());
+    pub fn project_coefficient(&mut self, coeff: &dyn Coefficient) -> Result<(), MfemError> {
+        mfem_sys::ffi::GridFunction_ProjectCoefficient(self.inner.pin_mut(), coeff.as_base())?;
+        Ok(())
+    }
+
+    /// Like [`GridFunction::project_coefficient`], for a vector [`FiniteElementSpace`],
+    /// or for H(curl) and H(div) spaces, see [`NdFeCollection`] and [`RtFeCollection`].
+    pub fn project_vector_coefficient(
+        &mut self,
+        coeff: &dyn VectorCoefficient,
+    ) -> Result<(), MfemError> {
+        mfem_sys::ffi::GridFunction_ProjectVectorCoefficient(
+            self.inner.pin_mut(),
+            coeff.as_base(),
+        )?;
+        Ok(())
+    }
+
+    /// The L2 norm of the difference from the exact solution `exsol`.
+    pub fn compute_l2_error(&self, exsol: &dyn Coefficient) -> Result<f64, MfemError> {
+        Ok(mfem_sys::ffi::GridFunction_ComputeL2Error(
+            &self.inner,
+            exsol.as_base(),
+        )?)
+    }
+
+    /// Like [`GridFunction::compute_l2_error`], for a vector-valued exact solution.
+    pub fn compute_vector_l2_error(&self, exsol: &dyn VectorCoefficient) -> Result<f64, MfemError> {
+        Ok(mfem_sys::ffi::GridFunction_ComputeL2Error_vector(
+            &self.inner,
+            exsol.as_base(),
+        )?)
     }
 
     pub fn set_all(&mut self, value: f64) {
         mfem_sys::ffi::GridFunction_SetAll(self.inner.pin_mut(), value);
     }
 
-    pub fn save_to_file(&self, path: &str, precision: i32) {
-        let_cxx_string!(fname = path);
-        mfem_sys::ffi::GridFunction_Save(&self.inner, &fname, precision);
+    pub fn save_to_file(&self, path: &str, precision: i32) -> Result<(), MfemError> {
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        self.save_to(&mut writer, precision)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Write the grid function in MFEM's own format to `writer`.
+    pub fn save_to<W: Write>(&self, mut writer: W, precision: i32) -> Result<(), MfemError> {
+        let mut rust_write = mfem_sys::RustWrite::new(&mut writer);
+        let result = mfem_sys::ffi::GridFunction_Print(&self.inner, &mut rust_write, precision);
+        if let Some(err) = rust_write.take_error() {
+            return Err(MfemError::Io(err));
+        }
+        Ok(result?)
     }
 }
 
@@ -309,8 +1493,12 @@ impl<'fes, 'a> GridFunctionRef<'fes, 'a> {
     }
 }
 
+impl<'fes> VectorRead for GridFunction<'fes> {}
+
 impl<'fes> VectorLike for GridFunction<'fes> {}
 
+impl_vector_ops!(GridFunction<'fes>);
+
 impl<'fes> AsBase<mfem_sys::ffi::Vector> for GridFunction<'fes> {
     fn as_base(&self) -> &mfem_sys::ffi::Vector {
         mfem_sys::ffi::GridFunction_as_Vector(&self.inner)
@@ -327,30 +1515,114 @@ impl<'fes> AsBaseMut<mfem_sys::ffi::Vector> for GridFunction<'fes> {
 // LinearForm //
 ////////////////
 
+/// A linear form `b(v)` on a finite element space.
+///
+/// The form keeps its integrators, so they and the coefficients they borrow
+/// need to live for `'fes`, i.e. outlive the form.
 pub struct LinearForm<'fes> {
     inner: UniquePtr<mfem_sys::ffi::LinearForm<'fes>>,
+    // NOTE: Declared after `inner` so that they're dropped after it, as it refers to them.
+    bdr_markers: Vec<UniquePtr<mfem_sys::ffi::ArrayInt>>,
 }
 
 impl<'fes> LinearForm<'fes> {
-    pub fn new(fespace: &'fes FiniteElementSpace) -> Self {
-        let inner = mfem_sys::ffi::LinearForm_ctor_fes(&fespace.inner);
-        Self { inner }
+    pub fn new(fespace: &'fes FiniteElementSpace) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::LinearForm_ctor_fes(&fespace.inner)?;
+        Ok(Self {
+            inner,
+            bdr_markers: Vec::new(),
+        })
+    }
+
+    pub fn add_domain_integrator<Lfi>(&mut self, lfi: Lfi) -> Result<(), MfemError>
+    where
+        Lfi: LinearFormIntegrator + 'fes,
+    {
+        mfem_sys::ffi::LinearForm_AddDomainIntegrator(self.inner.pin_mut(), lfi.into_base())?;
+        Ok(())
+    }
+
+    /// Integrate `lfi` over the boundary elements, e.g. for Neumann conditions.
+    ///
+    /// With `Some(bdr_attrs)`, only over the boundary elements with one of those attributes.
+    pub fn add_boundary_integrator<Lfi>(
+        &mut self,
+        lfi: Lfi,
+        bdr_attrs: Option<&[i32]>,
+    ) -> Result<(), MfemError>
+    where
+        Lfi: LinearFormIntegrator + 'fes,
+    {
+        match bdr_attrs {
+            None => {
+                mfem_sys::ffi::LinearForm_AddBoundaryIntegrator(
+                    self.inner.pin_mut(),
+                    lfi.into_base(),
+                )?;
+            }
+            Some(bdr_attrs) => {
+                let marker = mfem_sys::ffi::LinearForm_AddBoundaryIntegrator_attrs(
+                    self.inner.pin_mut(),
+                    lfi.into_base(),
+                    bdr_attrs,
+                )?;
+                self.bdr_markers.push(marker);
+            }
+        }
+        Ok(())
+    }
+
+    /// Integrate `lfi` over the interior faces, e.g. for DG methods.
+    pub fn add_interior_face_integrator<Lfi>(&mut self, lfi: Lfi) -> Result<(), MfemError>
+    where
+        Lfi: LinearFormIntegrator + 'fes,
+    {
+        mfem_sys::ffi::LinearForm_AddInteriorFaceIntegrator(self.inner.pin_mut(), lfi.into_base())?;
+        Ok(())
     }
 
-    pub fn add_domain_integrator<Lfi>(&mut self, lfi: Lfi)
+    /// Integrate `lfi` over the boundary faces, e.g. for DG boundary conditions.
+    ///
+    /// With `Some(bdr_attrs)`, only over the boundary faces with one of those attributes.
+    pub fn add_bdr_face_integrator<Lfi>(
+        &mut self,
+        lfi: Lfi,
+        bdr_attrs: Option<&[i32]>,
+    ) -> Result<(), MfemError>
     where
-        Lfi: LinearFormIntegrator,
+        Lfi: LinearFormIntegrator + 'fes,
     {
-        mfem_sys::ffi::LinearForm_AddDomainIntegrator(self.inner.pin_mut(), lfi.into_base());
+        match bdr_attrs {
+            None => {
+                mfem_sys::ffi::LinearForm_AddBdrFaceIntegrator(
+                    self.inner.pin_mut(),
+                    lfi.into_base(),
+                )?;
+            }
+            Some(bdr_attrs) => {
+                let marker = mfem_sys::ffi::LinearForm_AddBdrFaceIntegrator_attrs(
+                    self.inner.pin_mut(),
+                    lfi.into_base(),
+                    bdr_attrs,
+                )?;
+                self.bdr_markers.push(marker);
+            }
+        }
+        Ok(())
     }
 
-    pub fn assemble(&mut self) {
-        self.inner.pin_mut().Assemble();
+    pub fn assemble(&mut self) -> Result<(), MfemError> {
+        self.inner.pin_mut().Assemble()?;
+        Ok(())
     }
 }
 
+impl<'fes> VectorRead for LinearForm<'fes> {}
+
 impl<'fes> VectorLike for LinearForm<'fes> {}
 
+impl_vector_ops!(LinearForm<'fes>);
+
 impl<'fes> AsBase<mfem_sys::ffi::Vector> for LinearForm<'fes> {
     fn as_base(&self) -> &mfem_sys::ffi::Vector {
         mfem_sys::ffi::LinearForm_as_Vector(&self.inner)
@@ -368,7 +1640,144 @@ impl<'fes> AsBaseMut<mfem_sys::ffi::Vector> for LinearForm<'fes> {
 /////////////////
 
 pub trait Coefficient: AsBase<mfem_sys::ffi::Coefficient> {
-    // TODO(mkovaxx)
+    /// Set the time passed to time-dependent coefficients, e.g. [`FunctionCoefficient::with_time`].
+    ///
+    /// This can't be called while integrators borrow the coefficient. To reassemble a form
+    /// at successive time levels, have a [`FunctionCoefficient`] closure read the time from
+    /// e.g. a [`Cell`](std::cell::Cell) that's updated between assemblies instead.
+    /// Composite coefficients, e.g. [`SumCoefficient`], pass the time on to their operands.
+    fn set_time(&mut self, t: f64) {
+        mfem_sys::ffi::Coefficient_SetTime(AsBase::<mfem_sys::ffi::Coefficient>::as_base(self), t);
+    }
+
+    fn get_time(&self) -> f64 {
+        mfem_sys::ffi::Coefficient_GetTime(self.as_base())
+    }
+}
+
+/// Implements arithmetic between references to scalar coefficients, e.g. `&a + &b`, `2.0 * &a`.
+///
+/// The results borrow their operands, so intermediate results need to be bound to a variable,
+/// e.g. `let ab = &a + &b; let q = &ab / &c;`.
+macro_rules! impl_coefficient_ops {
+    ($ty:ident $(<$lt:lifetime>)?) => {
+        impl<'a, $($lt,)? Rhs: Coefficient + ?Sized> std::ops::Add<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = SumCoefficient<'a>;
+
+            fn add(self, rhs: &'a Rhs) -> SumCoefficient<'a> {
+                let inner = mfem_sys::ffi::SumCoefficient_ctor(self.as_base(), rhs.as_base(), 1.0, 1.0);
+                SumCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: Coefficient + ?Sized> std::ops::Sub<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = SumCoefficient<'a>;
+
+            fn sub(self, rhs: &'a Rhs) -> SumCoefficient<'a> {
+                let inner = mfem_sys::ffi::SumCoefficient_ctor(self.as_base(), rhs.as_base(), 1.0, -1.0);
+                SumCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: Coefficient + ?Sized> std::ops::Mul<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = ProductCoefficient<'a>;
+
+            fn mul(self, rhs: &'a Rhs) -> ProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ProductCoefficient_ctor(self.as_base(), rhs.as_base());
+                ProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: Coefficient + ?Sized> std::ops::Div<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = RatioCoefficient<'a>;
+
+            fn div(self, rhs: &'a Rhs) -> RatioCoefficient<'a> {
+                let inner = mfem_sys::ffi::RatioCoefficient_ctor(self.as_base(), rhs.as_base());
+                RatioCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Add<f64> for &'a $ty$(<$lt>)? {
+            type Output = SumCoefficient<'a>;
+
+            fn add(self, rhs: f64) -> SumCoefficient<'a> {
+                let inner = mfem_sys::ffi::SumCoefficient_ctor_const(rhs, self.as_base(), 1.0, 1.0);
+                SumCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Add<&'a $ty$(<$lt>)?> for f64 {
+            type Output = SumCoefficient<'a>;
+
+            fn add(self, rhs: &'a $ty$(<$lt>)?) -> SumCoefficient<'a> {
+                let inner = mfem_sys::ffi::SumCoefficient_ctor_const(self, rhs.as_base(), 1.0, 1.0);
+                SumCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Sub<f64> for &'a $ty$(<$lt>)? {
+            type Output = SumCoefficient<'a>;
+
+            fn sub(self, rhs: f64) -> SumCoefficient<'a> {
+                let inner = mfem_sys::ffi::SumCoefficient_ctor_const(rhs, self.as_base(), -1.0, 1.0);
+                SumCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Sub<&'a $ty$(<$lt>)?> for f64 {
+            type Output = SumCoefficient<'a>;
+
+            fn sub(self, rhs: &'a $ty$(<$lt>)?) -> SumCoefficient<'a> {
+                let inner = mfem_sys::ffi::SumCoefficient_ctor_const(self, rhs.as_base(), 1.0, -1.0);
+                SumCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Mul<f64> for &'a $ty$(<$lt>)? {
+            type Output = ProductCoefficient<'a>;
+
+            fn mul(self, rhs: f64) -> ProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ProductCoefficient_ctor_const(rhs, self.as_base());
+                ProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Mul<&'a $ty$(<$lt>)?> for f64 {
+            type Output = ProductCoefficient<'a>;
+
+            fn mul(self, rhs: &'a $ty$(<$lt>)?) -> ProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ProductCoefficient_ctor_const(self, rhs.as_base());
+                ProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Div<f64> for &'a $ty$(<$lt>)? {
+            type Output = ProductCoefficient<'a>;
+
+            fn div(self, rhs: f64) -> ProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ProductCoefficient_ctor_const(1.0 / rhs, self.as_base());
+                ProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Div<&'a $ty$(<$lt>)?> for f64 {
+            type Output = RatioCoefficient<'a>;
+
+            fn div(self, rhs: &'a $ty$(<$lt>)?) -> RatioCoefficient<'a> {
+                let inner = mfem_sys::ffi::RatioCoefficient_ctor_const(self, rhs.as_base());
+                RatioCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Neg for &'a $ty$(<$lt>)? {
+            type Output = ProductCoefficient<'a>;
+
+            fn neg(self) -> ProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ProductCoefficient_ctor_const(-1.0, self.as_base());
+                ProductCoefficient { inner }
+            }
+        }
+    };
 }
 
 /////////////////////////
@@ -394,261 +1803,3798 @@ impl AsBase<mfem_sys::ffi::Coefficient> for ConstantCoefficient {
     }
 }
 
-//////////////////////////
-// LinearFormIntegrator //
-//////////////////////////
-
-pub trait LinearFormIntegrator:
-    AsBase<mfem_sys::ffi::LinearFormIntegrator>
-    + IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
-{
-    // TODO(mkovaxx)
-}
+impl_coefficient_ops!(ConstantCoefficient);
 
-////////////////////////
-// DomainLFIntegrator //
-////////////////////////
+/////////////////////////
+// FunctionCoefficient //
+/////////////////////////
 
-pub struct DomainLFIntegrator<'coeff> {
-    inner: UniquePtr<mfem_sys::ffi::DomainLFIntegrator<'coeff>>,
+/// A [`Coefficient`] given by a Rust closure of the physical coordinates (and time).
+///
+/// If the closure panics, the MFEM operation that evaluated it returns an error.
+pub struct FunctionCoefficient<'f> {
+    inner: UniquePtr<mfem_sys::ffi::RustFunctionCoefficient<'f>>,
+    // NOTE: Owned, and freed in `drop()` after `inner`, as it refers to it.
+    func: *mut mfem_sys::RustFunction<'f>,
 }
 
-impl<'coeff> DomainLFIntegrator<'coeff> {
-    pub fn new(coeff: &'coeff dyn Coefficient, a: i32, b: i32) -> Self {
-        let inner = mfem_sys::ffi::DomainLFIntegrator_ctor_ab(coeff.as_base(), a, b);
-        Self { inner }
+impl<'f> FunctionCoefficient<'f> {
+    pub fn new<F>(func: F) -> Self
+    where
+        F: Fn(&[f64]) -> f64 + 'f,
+    {
+        Self::with_time(move |x, _t| func(x))
     }
-}
+
+    /// Like [`FunctionCoefficient::new`], but `func` also receives the time.
+    pub fn with_time<F>(func: F) -> Self
+    where
+        F: Fn(&[f64], f64) -> f64 + 'f,
+    {
+        let func = Box::into_raw(Box::new(mfem_sys::RustFunction::new(func)));
+        // SAFETY: `func` is only freed in `drop()`, after `inner`.
+        let func_ref = unsafe { &*func };
+        let inner = mfem_sys::ffi::RustFunctionCoefficient_ctor(func_ref);
+        Self { inner, func }
+    }
+}
+
+impl<'f> Drop for FunctionCoefficient<'f> {
+    fn drop(&mut self) {
+        self.inner = UniquePtr::null();
+        // SAFETY: `func` came from `Box::into_raw`, and `inner` no longer refers to it.
+        drop(unsafe { Box::from_raw(self.func) });
+    }
+}
+
+impl<'f> Coefficient for FunctionCoefficient<'f> {}
+
+impl<'f> AsBase<mfem_sys::ffi::Coefficient> for FunctionCoefficient<'f> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::RustFunctionCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(FunctionCoefficient<'f>);
+
+////////////////////////
+// PWConstCoefficient //
+////////////////////////
+
+/// A [`Coefficient`] that is constant on the parts of the mesh with the same attribute.
+///
+/// The `attributes` passed to the constructors are either [`Mesh::get_attributes`],
+/// or [`Mesh::get_bdr_attributes`] if the coefficient is used on the boundary.
+pub struct PwConstCoefficient {
+    inner: UniquePtr<mfem_sys::ffi::PWConstCoefficient>,
+}
+
+impl PwConstCoefficient {
+    /// `values[i]` is the value on attribute `i + 1`.
+    pub fn from_slice(values: &[f64], attributes: &ArrayIntRef) -> Result<Self, MfemError> {
+        if let Some(attr) = attributes
+            .iter()
+            .find(|&&attr| attr as usize > values.len())
+        {
+            return Err(MfemError::InvalidArgument(format!(
+                "no value for attribute {attr}"
+            )));
+        }
+        let inner = mfem_sys::ffi::PWConstCoefficient_ctor(values);
+        Ok(Self { inner })
+    }
+
+    /// `values[&attr]` is the value on attribute `attr`.
+    pub fn from_map(
+        values: &HashMap<i32, f64>,
+        attributes: &ArrayIntRef,
+    ) -> Result<Self, MfemError> {
+        check_attributes(values.keys(), attributes)?;
+        let max_attr = attributes.iter().copied().max().unwrap_or(0);
+        let dense: Vec<f64> = (1..=max_attr)
+            .map(|attr| values.get(&attr).copied().unwrap_or(0.0))
+            .collect();
+        let inner = mfem_sys::ffi::PWConstCoefficient_ctor(&dense);
+        Ok(Self { inner })
+    }
+}
+
+impl Coefficient for PwConstCoefficient {}
+
+impl AsBase<mfem_sys::ffi::Coefficient> for PwConstCoefficient {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::PWConstCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(PwConstCoefficient);
+
+///////////////////
+// PWCoefficient //
+///////////////////
+
+/// A [`Coefficient`] that evaluates a different [`Coefficient`] for each attribute.
+///
+/// See [`PwConstCoefficient`] for the meaning of `attributes`.
+pub struct PwCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::PWCoefficient<'coeff>>,
+}
+
+impl<'coeff> PwCoefficient<'coeff> {
+    pub fn new(
+        coeffs: &HashMap<i32, &'coeff dyn Coefficient>,
+        attributes: &ArrayIntRef,
+    ) -> Result<Self, MfemError> {
+        check_attributes(coeffs.keys(), attributes)?;
+        let mut inner = mfem_sys::ffi::PWCoefficient_ctor();
+        for (&attr, &coeff) in coeffs {
+            mfem_sys::ffi::PWCoefficient_UpdateCoefficient(inner.pin_mut(), attr, coeff.as_base());
+        }
+        Ok(Self { inner })
+    }
+}
+
+impl<'coeff> Coefficient for PwCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for PwCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::PWCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(PwCoefficient<'coeff>);
+
+/// Check that `keys` are exactly the `attributes` of a mesh.
+fn check_attributes<'a>(
+    keys: impl Iterator<Item = &'a i32> + Clone,
+    attributes: &ArrayIntRef,
+) -> Result<(), MfemError> {
+    if let Some(attr) = keys
+        .clone()
+        .find(|attr| !attributes.as_slice().contains(attr))
+    {
+        return Err(MfemError::InvalidArgument(format!(
+            "attribute {attr} is not in the mesh"
+        )));
+    }
+    if let Some(attr) = attributes
+        .iter()
+        .find(|&attr| !keys.clone().any(|key| key == attr))
+    {
+        return Err(MfemError::InvalidArgument(format!(
+            "no value for attribute {attr}"
+        )));
+    }
+    Ok(())
+}
+
+////////////////////
+// SumCoefficient //
+////////////////////
+
+/// `alpha * a + beta * b`, see also `&a + &b` and `&a - &b`.
+pub struct SumCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::SumCoefficient<'coeff>>,
+}
+
+impl<'coeff> SumCoefficient<'coeff> {
+    pub fn new(
+        a: &'coeff dyn Coefficient,
+        b: &'coeff dyn Coefficient,
+        alpha: f64,
+        beta: f64,
+    ) -> Self {
+        let inner = mfem_sys::ffi::SumCoefficient_ctor(a.as_base(), b.as_base(), alpha, beta);
+        Self { inner }
+    }
+
+    /// Like [`SumCoefficient::new`], but `a` is a constant.
+    pub fn with_constant(a: f64, b: &'coeff dyn Coefficient, alpha: f64, beta: f64) -> Self {
+        let inner = mfem_sys::ffi::SumCoefficient_ctor_const(a, b.as_base(), alpha, beta);
+        Self { inner }
+    }
+}
+
+impl<'coeff> Coefficient for SumCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for SumCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::SumCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(SumCoefficient<'coeff>);
+
+////////////////////////
+// ProductCoefficient //
+////////////////////////
+
+/// `a * b`, see also `&a * &b` and `2.0 * &b`.
+pub struct ProductCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::ProductCoefficient<'coeff>>,
+}
+
+impl<'coeff> ProductCoefficient<'coeff> {
+    pub fn new(a: &'coeff dyn Coefficient, b: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::ProductCoefficient_ctor(a.as_base(), b.as_base());
+        Self { inner }
+    }
+
+    /// Like [`ProductCoefficient::new`], but `a` is a constant.
+    pub fn with_constant(a: f64, b: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::ProductCoefficient_ctor_const(a, b.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> Coefficient for ProductCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for ProductCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::ProductCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(ProductCoefficient<'coeff>);
+
+//////////////////////
+// RatioCoefficient //
+//////////////////////
+
+/// `a / b`, see also `&a / &b` and `1.0 / &b`.
+pub struct RatioCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::RatioCoefficient<'coeff>>,
+}
+
+impl<'coeff> RatioCoefficient<'coeff> {
+    pub fn new(a: &'coeff dyn Coefficient, b: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::RatioCoefficient_ctor(a.as_base(), b.as_base());
+        Self { inner }
+    }
+
+    /// Like [`RatioCoefficient::new`], but `a` is a constant.
+    pub fn with_constant(a: f64, b: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::RatioCoefficient_ctor_const(a, b.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> Coefficient for RatioCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for RatioCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::RatioCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(RatioCoefficient<'coeff>);
+
+//////////////////////
+// PowerCoefficient //
+//////////////////////
+
+/// `a` raised to the power `p`.
+pub struct PowerCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::PowerCoefficient<'coeff>>,
+}
+
+impl<'coeff> PowerCoefficient<'coeff> {
+    pub fn new(a: &'coeff dyn Coefficient, p: f64) -> Self {
+        let inner = mfem_sys::ffi::PowerCoefficient_ctor(a.as_base(), p);
+        Self { inner }
+    }
+}
+
+impl<'coeff> Coefficient for PowerCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for PowerCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::PowerCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(PowerCoefficient<'coeff>);
+
+////////////////////////////
+// TransformedCoefficient //
+////////////////////////////
+
+/// `func(q)` or `func(q1, q2)`, evaluated pointwise.
+///
+/// Like MFEM's `TransformedCoefficient`, this takes a plain `fn` (or a closure that captures nothing).
+/// Use [`FunctionCoefficient`] for functions of the coordinates instead.
+/// If `func` panics, the MFEM operation that evaluated it returns an error.
+pub struct TransformedCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::RustTransformedCoefficient<'coeff>>,
+    // NOTE: Owned, and freed in `drop()` after `inner`, as it refers to it.
+    func: *mut mfem_sys::RustTransform,
+}
+
+impl<'coeff> TransformedCoefficient<'coeff> {
+    pub fn new(q: &'coeff dyn Coefficient, func: fn(f64) -> f64) -> Self {
+        let func = Box::into_raw(Box::new(mfem_sys::RustTransform::Unary(func)));
+        // SAFETY: `func` is only freed in `drop()`, after `inner`.
+        let func_ref = unsafe { &*func };
+        let inner = mfem_sys::ffi::RustTransformedCoefficient_ctor(q.as_base(), func_ref);
+        Self { inner, func }
+    }
+
+    pub fn new_binary(
+        q1: &'coeff dyn Coefficient,
+        q2: &'coeff dyn Coefficient,
+        func: fn(f64, f64) -> f64,
+    ) -> Self {
+        let func = Box::into_raw(Box::new(mfem_sys::RustTransform::Binary(func)));
+        // SAFETY: `func` is only freed in `drop()`, after `inner`.
+        let func_ref = unsafe { &*func };
+        let inner =
+            mfem_sys::ffi::RustTransformedCoefficient_ctor2(q1.as_base(), q2.as_base(), func_ref);
+        Self { inner, func }
+    }
+}
+
+impl<'coeff> Drop for TransformedCoefficient<'coeff> {
+    fn drop(&mut self) {
+        self.inner = UniquePtr::null();
+        // SAFETY: `func` came from `Box::into_raw`, and `inner` no longer refers to it.
+        drop(unsafe { Box::from_raw(self.func) });
+    }
+}
+
+impl<'coeff> Coefficient for TransformedCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for TransformedCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::RustTransformedCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(TransformedCoefficient<'coeff>);
+
+///////////////////////
+// VectorCoefficient //
+///////////////////////
+
+pub trait VectorCoefficient: AsBase<mfem_sys::ffi::VectorCoefficient> {
+    fn get_vdim(&self) -> i32 {
+        mfem_sys::ffi::VectorCoefficient_GetVDim(self.as_base())
+    }
+
+    /// See [`Coefficient::set_time`].
+    fn set_time(&mut self, t: f64) {
+        mfem_sys::ffi::VectorCoefficient_SetTime(
+            AsBase::<mfem_sys::ffi::VectorCoefficient>::as_base(self),
+            t,
+        );
+    }
+
+    fn get_time(&self) -> f64 {
+        mfem_sys::ffi::VectorCoefficient_GetTime(self.as_base())
+    }
+}
+
+/// Implements arithmetic between references to vector coefficients, e.g. `&u + &v`, `&v * &a`.
+///
+/// Adding coefficients with different vdims panics, use [`VectorSumCoefficient::new`] to get an error instead.
+/// Scaling by a scalar [`Coefficient`] is written as `&v * &a`, see [`ScalarVectorProductCoefficient`].
+macro_rules! impl_vector_coefficient_ops {
+    ($ty:ident $(<$lt:lifetime>)?) => {
+        impl<'a, $($lt,)? Rhs: VectorCoefficient + ?Sized> std::ops::Add<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = VectorSumCoefficient<'a>;
+
+            fn add(self, rhs: &'a Rhs) -> VectorSumCoefficient<'a> {
+                match mfem_sys::ffi::VectorSumCoefficient_ctor(self.as_base(), rhs.as_base(), 1.0, 1.0) {
+                    Ok(inner) => VectorSumCoefficient { inner },
+                    Err(e) => panic!("{}", MfemError::from(e)),
+                }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: VectorCoefficient + ?Sized> std::ops::Sub<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = VectorSumCoefficient<'a>;
+
+            fn sub(self, rhs: &'a Rhs) -> VectorSumCoefficient<'a> {
+                match mfem_sys::ffi::VectorSumCoefficient_ctor(self.as_base(), rhs.as_base(), 1.0, -1.0) {
+                    Ok(inner) => VectorSumCoefficient { inner },
+                    Err(e) => panic!("{}", MfemError::from(e)),
+                }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: Coefficient + ?Sized> std::ops::Mul<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = ScalarVectorProductCoefficient<'a>;
+
+            fn mul(self, rhs: &'a Rhs) -> ScalarVectorProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarVectorProductCoefficient_ctor(rhs.as_base(), self.as_base());
+                ScalarVectorProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Mul<f64> for &'a $ty$(<$lt>)? {
+            type Output = ScalarVectorProductCoefficient<'a>;
+
+            fn mul(self, rhs: f64) -> ScalarVectorProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarVectorProductCoefficient_ctor_const(rhs, self.as_base());
+                ScalarVectorProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Mul<&'a $ty$(<$lt>)?> for f64 {
+            type Output = ScalarVectorProductCoefficient<'a>;
+
+            fn mul(self, rhs: &'a $ty$(<$lt>)?) -> ScalarVectorProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarVectorProductCoefficient_ctor_const(self, rhs.as_base());
+                ScalarVectorProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Neg for &'a $ty$(<$lt>)? {
+            type Output = ScalarVectorProductCoefficient<'a>;
+
+            fn neg(self) -> ScalarVectorProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarVectorProductCoefficient_ctor_const(-1.0, self.as_base());
+                ScalarVectorProductCoefficient { inner }
+            }
+        }
+    };
+}
+
+///////////////////////////////
+// VectorConstantCoefficient //
+///////////////////////////////
+
+pub struct VectorConstantCoefficient {
+    inner: UniquePtr<mfem_sys::ffi::VectorConstantCoefficient>,
+}
+
+impl VectorConstantCoefficient {
+    pub fn new(value: &[f64]) -> Self {
+        let inner = mfem_sys::ffi::VectorConstantCoefficient_ctor(value);
+        Self { inner }
+    }
+}
+
+impl VectorCoefficient for VectorConstantCoefficient {}
+
+impl AsBase<mfem_sys::ffi::VectorCoefficient> for VectorConstantCoefficient {
+    fn as_base(&self) -> &mfem_sys::ffi::VectorCoefficient {
+        mfem_sys::ffi::VectorConstantCoefficient_as_VectorCoeff(&self.inner)
+    }
+}
+
+impl_vector_coefficient_ops!(VectorConstantCoefficient);
+
+///////////////////////////////
+// VectorFunctionCoefficient //
+///////////////////////////////
+
+/// A [`VectorCoefficient`] given by a Rust closure that fills in the `vdim` components
+/// from the physical coordinates (and time).
+///
+/// If the closure panics, the MFEM operation that evaluated it returns an error.
+pub struct VectorFunctionCoefficient<'f> {
+    inner: UniquePtr<mfem_sys::ffi::RustVectorFunctionCoefficient<'f>>,
+    // NOTE: Owned, and freed in `drop()` after `inner`, as it refers to it.
+    func: *mut mfem_sys::RustVectorFunction<'f>,
+}
+
+impl<'f> VectorFunctionCoefficient<'f> {
+    pub fn new<F>(vdim: i32, func: F) -> Self
+    where
+        F: Fn(&[f64], &mut [f64]) + 'f,
+    {
+        Self::with_time(vdim, move |x, _t, v| func(x, v))
+    }
+
+    /// Like [`VectorFunctionCoefficient::new`], but `func` also receives the time.
+    pub fn with_time<F>(vdim: i32, func: F) -> Self
+    where
+        F: Fn(&[f64], f64, &mut [f64]) + 'f,
+    {
+        let func = Box::into_raw(Box::new(mfem_sys::RustVectorFunction::new(func)));
+        // SAFETY: `func` is only freed in `drop()`, after `inner`.
+        let func_ref = unsafe { &*func };
+        let inner = mfem_sys::ffi::RustVectorFunctionCoefficient_ctor(vdim, func_ref);
+        Self { inner, func }
+    }
+}
+
+impl<'f> Drop for VectorFunctionCoefficient<'f> {
+    fn drop(&mut self) {
+        self.inner = UniquePtr::null();
+        // SAFETY: `func` came from `Box::into_raw`, and `inner` no longer refers to it.
+        drop(unsafe { Box::from_raw(self.func) });
+    }
+}
+
+impl<'f> VectorCoefficient for VectorFunctionCoefficient<'f> {}
+
+impl<'f> AsBase<mfem_sys::ffi::VectorCoefficient> for VectorFunctionCoefficient<'f> {
+    fn as_base(&self) -> &mfem_sys::ffi::VectorCoefficient {
+        mfem_sys::ffi::RustVectorFunctionCoefficient_as_VectorCoeff(&self.inner)
+    }
+}
+
+impl_vector_coefficient_ops!(VectorFunctionCoefficient<'f>);
+
+//////////////////////////
+// VectorSumCoefficient //
+//////////////////////////
+
+/// `alpha * a + beta * b`, see also `&a + &b` and `&a - &b`.
+pub struct VectorSumCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorSumCoefficient<'coeff>>,
+}
+
+impl<'coeff> VectorSumCoefficient<'coeff> {
+    pub fn new(
+        a: &'coeff dyn VectorCoefficient,
+        b: &'coeff dyn VectorCoefficient,
+        alpha: f64,
+        beta: f64,
+    ) -> Result<Self, MfemError> {
+        let inner =
+            mfem_sys::ffi::VectorSumCoefficient_ctor(a.as_base(), b.as_base(), alpha, beta)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'coeff> VectorCoefficient for VectorSumCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::VectorCoefficient> for VectorSumCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::VectorCoefficient {
+        mfem_sys::ffi::VectorSumCoefficient_as_VectorCoeff(&self.inner)
+    }
+}
+
+impl_vector_coefficient_ops!(VectorSumCoefficient<'coeff>);
+
+////////////////////////////////////
+// ScalarVectorProductCoefficient //
+////////////////////////////////////
+
+/// `a * b` for a scalar `a` and a vector `b`, see also `&b * &a` and `2.0 * &b`.
+pub struct ScalarVectorProductCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::ScalarVectorProductCoefficient<'coeff>>,
+}
+
+impl<'coeff> ScalarVectorProductCoefficient<'coeff> {
+    pub fn new(a: &'coeff dyn Coefficient, b: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::ScalarVectorProductCoefficient_ctor(a.as_base(), b.as_base());
+        Self { inner }
+    }
+
+    /// Like [`ScalarVectorProductCoefficient::new`], but `a` is a constant.
+    pub fn with_constant(a: f64, b: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::ScalarVectorProductCoefficient_ctor_const(a, b.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> VectorCoefficient for ScalarVectorProductCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::VectorCoefficient> for ScalarVectorProductCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::VectorCoefficient {
+        mfem_sys::ffi::ScalarVectorProductCoefficient_as_VectorCoeff(&self.inner)
+    }
+}
+
+impl_vector_coefficient_ops!(ScalarVectorProductCoefficient<'coeff>);
+
+/////////////////////////////
+// InnerProductCoefficient //
+/////////////////////////////
+
+/// The scalar `a · b` of two vector coefficients.
+pub struct InnerProductCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::InnerProductCoefficient<'coeff>>,
+}
+
+impl<'coeff> InnerProductCoefficient<'coeff> {
+    pub fn new(
+        a: &'coeff dyn VectorCoefficient,
+        b: &'coeff dyn VectorCoefficient,
+    ) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::InnerProductCoefficient_ctor(a.as_base(), b.as_base())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'coeff> Coefficient for InnerProductCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::Coefficient> for InnerProductCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::Coefficient {
+        mfem_sys::ffi::InnerProductCoefficient_as_Coeff(&self.inner)
+    }
+}
+
+impl_coefficient_ops!(InnerProductCoefficient<'coeff>);
+
+///////////////////////
+// MatrixCoefficient //
+///////////////////////
+
+pub trait MatrixCoefficient: AsBase<mfem_sys::ffi::MatrixCoefficient> {
+    fn get_height(&self) -> i32 {
+        mfem_sys::ffi::MatrixCoefficient_GetHeight(self.as_base())
+    }
+
+    fn get_width(&self) -> i32 {
+        mfem_sys::ffi::MatrixCoefficient_GetWidth(self.as_base())
+    }
+
+    /// See [`Coefficient::set_time`].
+    fn set_time(&mut self, t: f64) {
+        mfem_sys::ffi::MatrixCoefficient_SetTime(
+            AsBase::<mfem_sys::ffi::MatrixCoefficient>::as_base(self),
+            t,
+        );
+    }
+
+    fn get_time(&self) -> f64 {
+        mfem_sys::ffi::MatrixCoefficient_GetTime(self.as_base())
+    }
+}
+
+/// Implements arithmetic between references to matrix coefficients, e.g. `&m + &n`, `&m * &a`.
+///
+/// Adding coefficients of different sizes panics, use [`MatrixSumCoefficient::new`] to get an error instead.
+/// Scaling by a scalar [`Coefficient`] is written as `&m * &a`, see [`ScalarMatrixProductCoefficient`].
+macro_rules! impl_matrix_coefficient_ops {
+    ($ty:ident $(<$lt:lifetime>)?) => {
+        impl<'a, $($lt,)? Rhs: MatrixCoefficient + ?Sized> std::ops::Add<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = MatrixSumCoefficient<'a>;
+
+            fn add(self, rhs: &'a Rhs) -> MatrixSumCoefficient<'a> {
+                match mfem_sys::ffi::MatrixSumCoefficient_ctor(self.as_base(), rhs.as_base(), 1.0, 1.0) {
+                    Ok(inner) => MatrixSumCoefficient { inner },
+                    Err(e) => panic!("{}", MfemError::from(e)),
+                }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: MatrixCoefficient + ?Sized> std::ops::Sub<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = MatrixSumCoefficient<'a>;
+
+            fn sub(self, rhs: &'a Rhs) -> MatrixSumCoefficient<'a> {
+                match mfem_sys::ffi::MatrixSumCoefficient_ctor(self.as_base(), rhs.as_base(), 1.0, -1.0) {
+                    Ok(inner) => MatrixSumCoefficient { inner },
+                    Err(e) => panic!("{}", MfemError::from(e)),
+                }
+            }
+        }
+
+        impl<'a, $($lt,)? Rhs: Coefficient + ?Sized> std::ops::Mul<&'a Rhs> for &'a $ty$(<$lt>)? {
+            type Output = ScalarMatrixProductCoefficient<'a>;
+
+            fn mul(self, rhs: &'a Rhs) -> ScalarMatrixProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarMatrixProductCoefficient_ctor(rhs.as_base(), self.as_base());
+                ScalarMatrixProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Mul<f64> for &'a $ty$(<$lt>)? {
+            type Output = ScalarMatrixProductCoefficient<'a>;
+
+            fn mul(self, rhs: f64) -> ScalarMatrixProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarMatrixProductCoefficient_ctor_const(rhs, self.as_base());
+                ScalarMatrixProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Mul<&'a $ty$(<$lt>)?> for f64 {
+            type Output = ScalarMatrixProductCoefficient<'a>;
+
+            fn mul(self, rhs: &'a $ty$(<$lt>)?) -> ScalarMatrixProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarMatrixProductCoefficient_ctor_const(self, rhs.as_base());
+                ScalarMatrixProductCoefficient { inner }
+            }
+        }
+
+        impl<'a, $($lt)?> std::ops::Neg for &'a $ty$(<$lt>)? {
+            type Output = ScalarMatrixProductCoefficient<'a>;
+
+            fn neg(self) -> ScalarMatrixProductCoefficient<'a> {
+                let inner = mfem_sys::ffi::ScalarMatrixProductCoefficient_ctor_const(-1.0, self.as_base());
+                ScalarMatrixProductCoefficient { inner }
+            }
+        }
+    };
+}
+
+///////////////////////////////
+// MatrixConstantCoefficient //
+///////////////////////////////
+
+pub struct MatrixConstantCoefficient {
+    inner: UniquePtr<mfem_sys::ffi::MatrixConstantCoefficient>,
+}
+
+impl MatrixConstantCoefficient {
+    /// The `height x width` entries of `data` are in column-major order, as in MFEM's `DenseMatrix`.
+    pub fn new(height: i32, width: i32, data: &[f64]) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::MatrixConstantCoefficient_ctor(height, width, data)?;
+        Ok(Self { inner })
+    }
+}
+
+impl MatrixCoefficient for MatrixConstantCoefficient {}
+
+impl AsBase<mfem_sys::ffi::MatrixCoefficient> for MatrixConstantCoefficient {
+    fn as_base(&self) -> &mfem_sys::ffi::MatrixCoefficient {
+        mfem_sys::ffi::MatrixConstantCoefficient_as_MatrixCoeff(&self.inner)
+    }
+}
+
+impl_matrix_coefficient_ops!(MatrixConstantCoefficient);
+
+///////////////////////////////
+// MatrixFunctionCoefficient //
+///////////////////////////////
+
+/// A [`MatrixCoefficient`] given by a Rust closure that fills in the `height x width` entries
+/// (in column-major order) from the physical coordinates (and time).
+///
+/// If the closure panics, the MFEM operation that evaluated it returns an error.
+pub struct MatrixFunctionCoefficient<'f> {
+    inner: UniquePtr<mfem_sys::ffi::RustMatrixFunctionCoefficient<'f>>,
+    // NOTE: Owned, and freed in `drop()` after `inner`, as it refers to it.
+    func: *mut mfem_sys::RustMatrixFunction<'f>,
+}
+
+impl<'f> MatrixFunctionCoefficient<'f> {
+    pub fn new<F>(height: i32, width: i32, func: F) -> Self
+    where
+        F: Fn(&[f64], &mut [f64]) + 'f,
+    {
+        Self::with_time(height, width, move |x, _t, k| func(x, k))
+    }
+
+    /// Like [`MatrixFunctionCoefficient::new`], but `func` also receives the time.
+    pub fn with_time<F>(height: i32, width: i32, func: F) -> Self
+    where
+        F: Fn(&[f64], f64, &mut [f64]) + 'f,
+    {
+        let func = Box::into_raw(Box::new(mfem_sys::RustMatrixFunction::new(func)));
+        // SAFETY: `func` is only freed in `drop()`, after `inner`.
+        let func_ref = unsafe { &*func };
+        let inner = mfem_sys::ffi::RustMatrixFunctionCoefficient_ctor(height, width, func_ref);
+        Self { inner, func }
+    }
+}
+
+impl<'f> Drop for MatrixFunctionCoefficient<'f> {
+    fn drop(&mut self) {
+        self.inner = UniquePtr::null();
+        // SAFETY: `func` came from `Box::into_raw`, and `inner` no longer refers to it.
+        drop(unsafe { Box::from_raw(self.func) });
+    }
+}
+
+impl<'f> MatrixCoefficient for MatrixFunctionCoefficient<'f> {}
+
+impl<'f> AsBase<mfem_sys::ffi::MatrixCoefficient> for MatrixFunctionCoefficient<'f> {
+    fn as_base(&self) -> &mfem_sys::ffi::MatrixCoefficient {
+        mfem_sys::ffi::RustMatrixFunctionCoefficient_as_MatrixCoeff(&self.inner)
+    }
+}
+
+impl_matrix_coefficient_ops!(MatrixFunctionCoefficient<'f>);
+
+//////////////////////////
+// MatrixSumCoefficient //
+//////////////////////////
+
+/// `alpha * a + beta * b`, see also `&a + &b` and `&a - &b`.
+pub struct MatrixSumCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::MatrixSumCoefficient<'coeff>>,
+}
+
+impl<'coeff> MatrixSumCoefficient<'coeff> {
+    pub fn new(
+        a: &'coeff dyn MatrixCoefficient,
+        b: &'coeff dyn MatrixCoefficient,
+        alpha: f64,
+        beta: f64,
+    ) -> Result<Self, MfemError> {
+        let inner =
+            mfem_sys::ffi::MatrixSumCoefficient_ctor(a.as_base(), b.as_base(), alpha, beta)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'coeff> MatrixCoefficient for MatrixSumCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::MatrixCoefficient> for MatrixSumCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::MatrixCoefficient {
+        mfem_sys::ffi::MatrixSumCoefficient_as_MatrixCoeff(&self.inner)
+    }
+}
+
+impl_matrix_coefficient_ops!(MatrixSumCoefficient<'coeff>);
+
+////////////////////////////////////
+// ScalarMatrixProductCoefficient //
+////////////////////////////////////
+
+/// `a * b` for a scalar `a` and a matrix `b`, see also `&b * &a` and `2.0 * &b`.
+pub struct ScalarMatrixProductCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::ScalarMatrixProductCoefficient<'coeff>>,
+}
+
+impl<'coeff> ScalarMatrixProductCoefficient<'coeff> {
+    pub fn new(a: &'coeff dyn Coefficient, b: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::ScalarMatrixProductCoefficient_ctor(a.as_base(), b.as_base());
+        Self { inner }
+    }
+
+    /// Like [`ScalarMatrixProductCoefficient::new`], but `a` is a constant.
+    pub fn with_constant(a: f64, b: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::ScalarMatrixProductCoefficient_ctor_const(a, b.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> MatrixCoefficient for ScalarMatrixProductCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::MatrixCoefficient> for ScalarMatrixProductCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::MatrixCoefficient {
+        mfem_sys::ffi::ScalarMatrixProductCoefficient_as_MatrixCoeff(&self.inner)
+    }
+}
+
+impl_matrix_coefficient_ops!(ScalarMatrixProductCoefficient<'coeff>);
+
+////////////////////////////////////
+// MatrixVectorProductCoefficient //
+////////////////////////////////////
+
+/// The vector `a * b` for a matrix `a` and a vector `b`.
+pub struct MatrixVectorProductCoefficient<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::MatrixVectorProductCoefficient<'coeff>>,
+}
+
+impl<'coeff> MatrixVectorProductCoefficient<'coeff> {
+    pub fn new(
+        a: &'coeff dyn MatrixCoefficient,
+        b: &'coeff dyn VectorCoefficient,
+    ) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::MatrixVectorProductCoefficient_ctor(a.as_base(), b.as_base())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'coeff> VectorCoefficient for MatrixVectorProductCoefficient<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::VectorCoefficient> for MatrixVectorProductCoefficient<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::VectorCoefficient {
+        mfem_sys::ffi::MatrixVectorProductCoefficient_as_VectorCoeff(&self.inner)
+    }
+}
+
+impl_vector_coefficient_ops!(MatrixVectorProductCoefficient<'coeff>);
+
+//////////////////////////
+// LinearFormIntegrator //
+//////////////////////////
+
+pub trait LinearFormIntegrator:
+    AsBase<mfem_sys::ffi::LinearFormIntegrator>
+    + IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+{
+    // TODO(mkovaxx)
+}
+
+////////////////////////
+// DomainLFIntegrator //
+////////////////////////
+
+pub struct DomainLFIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::DomainLFIntegrator<'coeff>>,
+}
+
+impl<'coeff> DomainLFIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient, a: i32, b: i32) -> Self {
+        let inner = mfem_sys::ffi::DomainLFIntegrator_ctor_ab(coeff.as_base(), a, b);
+        Self { inner }
+    }
+}
 
 impl<'coeff> LinearFormIntegrator for DomainLFIntegrator<'coeff> {}
 
-impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for DomainLFIntegrator<'coeff> {
-    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
-        mfem_sys::ffi::DomainLFIntegrator_as_LFI(&self.inner)
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for DomainLFIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::DomainLFIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for DomainLFIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::DomainLFIntegrator_into_LFI(self.inner)
+    }
+}
+
+//////////////////////////
+// BoundaryLFIntegrator //
+//////////////////////////
+
+/// `(g, v)` on the boundary, e.g. for a Neumann condition `grad(u) · n = g`.
+///
+/// `a` and `b` set the quadrature order like in [`DomainLFIntegrator::new`].
+pub struct BoundaryLFIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::BoundaryLFIntegrator<'coeff>>,
+}
+
+impl<'coeff> BoundaryLFIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient, a: i32, b: i32) -> Self {
+        let inner = mfem_sys::ffi::BoundaryLFIntegrator_ctor_ab(coeff.as_base(), a, b);
+        Self { inner }
+    }
+}
+
+impl<'coeff> LinearFormIntegrator for BoundaryLFIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for BoundaryLFIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::BoundaryLFIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for BoundaryLFIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::BoundaryLFIntegrator_into_LFI(self.inner)
+    }
+}
+
+////////////////////////////////
+// BoundaryNormalLFIntegrator //
+////////////////////////////////
+
+/// `(g · n, v)` on the boundary, for a vector coefficient `g` and the outward normal `n`.
+pub struct BoundaryNormalLFIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::BoundaryNormalLFIntegrator<'coeff>>,
+}
+
+impl<'coeff> BoundaryNormalLFIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn VectorCoefficient, a: i32, b: i32) -> Self {
+        let inner = mfem_sys::ffi::BoundaryNormalLFIntegrator_ctor_ab(coeff.as_base(), a, b);
+        Self { inner }
+    }
+}
+
+impl<'coeff> LinearFormIntegrator for BoundaryNormalLFIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for BoundaryNormalLFIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::BoundaryNormalLFIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for BoundaryNormalLFIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::BoundaryNormalLFIntegrator_into_LFI(self.inner)
+    }
+}
+
+////////////////////////////////
+// VectorBoundaryLFIntegrator //
+////////////////////////////////
+
+/// `(g, v)` on the boundary, for a vector coefficient `g` and a vector [`FiniteElementSpace`].
+pub struct VectorBoundaryLFIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorBoundaryLFIntegrator<'coeff>>,
+}
+
+impl<'coeff> VectorBoundaryLFIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorBoundaryLFIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> LinearFormIntegrator for VectorBoundaryLFIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for VectorBoundaryLFIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::VectorBoundaryLFIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for VectorBoundaryLFIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::VectorBoundaryLFIntegrator_into_LFI(self.inner)
+    }
+}
+
+/////////////////////////////
+// DGDirichletLFIntegrator //
+/////////////////////////////
+
+/// The right-hand side of [`DGDiffusionIntegrator`] for the Dirichlet condition `u = u_d`,
+/// added with [`LinearForm::add_bdr_face_integrator`].
+///
+/// `sigma` and `kappa` must match the ones of the [`DGDiffusionIntegrator`].
+pub struct DGDirichletLFIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::DGDirichletLFIntegrator<'coeff>>,
+}
+
+impl<'coeff> DGDirichletLFIntegrator<'coeff> {
+    pub fn new(u_d: &'coeff dyn Coefficient, sigma: f64, kappa: f64) -> Self {
+        let inner = mfem_sys::ffi::DGDirichletLFIntegrator_ctor(u_d.as_base(), sigma, kappa);
+        Self { inner }
+    }
+
+    /// Like [`DGDirichletLFIntegrator::new`], for the diffusion coefficient `q`.
+    pub fn with_diffusion(
+        u_d: &'coeff dyn Coefficient,
+        q: &'coeff dyn Coefficient,
+        sigma: f64,
+        kappa: f64,
+    ) -> Self {
+        let inner =
+            mfem_sys::ffi::DGDirichletLFIntegrator_ctor_q(u_d.as_base(), q.as_base(), sigma, kappa);
+        Self { inner }
+    }
+}
+
+impl<'coeff> LinearFormIntegrator for DGDirichletLFIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for DGDirichletLFIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::DGDirichletLFIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for DGDirichletLFIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::DGDirichletLFIntegrator_into_LFI(self.inner)
+    }
+}
+
+////////////////////////////
+// BoundaryFlowIntegrator //
+////////////////////////////
+
+/// The inflow boundary term `alpha/2 (u · n) f w - beta |u · n| f w` for DG advection,
+/// added with [`LinearForm::add_bdr_face_integrator`].
+pub struct BoundaryFlowIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::BoundaryFlowIntegrator<'coeff>>,
+}
+
+impl<'coeff> BoundaryFlowIntegrator<'coeff> {
+    pub fn new(
+        f: &'coeff dyn Coefficient,
+        u: &'coeff dyn VectorCoefficient,
+        alpha: f64,
+        beta: f64,
+    ) -> Self {
+        let inner =
+            mfem_sys::ffi::BoundaryFlowIntegrator_ctor(f.as_base(), u.as_base(), alpha, beta);
+        Self { inner }
+    }
+}
+
+impl<'coeff> LinearFormIntegrator for BoundaryFlowIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for BoundaryFlowIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::BoundaryFlowIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for BoundaryFlowIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::BoundaryFlowIntegrator_into_LFI(self.inner)
+    }
+}
+
+////////////////////////////////
+// VectorFEDomainLFIntegrator //
+////////////////////////////////
+
+/// `(f, v)` for a vector coefficient `f` and H(curl) or H(div) elements.
+pub struct VectorFEDomainLFIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorFEDomainLFIntegrator<'coeff>>,
+}
+
+impl<'coeff> VectorFEDomainLFIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorFEDomainLFIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> LinearFormIntegrator for VectorFEDomainLFIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::LinearFormIntegrator> for VectorFEDomainLFIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::VectorFEDomainLFIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
+    for VectorFEDomainLFIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::VectorFEDomainLFIntegrator_into_LFI(self.inner)
+    }
+}
+
+////////////////////////////////
+// CustomLinearFormIntegrator //
+////////////////////////////////
+
+/// An integrator implemented in Rust, which becomes a [`LinearFormIntegrator`]
+/// when wrapped in a [`RustLinearFormIntegrator`].
+///
+/// Panics are caught and make the assembly return an error.
+pub trait CustomLinearFormIntegrator {
+    /// Add the contributions of the element `el`, whose geometry is given by `trans`, to `elvect`.
+    ///
+    /// `elvect` is zeroed beforehand, and has one entry per shape function of `el`,
+    /// so the [`FiniteElementSpace`] must have a vector dimension of 1, which is checked
+    /// when the integrator is added to a [`LinearForm`].
+    fn assemble_rhs_element_vect(
+        &self,
+        el: &FiniteElement,
+        trans: &mut ElementTransformation,
+        elvect: &mut [f64],
+    );
+}
+
+//////////////////////////////
+// RustLinearFormIntegrator //
+//////////////////////////////
+
+/// Adapts a [`CustomLinearFormIntegrator`] to be added to a [`LinearForm`].
+///
+/// The integrator must be `'static`, as forms don't keep track of what their
+/// integrators borrow.
+pub struct RustLinearFormIntegrator {
+    inner: UniquePtr<mfem_sys::ffi::RustLinearFormIntegrator<'static>>,
+}
+
+impl RustLinearFormIntegrator {
+    pub fn new<I>(integ: I) -> Self
+    where
+        I: CustomLinearFormIntegrator + 'static,
+    {
+        let integ = Box::new(mfem_sys::RustLinearIntegrator::new(
+            move |el, trans, elvect| {
+                integ.assemble_rhs_element_vect(
+                    &FiniteElement { inner: el },
+                    &mut ElementTransformation::new(trans),
+                    elvect,
+                )
+            },
+        ));
+        // SAFETY: The C++ side takes ownership, and drops `integ` along with `inner`.
+        let inner = unsafe { mfem_sys::ffi::RustLinearFormIntegrator_ctor(Box::into_raw(integ)) };
+        Self { inner }
+    }
+}
+
+impl LinearFormIntegrator for RustLinearFormIntegrator {}
+
+impl AsBase<mfem_sys::ffi::LinearFormIntegrator> for RustLinearFormIntegrator {
+    fn as_base(&self) -> &mfem_sys::ffi::LinearFormIntegrator {
+        mfem_sys::ffi::RustLinearFormIntegrator_as_LFI(&self.inner)
+    }
+}
+
+impl IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>> for RustLinearFormIntegrator {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
+        mfem_sys::ffi::RustLinearFormIntegrator_into_LFI(self.inner)
+    }
+}
+
+//////////////////
+// BilinearForm //
+//////////////////
+
+/// A bilinear form `a(u, v)` on a finite element space.
+///
+/// The form keeps its integrators, so they and the coefficients they borrow
+/// need to live for `'fes`, i.e. outlive the form.
+pub struct BilinearForm<'fes> {
+    inner: UniquePtr<mfem_sys::ffi::BilinearForm<'fes>>,
+    // NOTE: Declared after `inner` so that they're dropped after it, as it refers to them.
+    bdr_markers: Vec<UniquePtr<mfem_sys::ffi::ArrayInt>>,
+}
+
+impl<'fes> BilinearForm<'fes> {
+    pub fn new(fespace: &'fes FiniteElementSpace) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::BilinearForm_ctor_fes(&fespace.inner)?;
+        Ok(Self {
+            inner,
+            bdr_markers: Vec::new(),
+        })
+    }
+
+    pub fn add_domain_integrator<Bfi>(&mut self, bfi: Bfi) -> Result<(), MfemError>
+    where
+        Bfi: BilinearFormIntegrator + 'fes,
+    {
+        mfem_sys::ffi::BilinearForm_AddDomainIntegrator(self.inner.pin_mut(), bfi.into_base())?;
+        Ok(())
+    }
+
+    /// Integrate `bfi` over the boundary elements, e.g. a [`MassIntegrator`] for Robin conditions.
+    ///
+    /// With `Some(bdr_attrs)`, only over the boundary elements with one of those attributes.
+    pub fn add_boundary_integrator<Bfi>(
+        &mut self,
+        bfi: Bfi,
+        bdr_attrs: Option<&[i32]>,
+    ) -> Result<(), MfemError>
+    where
+        Bfi: BilinearFormIntegrator + 'fes,
+    {
+        match bdr_attrs {
+            None => {
+                mfem_sys::ffi::BilinearForm_AddBoundaryIntegrator(
+                    self.inner.pin_mut(),
+                    bfi.into_base(),
+                )?;
+            }
+            Some(bdr_attrs) => {
+                let marker = mfem_sys::ffi::BilinearForm_AddBoundaryIntegrator_attrs(
+                    self.inner.pin_mut(),
+                    bfi.into_base(),
+                    bdr_attrs,
+                )?;
+                self.bdr_markers.push(marker);
+            }
+        }
+        Ok(())
+    }
+
+    /// Integrate `bfi` over the interior faces, e.g. for DG methods.
+    pub fn add_interior_face_integrator<Bfi>(&mut self, bfi: Bfi) -> Result<(), MfemError>
+    where
+        Bfi: BilinearFormIntegrator + 'fes,
+    {
+        mfem_sys::ffi::BilinearForm_AddInteriorFaceIntegrator(
+            self.inner.pin_mut(),
+            bfi.into_base(),
+        )?;
+        Ok(())
+    }
+
+    /// Integrate `bfi` over the boundary faces, e.g. for DG methods.
+    ///
+    /// With `Some(bdr_attrs)`, only over the boundary faces with one of those attributes.
+    pub fn add_bdr_face_integrator<Bfi>(
+        &mut self,
+        bfi: Bfi,
+        bdr_attrs: Option<&[i32]>,
+    ) -> Result<(), MfemError>
+    where
+        Bfi: BilinearFormIntegrator + 'fes,
+    {
+        match bdr_attrs {
+            None => {
+                mfem_sys::ffi::BilinearForm_AddBdrFaceIntegrator(
+                    self.inner.pin_mut(),
+                    bfi.into_base(),
+                )?;
+            }
+            Some(bdr_attrs) => {
+                let marker = mfem_sys::ffi::BilinearForm_AddBdrFaceIntegrator_attrs(
+                    self.inner.pin_mut(),
+                    bfi.into_base(),
+                    bdr_attrs,
+                )?;
+                self.bdr_markers.push(marker);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the integrators' contributions to the matrix.
+    ///
+    /// Call [`BilinearForm::update`] first to reassemble from scratch, e.g. at the next
+    /// time level.
+    pub fn assemble(&mut self, skip_zeros: bool) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .Assemble(if skip_zeros { 1 } else { 0 })?;
+        Ok(())
+    }
+
+    /// Zero the assembled matrix, keeping the integrators and the sparsity pattern,
+    /// so that the form can be reassembled, e.g. at the next time level.
+    pub fn update(&mut self) {
+        mfem_sys::ffi::BilinearForm_Update(self.inner.pin_mut());
+    }
+
+    /// Finish the assembled matrix, which makes [`BilinearForm::mult`] faster.
+    pub fn finalize(&mut self, skip_zeros: bool) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .Finalize(if skip_zeros { 1 } else { 0 })?;
+        Ok(())
+    }
+
+    /// `y = A x` with the assembled matrix `A`.
+    pub fn mult<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::BilinearForm_Mult(&self.inner, x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+
+    /// The assembled matrix, e.g. to use it as an [`Operator`] or to build a preconditioner for it.
+    ///
+    /// The form itself is not an [`Operator`], as it has no matrix to apply until it's assembled.
+    pub fn sp_mat(&self) -> Result<SparseMatrixRef<'_>, MfemError> {
+        let inner = mfem_sys::ffi::BilinearForm_SpMat(&self.inner)?;
+        Ok(SparseMatrixRef { inner })
+    }
+
+    pub fn form_linear_system<X, B>(
+        &self,
+        ess_tdof_list: &ArrayInt,
+        x: &X,
+        b: &B,
+        a_mat: &mut OperatorHandle,
+        x_vec: &mut Vector,
+        b_vec: &mut Vector,
+    ) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        B: VectorRead,
+    {
+        mfem_sys::ffi::BilinearForm_FormLinearSystem(
+            &self.inner,
+            &ess_tdof_list.inner,
+            &x.as_base(),
+            &b.as_base(),
+            a_mat.inner.pin_mut(),
+            x_vec.inner.pin_mut(),
+            b_vec.inner.pin_mut(),
+        )?;
+        Ok(())
+    }
+
+    pub fn recover_fem_solution<B, X>(
+        &mut self,
+        x_vec: &Vector,
+        b_vec: &B,
+        x: &mut X,
+    ) -> Result<(), MfemError>
+    where
+        B: VectorRead,
+        X: VectorLike,
+    {
+        mfem_sys::ffi::BilinearForm_RecoverFEMSolution(
+            self.inner.pin_mut(),
+            &x_vec.inner,
+            b_vec.as_base(),
+            x.as_base_mut(),
+        )?;
+        Ok(())
+    }
+}
+
+///////////////////////
+// MixedBilinearForm //
+///////////////////////
+
+/// A bilinear form `a(u, v)` with the trial function `u` and the test function `v` from
+/// different spaces, whose matrix maps the trial space to the test space.
+///
+/// The integrators must be suitable for the pair of spaces, e.g. a [`VectorFEDivergenceIntegrator`]
+/// for Raviart-Thomas trial and L2 test functions.
+///
+/// The form keeps its integrators, so they and the coefficients they borrow
+/// need to live for `'trial` and `'test`, i.e. outlive the form.
+pub struct MixedBilinearForm<'trial, 'test> {
+    inner: UniquePtr<mfem_sys::ffi::MixedBilinearForm<'trial, 'test>>,
+    // NOTE: Declared after `inner` so that they're dropped after it, as it refers to them.
+    bdr_markers: Vec<UniquePtr<mfem_sys::ffi::ArrayInt>>,
+}
+
+impl<'trial, 'test> MixedBilinearForm<'trial, 'test> {
+    pub fn new(
+        trial_fespace: &'trial FiniteElementSpace,
+        test_fespace: &'test FiniteElementSpace,
+    ) -> Result<Self, MfemError> {
+        let inner =
+            mfem_sys::ffi::MixedBilinearForm_ctor_fes(&trial_fespace.inner, &test_fespace.inner)?;
+        Ok(Self {
+            inner,
+            bdr_markers: Vec::new(),
+        })
+    }
+
+    pub fn add_domain_integrator<Bfi>(&mut self, bfi: Bfi) -> Result<(), MfemError>
+    where
+        Bfi: BilinearFormIntegrator + 'trial + 'test,
+    {
+        mfem_sys::ffi::MixedBilinearForm_AddDomainIntegrator(
+            self.inner.pin_mut(),
+            bfi.into_base(),
+        )?;
+        Ok(())
+    }
+
+    /// Integrate `bfi` over the boundary elements.
+    ///
+    /// With `Some(bdr_attrs)`, only over the boundary elements with one of those attributes.
+    pub fn add_boundary_integrator<Bfi>(
+        &mut self,
+        bfi: Bfi,
+        bdr_attrs: Option<&[i32]>,
+    ) -> Result<(), MfemError>
+    where
+        Bfi: BilinearFormIntegrator + 'trial + 'test,
+    {
+        match bdr_attrs {
+            None => {
+                mfem_sys::ffi::MixedBilinearForm_AddBoundaryIntegrator(
+                    self.inner.pin_mut(),
+                    bfi.into_base(),
+                )?;
+            }
+            Some(bdr_attrs) => {
+                let marker = mfem_sys::ffi::MixedBilinearForm_AddBoundaryIntegrator_attrs(
+                    self.inner.pin_mut(),
+                    bfi.into_base(),
+                    bdr_attrs,
+                )?;
+                self.bdr_markers.push(marker);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the integrators' contributions to the matrix.
+    ///
+    /// Call [`MixedBilinearForm::update`] first to reassemble from scratch.
+    pub fn assemble(&mut self, skip_zeros: bool) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .Assemble(if skip_zeros { 1 } else { 0 })?;
+        Ok(())
+    }
+
+    /// Zero the assembled matrix, keeping the integrators and the sparsity pattern,
+    /// so that the form can be reassembled, e.g. at the next time level.
+    pub fn update(&mut self) {
+        mfem_sys::ffi::MixedBilinearForm_Update(self.inner.pin_mut());
+    }
+
+    /// Finish the assembled matrix, which makes [`MixedBilinearForm::mult`] faster.
+    pub fn finalize(&mut self, skip_zeros: bool) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .Finalize(if skip_zeros { 1 } else { 0 })?;
+        Ok(())
+    }
+
+    /// `y = B x` with the assembled matrix `B`, for `x` in the trial space.
+    pub fn mult<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::MixedBilinearForm_Mult(&self.inner, x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+
+    /// `y = B^T x` with the assembled matrix `B`, for `x` in the test space.
+    pub fn mult_transpose<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::MixedBilinearForm_MultTranspose(&self.inner, x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+
+    /// The assembled matrix, with one row per test and one column per trial degree of freedom,
+    /// e.g. to use it as an [`Operator`].
+    pub fn sp_mat(&self) -> Result<SparseMatrixRef<'_>, MfemError> {
+        let inner = mfem_sys::ffi::MixedBilinearForm_SpMat(&self.inner)?;
+        Ok(SparseMatrixRef { inner })
+    }
+
+    /// Like [`BilinearForm::form_linear_system`], with separate essential true dofs for the
+    /// trial and the test space.
+    ///
+    /// The columns of the trial dofs are eliminated, moving the known values of `x` to `b_vec`,
+    /// and the rows of the test dofs are zeroed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn form_rect_linear_system<X, B>(
+        &self,
+        trial_tdof_list: &ArrayInt,
+        test_tdof_list: &ArrayInt,
+        x: &X,
+        b: &B,
+        a_mat: &mut OperatorHandle,
+        x_vec: &mut Vector,
+        b_vec: &mut Vector,
+    ) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        B: VectorRead,
+    {
+        mfem_sys::ffi::MixedBilinearForm_FormRectangularLinearSystem(
+            &self.inner,
+            &trial_tdof_list.inner,
+            &test_tdof_list.inner,
+            x.as_base(),
+            b.as_base(),
+            a_mat.inner.pin_mut(),
+            x_vec.inner.pin_mut(),
+            b_vec.inner.pin_mut(),
+        )?;
+        Ok(())
+    }
+}
+
+////////////////////////////
+// DiscreteLinearOperator //
+////////////////////////////
+
+/// The matrix of a map between finite element spaces, given by interpolating the image of each
+/// basis function of the domain space, e.g. a [`GradientInterpolator`] from H1 to H(curl).
+pub struct DiscreteLinearOperator<'domain, 'range> {
+    inner: UniquePtr<mfem_sys::ffi::DiscreteLinearOperator<'domain, 'range>>,
+}
+
+impl<'domain, 'range> DiscreteLinearOperator<'domain, 'range> {
+    pub fn new(
+        domain_fespace: &'domain FiniteElementSpace,
+        range_fespace: &'range FiniteElementSpace,
+    ) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::DiscreteLinearOperator_ctor_fes(
+            &domain_fespace.inner,
+            &range_fespace.inner,
+        )?;
+        Ok(Self { inner })
+    }
+
+    pub fn add_domain_interpolator<Di>(&mut self, di: Di) -> Result<(), MfemError>
+    where
+        Di: DiscreteInterpolator,
+    {
+        mfem_sys::ffi::DiscreteLinearOperator_AddDomainInterpolator(
+            self.inner.pin_mut(),
+            di.into_base(),
+        )?;
+        Ok(())
+    }
+
+    /// Compute the matrix.
+    pub fn assemble(&mut self, skip_zeros: bool) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .Assemble(if skip_zeros { 1 } else { 0 })?;
+        Ok(())
+    }
+
+    /// Finish the assembled matrix, which makes [`DiscreteLinearOperator::mult`] faster.
+    pub fn finalize(&mut self, skip_zeros: bool) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .Finalize(if skip_zeros { 1 } else { 0 })?;
+        Ok(())
+    }
+
+    /// `y = D x` with the assembled matrix `D`, e.g. the gradient `y` of the potential `x`.
+    pub fn mult<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::MixedBilinearForm_Mult(self.as_mixed(), x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+
+    /// The assembled matrix, with one row per range and one column per domain degree of freedom,
+    /// e.g. to use it as an [`Operator`].
+    pub fn sp_mat(&self) -> Result<SparseMatrixRef<'_>, MfemError> {
+        let inner = mfem_sys::ffi::MixedBilinearForm_SpMat(self.as_mixed())?;
+        Ok(SparseMatrixRef { inner })
+    }
+
+    fn as_mixed(&self) -> &mfem_sys::ffi::MixedBilinearForm<'domain, 'range> {
+        mfem_sys::ffi::DiscreteLinearOperator_as_MixedBilinearForm(&self.inner)
+    }
+}
+
+///////////////////
+// NonlinearForm //
+///////////////////
+
+/// A nonlinear operator `F(x)` given as a sum of integrals, e.g. of a [`HyperelasticNLFIntegrator`].
+///
+/// The rows of the essential true dofs are set to zero in [`NonlinearForm::mult`]
+/// and to the identity in [`NonlinearForm::get_gradient`].
+///
+/// The form keeps its integrators, so they and the models they borrow
+/// need to live for `'fes`, i.e. outlive the form.
+pub struct NonlinearForm<'fes> {
+    inner: UniquePtr<mfem_sys::ffi::NonlinearForm<'fes>>,
+}
+
+impl<'fes> NonlinearForm<'fes> {
+    pub fn new(fespace: &'fes FiniteElementSpace) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::NonlinearForm_ctor_fes(&fespace.inner)?;
+        Ok(Self { inner })
+    }
+
+    pub fn add_domain_integrator<Nlfi>(&mut self, nlfi: Nlfi) -> Result<(), MfemError>
+    where
+        Nlfi: NonlinearFormIntegrator + 'fes,
+    {
+        mfem_sys::ffi::NonlinearForm_AddDomainIntegrator(self.inner.pin_mut(), nlfi.into_base())?;
+        Ok(())
+    }
+
+    /// The list is copied, so it can be dropped afterwards.
+    pub fn set_essential_true_dofs(&mut self, ess_tdof_list: &ArrayInt) -> Result<(), MfemError> {
+        self.inner
+            .pin_mut()
+            .SetEssentialTrueDofs(&ess_tdof_list.inner)?;
+        Ok(())
+    }
+
+    /// `y = F(x)`.
+    pub fn mult<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::NonlinearForm_Mult(&self.inner, x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+
+    /// The Jacobian of `F` at `x`.
+    ///
+    /// The matrix is owned by the form and overwritten by the next call.
+    pub fn get_gradient<X>(&mut self, x: &X) -> Result<SparseMatrixRef<'_>, MfemError>
+    where
+        X: VectorRead,
+    {
+        let inner = mfem_sys::ffi::NonlinearForm_GetGradient(self.inner.pin_mut(), x.as_base())?;
+        Ok(SparseMatrixRef { inner })
+    }
+
+    /// The sum of the energies of the integrators at `x`, e.g. the total strain energy.
+    pub fn get_energy<X>(&self, x: &X) -> Result<f64, MfemError>
+    where
+        X: VectorRead,
+    {
+        let energy = mfem_sys::ffi::NonlinearForm_GetEnergy(&self.inner, x.as_base())?;
+        Ok(energy)
+    }
+}
+
+impl<'fes> Operator for NonlinearForm<'fes> {}
+
+impl<'fes> AsBase<mfem_sys::ffi::Operator> for NonlinearForm<'fes> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::NonlinearForm_as_Operator(&self.inner)
+    }
+}
+
+////////////////////////////
+// BilinearFormIntegrator //
+////////////////////////////
+
+pub trait BilinearFormIntegrator:
+    AsBase<mfem_sys::ffi::BilinearFormIntegrator>
+    + IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+{
+    // TODO(mkovaxx)
+}
+
+/////////////////////////
+// DiffusionIntegrator //
+/////////////////////////
+
+pub struct DiffusionIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::DiffusionIntegrator<'coeff>>,
+}
+
+impl<'coeff> DiffusionIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::DiffusionIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a (possibly anisotropic) diffusion tensor instead of a scalar.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::DiffusionIntegrator_ctor_matrix(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for DiffusionIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for DiffusionIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::DiffusionIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for DiffusionIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::DiffusionIntegrator_into_BFI(self.inner)
+    }
+}
+
+////////////////////
+// MassIntegrator //
+////////////////////
+
+/// `(q u, v)`, where `q` is a scalar coefficient.
+pub struct MassIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::MassIntegrator<'coeff>>,
+}
+
+impl<'coeff> MassIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::MassIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for MassIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for MassIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::MassIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>> for MassIntegrator<'coeff> {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::MassIntegrator_into_BFI(self.inner)
+    }
+}
+
+//////////////////////////
+// ConvectionIntegrator //
+//////////////////////////
+
+/// `alpha * (velocity · grad(u), v)`.
+pub struct ConvectionIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::ConvectionIntegrator<'coeff>>,
+}
+
+impl<'coeff> ConvectionIntegrator<'coeff> {
+    pub fn new(velocity: &'coeff dyn VectorCoefficient, alpha: f64) -> Self {
+        let inner = mfem_sys::ffi::ConvectionIntegrator_ctor(velocity.as_base(), alpha);
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for ConvectionIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for ConvectionIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::ConvectionIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for ConvectionIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::ConvectionIntegrator_into_BFI(self.inner)
+    }
+}
+
+//////////////////////////
+// ElasticityIntegrator //
+//////////////////////////
+
+/// Isotropic linear elasticity with Lamé coefficients `lambda` and `mu`,
+/// for a vector [`FiniteElementSpace`] with `vdim` equal to the mesh dimension.
+pub struct ElasticityIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::ElasticityIntegrator<'coeff>>,
+}
+
+impl<'coeff> ElasticityIntegrator<'coeff> {
+    pub fn new(lambda: &'coeff dyn Coefficient, mu: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::ElasticityIntegrator_ctor(lambda.as_base(), mu.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for ElasticityIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for ElasticityIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::ElasticityIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for ElasticityIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::ElasticityIntegrator_into_BFI(self.inner)
+    }
+}
+
+///////////////////////////////
+// VectorDiffusionIntegrator //
+///////////////////////////////
+
+/// `(q grad(u), grad(v))` for each component of a vector [`FiniteElementSpace`].
+pub struct VectorDiffusionIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorDiffusionIntegrator<'coeff>>,
+}
+
+impl<'coeff> VectorDiffusionIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorDiffusionIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a different scalar coefficient for each component.
+    pub fn with_vector(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorDiffusionIntegrator_ctor_vector(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Couple the components through a `vdim x vdim` matrix coefficient.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorDiffusionIntegrator_ctor_matrix(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for VectorDiffusionIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for VectorDiffusionIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::VectorDiffusionIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for VectorDiffusionIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::VectorDiffusionIntegrator_into_BFI(self.inner)
+    }
+}
+
+//////////////////////////
+// VectorMassIntegrator //
+//////////////////////////
+
+/// `(q u, v)` for each component of a vector [`FiniteElementSpace`].
+pub struct VectorMassIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorMassIntegrator<'coeff>>,
+}
+
+impl<'coeff> VectorMassIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorMassIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a different scalar coefficient for each component.
+    pub fn with_vector(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorMassIntegrator_ctor_vector(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Couple the components through a `vdim x vdim` matrix coefficient.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorMassIntegrator_ctor_matrix(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for VectorMassIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for VectorMassIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::VectorMassIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for VectorMassIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::VectorMassIntegrator_into_BFI(self.inner)
+    }
+}
+
+////////////////////////
+// CurlCurlIntegrator //
+////////////////////////
+
+/// `(q curl(u), curl(v))` for H(curl) elements, e.g. Nédélec.
+pub struct CurlCurlIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::CurlCurlIntegrator<'coeff>>,
+}
+
+impl<'coeff> CurlCurlIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::CurlCurlIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a diagonal (anisotropic) coefficient.
+    pub fn with_vector(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::CurlCurlIntegrator_ctor_vector(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a full (anisotropic) coefficient.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::CurlCurlIntegrator_ctor_matrix(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for CurlCurlIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for CurlCurlIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::CurlCurlIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for CurlCurlIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::CurlCurlIntegrator_into_BFI(self.inner)
+    }
+}
+
+//////////////////////
+// DivDivIntegrator //
+//////////////////////
+
+/// `(q div(u), div(v))` for H(div) elements, e.g. Raviart-Thomas.
+pub struct DivDivIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::DivDivIntegrator<'coeff>>,
+}
+
+impl<'coeff> DivDivIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::DivDivIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for DivDivIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for DivDivIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::DivDivIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for DivDivIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::DivDivIntegrator_into_BFI(self.inner)
+    }
+}
+
+////////////////////////////
+// VectorFEMassIntegrator //
+////////////////////////////
+
+/// `(q u, v)` for H(curl) or H(div) elements.
+pub struct VectorFEMassIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorFEMassIntegrator<'coeff>>,
+}
+
+impl<'coeff> VectorFEMassIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorFEMassIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a diagonal (anisotropic) coefficient.
+    pub fn with_vector(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorFEMassIntegrator_ctor_vector(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a full (anisotropic) coefficient.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorFEMassIntegrator_ctor_matrix(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for VectorFEMassIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for VectorFEMassIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::VectorFEMassIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for VectorFEMassIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::VectorFEMassIntegrator_into_BFI(self.inner)
+    }
+}
+
+////////////////////////////
+// BoundaryMassIntegrator //
+////////////////////////////
+
+/// `(q u, v)` on the boundary, e.g. for a Robin condition `grad(u) · n + q u = g`.
+///
+/// A plain [`MassIntegrator`] does the same job when added with
+/// [`BilinearForm::add_boundary_integrator`].
+pub struct BoundaryMassIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::BoundaryMassIntegrator<'coeff>>,
+}
+
+impl<'coeff> BoundaryMassIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::BoundaryMassIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for BoundaryMassIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for BoundaryMassIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::BoundaryMassIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for BoundaryMassIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::BoundaryMassIntegrator_into_BFI(self.inner)
+    }
+}
+
+///////////////////////////
+// DGDiffusionIntegrator //
+///////////////////////////
+
+/// The interior penalty DG face terms of `-div(q grad(u))`, added with
+/// [`BilinearForm::add_interior_face_integrator`] and [`BilinearForm::add_bdr_face_integrator`].
+///
+/// `sigma` is -1 for SIPG, 1 for NIPG and 0 for IIPG, and `kappa` is the penalty parameter.
+pub struct DGDiffusionIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::DGDiffusionIntegrator<'coeff>>,
+}
+
+impl<'coeff> DGDiffusionIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient, sigma: f64, kappa: f64) -> Self {
+        let inner = mfem_sys::ffi::DGDiffusionIntegrator_ctor(coeff.as_base(), sigma, kappa);
+        Self { inner }
+    }
+
+    /// Use a (possibly anisotropic) diffusion tensor instead of a scalar.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient, sigma: f64, kappa: f64) -> Self {
+        let inner = mfem_sys::ffi::DGDiffusionIntegrator_ctor_matrix(coeff.as_base(), sigma, kappa);
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for DGDiffusionIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for DGDiffusionIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::DGDiffusionIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for DGDiffusionIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::DGDiffusionIntegrator_into_BFI(self.inner)
+    }
+}
+
+///////////////////////
+// DGTraceIntegrator //
+///////////////////////
+
+/// The DG face term `alpha <(u · n) {v}, [w]> + beta <|u · n| [v], [w]>` of advection by `u`.
+///
+/// See also [`TransposeIntegrator`].
+pub struct DGTraceIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::DGTraceIntegrator<'coeff>>,
+}
+
+impl<'coeff> DGTraceIntegrator<'coeff> {
+    pub fn new(u: &'coeff dyn VectorCoefficient, alpha: f64, beta: f64) -> Self {
+        let inner = mfem_sys::ffi::DGTraceIntegrator_ctor(u.as_base(), alpha, beta);
+        Self { inner }
+    }
+
+    /// Like [`DGTraceIntegrator::new`], for the flux `rho u` instead of `u`.
+    pub fn with_density(
+        rho: &'coeff dyn Coefficient,
+        u: &'coeff dyn VectorCoefficient,
+        alpha: f64,
+        beta: f64,
+    ) -> Self {
+        let inner =
+            mfem_sys::ffi::DGTraceIntegrator_ctor_rho(rho.as_base(), u.as_base(), alpha, beta);
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for DGTraceIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for DGTraceIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::DGTraceIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for DGTraceIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::DGTraceIntegrator_into_BFI(self.inner)
+    }
+}
+
+/////////////////////////
+// TransposeIntegrator //
+/////////////////////////
+
+/// The transpose of another [`BilinearFormIntegrator`].
+pub struct TransposeIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::TransposeIntegrator<'coeff>>,
+}
+
+impl<'coeff> TransposeIntegrator<'coeff> {
+    pub fn new<Bfi>(bfi: Bfi) -> Self
+    where
+        Bfi: BilinearFormIntegrator + 'coeff,
+    {
+        let inner = mfem_sys::ffi::TransposeIntegrator_ctor(bfi.into_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for TransposeIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for TransposeIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::TransposeIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for TransposeIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::TransposeIntegrator_into_BFI(self.inner)
+    }
+}
+
+/////////////////////////////////////
+// MixedScalarDivergenceIntegrator //
+/////////////////////////////////////
+
+/// `(q div u, v)`, where `u` is an H(div) trial function and `v` a scalar H1 or L2 test function,
+/// for [`MixedBilinearForm`].
+pub struct MixedScalarDivergenceIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::MixedScalarDivergenceIntegrator<'coeff>>,
+}
+
+impl<'coeff> MixedScalarDivergenceIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::MixedScalarDivergenceIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for MixedScalarDivergenceIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator>
+    for MixedScalarDivergenceIntegrator<'coeff>
+{
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::MixedScalarDivergenceIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for MixedScalarDivergenceIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::MixedScalarDivergenceIntegrator_into_BFI(self.inner)
+    }
+}
+
+//////////////////////////////////
+// VectorFEDivergenceIntegrator //
+//////////////////////////////////
+
+/// `(q div u, v)`, where `u` is an H(div) trial function, see [`RtFeCollection`],
+/// and `v` a scalar (typically L2) test function, for [`MixedBilinearForm`].
+pub struct VectorFEDivergenceIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::VectorFEDivergenceIntegrator<'coeff>>,
+}
+
+impl<'coeff> VectorFEDivergenceIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::VectorFEDivergenceIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for VectorFEDivergenceIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator>
+    for VectorFEDivergenceIntegrator<'coeff>
+{
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::VectorFEDivergenceIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for VectorFEDivergenceIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::VectorFEDivergenceIntegrator_into_BFI(self.inner)
+    }
+}
+
+////////////////////////
+// GradientIntegrator //
+////////////////////////
+
+/// `(q grad u, v)`, where `u` is a scalar H1 trial function and `v` a vector H1 test function,
+/// for [`MixedBilinearForm`].
+pub struct GradientIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::GradientIntegrator<'coeff>>,
+}
+
+impl<'coeff> GradientIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::GradientIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for GradientIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for GradientIntegrator<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::GradientIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for GradientIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::GradientIntegrator_into_BFI(self.inner)
+    }
+}
+
+///////////////////////////////////
+// MixedVectorGradientIntegrator //
+///////////////////////////////////
+
+/// `(Q grad u, v)`, where `u` is a scalar H1 trial function and `v` an H(curl) test function,
+/// see [`NdFeCollection`], for [`MixedBilinearForm`].
+pub struct MixedVectorGradientIntegrator<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::MixedVectorGradientIntegrator<'coeff>>,
+}
+
+impl<'coeff> MixedVectorGradientIntegrator<'coeff> {
+    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::MixedVectorGradientIntegrator_ctor(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a diagonal matrix, given by its diagonal, instead of a scalar.
+    pub fn with_vector(coeff: &'coeff dyn VectorCoefficient) -> Self {
+        let inner = mfem_sys::ffi::MixedVectorGradientIntegrator_ctor_vector(coeff.as_base());
+        Self { inner }
+    }
+
+    /// Use a matrix instead of a scalar.
+    pub fn with_matrix(coeff: &'coeff dyn MatrixCoefficient) -> Self {
+        let inner = mfem_sys::ffi::MixedVectorGradientIntegrator_ctor_matrix(coeff.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> BilinearFormIntegrator for MixedVectorGradientIntegrator<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator>
+    for MixedVectorGradientIntegrator<'coeff>
+{
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::MixedVectorGradientIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
+    for MixedVectorGradientIntegrator<'coeff>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::MixedVectorGradientIntegrator_into_BFI(self.inner)
+    }
+}
+
+//////////////////////////
+// DiscreteInterpolator //
+//////////////////////////
+
+pub trait DiscreteInterpolator:
+    AsBase<mfem_sys::ffi::DiscreteInterpolator>
+    + IntoBase<UniquePtr<mfem_sys::ffi::DiscreteInterpolator>>
+{
+}
+
+//////////////////////////
+// GradientInterpolator //
+//////////////////////////
+
+/// The gradient from H1 to H(curl), see [`DiscreteLinearOperator`].
+pub struct GradientInterpolator {
+    inner: UniquePtr<mfem_sys::ffi::GradientInterpolator>,
+}
+
+impl GradientInterpolator {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::GradientInterpolator_ctor();
+        Self { inner }
+    }
+}
+
+impl DiscreteInterpolator for GradientInterpolator {}
+
+impl AsBase<mfem_sys::ffi::DiscreteInterpolator> for GradientInterpolator {
+    fn as_base(&self) -> &mfem_sys::ffi::DiscreteInterpolator {
+        mfem_sys::ffi::GradientInterpolator_as_DI(&self.inner)
+    }
+}
+
+impl IntoBase<UniquePtr<mfem_sys::ffi::DiscreteInterpolator>> for GradientInterpolator {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::DiscreteInterpolator> {
+        mfem_sys::ffi::GradientInterpolator_into_DI(self.inner)
+    }
+}
+
+//////////////////////
+// CurlInterpolator //
+//////////////////////
+
+/// The curl from H(curl) to H(div) in 3D, see [`DiscreteLinearOperator`].
+pub struct CurlInterpolator {
+    inner: UniquePtr<mfem_sys::ffi::CurlInterpolator>,
+}
+
+impl CurlInterpolator {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::CurlInterpolator_ctor();
+        Self { inner }
+    }
+}
+
+impl DiscreteInterpolator for CurlInterpolator {}
+
+impl AsBase<mfem_sys::ffi::DiscreteInterpolator> for CurlInterpolator {
+    fn as_base(&self) -> &mfem_sys::ffi::DiscreteInterpolator {
+        mfem_sys::ffi::CurlInterpolator_as_DI(&self.inner)
+    }
+}
+
+impl IntoBase<UniquePtr<mfem_sys::ffi::DiscreteInterpolator>> for CurlInterpolator {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::DiscreteInterpolator> {
+        mfem_sys::ffi::CurlInterpolator_into_DI(self.inner)
+    }
+}
+
+////////////////////////////
+// DivergenceInterpolator //
+////////////////////////////
+
+/// The divergence from H(div) to L2, see [`DiscreteLinearOperator`].
+pub struct DivergenceInterpolator {
+    inner: UniquePtr<mfem_sys::ffi::DivergenceInterpolator>,
+}
+
+impl DivergenceInterpolator {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::DivergenceInterpolator_ctor();
+        Self { inner }
+    }
+}
+
+impl DiscreteInterpolator for DivergenceInterpolator {}
+
+impl AsBase<mfem_sys::ffi::DiscreteInterpolator> for DivergenceInterpolator {
+    fn as_base(&self) -> &mfem_sys::ffi::DiscreteInterpolator {
+        mfem_sys::ffi::DivergenceInterpolator_as_DI(&self.inner)
+    }
+}
+
+impl IntoBase<UniquePtr<mfem_sys::ffi::DiscreteInterpolator>> for DivergenceInterpolator {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::DiscreteInterpolator> {
+        mfem_sys::ffi::DivergenceInterpolator_into_DI(self.inner)
+    }
+}
+
+//////////////////////////
+// IdentityInterpolator //
+//////////////////////////
+
+/// The identity, i.e. the interpolation into another space, see [`DiscreteLinearOperator`].
+pub struct IdentityInterpolator {
+    inner: UniquePtr<mfem_sys::ffi::IdentityInterpolator>,
+}
+
+impl IdentityInterpolator {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::IdentityInterpolator_ctor();
+        Self { inner }
+    }
+}
+
+impl DiscreteInterpolator for IdentityInterpolator {}
+
+impl AsBase<mfem_sys::ffi::DiscreteInterpolator> for IdentityInterpolator {
+    fn as_base(&self) -> &mfem_sys::ffi::DiscreteInterpolator {
+        mfem_sys::ffi::IdentityInterpolator_as_DI(&self.inner)
+    }
+}
+
+impl IntoBase<UniquePtr<mfem_sys::ffi::DiscreteInterpolator>> for IdentityInterpolator {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::DiscreteInterpolator> {
+        mfem_sys::ffi::IdentityInterpolator_into_DI(self.inner)
+    }
+}
+
+//////////////////////////////////
+// CustomBilinearFormIntegrator //
+//////////////////////////////////
+
+/// An integrator implemented in Rust, which becomes a [`BilinearFormIntegrator`]
+/// when wrapped in a [`RustBilinearFormIntegrator`].
+///
+/// Panics are caught and make the assembly return an error.
+pub trait CustomBilinearFormIntegrator {
+    /// Add the local matrix of the element `el`, whose geometry is given by `trans`, to `elmat`.
+    ///
+    /// `elmat` is zeroed beforehand, and has one row and column per shape function of `el`,
+    /// so the [`FiniteElementSpace`] must have a vector dimension of 1, which is checked
+    /// when the integrator is added to a [`BilinearForm`].
+    fn assemble_element_matrix(
+        &self,
+        el: &FiniteElement,
+        trans: &mut ElementTransformation,
+        elmat: &mut DenseMatrixMut,
+    );
+
+    /// Add the local matrix that couples the elements `el1` and `el2` on both sides of a face
+    /// to `elmat`, see [`BilinearForm::add_interior_face_integrator`].
+    ///
+    /// `elmat` is zeroed beforehand, and has one row and column per shape function of `el1`,
+    /// followed by those of `el2`. On boundary faces, `trans.elem2_no()` is `None`, `el2` is the
+    /// same as `el1`, and `elmat` only has the rows and columns of `el1`.
+    ///
+    /// The default implementation panics, as face integrals are optional.
+    fn assemble_face_matrix(
+        &self,
+        _el1: &FiniteElement,
+        _el2: &FiniteElement,
+        _trans: &mut FaceElementTransformations,
+        _elmat: &mut DenseMatrixMut,
+    ) {
+        panic!("assemble_face_matrix() is not implemented");
+    }
+}
+
+////////////////////////////////
+// RustBilinearFormIntegrator //
+////////////////////////////////
+
+/// Adapts a [`CustomBilinearFormIntegrator`] to be added to a [`BilinearForm`].
+///
+/// The integrator must be `'static`, as forms don't keep track of what their
+/// integrators borrow.
+pub struct RustBilinearFormIntegrator {
+    inner: UniquePtr<mfem_sys::ffi::RustBilinearFormIntegrator<'static>>,
+}
+
+impl RustBilinearFormIntegrator {
+    pub fn new<I>(integ: I) -> Self
+    where
+        I: CustomBilinearFormIntegrator + 'static,
+    {
+        let integ = std::rc::Rc::new(integ);
+        let face_integ = integ.clone();
+        let integ = Box::new(mfem_sys::RustBilinearIntegrator::new(
+            move |el, trans, elmat| {
+                integ.assemble_element_matrix(
+                    &FiniteElement { inner: el },
+                    &mut ElementTransformation::new(trans),
+                    &mut DenseMatrixMut { inner: elmat },
+                )
+            },
+            move |el1, el2, trans, elmat| {
+                face_integ.assemble_face_matrix(
+                    &FiniteElement { inner: el1 },
+                    &FiniteElement { inner: el2 },
+                    &mut FaceElementTransformations::new(trans),
+                    &mut DenseMatrixMut { inner: elmat },
+                )
+            },
+        ));
+        // SAFETY: The C++ side takes ownership, and drops `integ` along with `inner`.
+        let inner = unsafe { mfem_sys::ffi::RustBilinearFormIntegrator_ctor(Box::into_raw(integ)) };
+        Self { inner }
+    }
+}
+
+impl BilinearFormIntegrator for RustBilinearFormIntegrator {}
+
+impl AsBase<mfem_sys::ffi::BilinearFormIntegrator> for RustBilinearFormIntegrator {
+    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
+        mfem_sys::ffi::RustBilinearFormIntegrator_as_BFI(&self.inner)
+    }
+}
+
+impl IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>> for RustBilinearFormIntegrator {
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
+        mfem_sys::ffi::RustBilinearFormIntegrator_into_BFI(self.inner)
+    }
+}
+
+/////////////////////////////
+// NonlinearFormIntegrator //
+/////////////////////////////
+
+pub trait NonlinearFormIntegrator:
+    AsBase<mfem_sys::ffi::NonlinearFormIntegrator>
+    + IntoBase<UniquePtr<mfem_sys::ffi::NonlinearFormIntegrator>>
+{
+}
+
+///////////////////////
+// HyperelasticModel //
+///////////////////////
+
+/// A strain energy density `W(F)` of the deformation gradient `F`.
+pub trait HyperelasticModel: AsBase<mfem_sys::ffi::HyperelasticModel> {}
+
+/////////////////////
+// NeoHookeanModel //
+/////////////////////
+
+/// `W(F) = mu/2 (J^(-2/dim) tr(F^T F) - dim) + K/2 (J - 1)^2` with `J = det(F)`,
+/// given the shear modulus `mu` and the bulk modulus `K`.
+pub struct NeoHookeanModel<'coeff> {
+    inner: UniquePtr<mfem_sys::ffi::NeoHookeanModel<'coeff>>,
+}
+
+impl<'coeff> NeoHookeanModel<'coeff> {
+    pub fn new(mu: f64, k: f64) -> Self {
+        let inner = mfem_sys::ffi::NeoHookeanModel_ctor(mu, k);
+        Self { inner }
+    }
+
+    /// With moduli that vary in space.
+    pub fn with_coefficients(mu: &'coeff dyn Coefficient, k: &'coeff dyn Coefficient) -> Self {
+        let inner = mfem_sys::ffi::NeoHookeanModel_ctor_coeff(mu.as_base(), k.as_base());
+        Self { inner }
+    }
+}
+
+impl<'coeff> HyperelasticModel for NeoHookeanModel<'coeff> {}
+
+impl<'coeff> AsBase<mfem_sys::ffi::HyperelasticModel> for NeoHookeanModel<'coeff> {
+    fn as_base(&self) -> &mfem_sys::ffi::HyperelasticModel {
+        mfem_sys::ffi::NeoHookeanModel_as_HM(&self.inner)
+    }
+}
+
+///////////////////////////////
+// HyperelasticNLFIntegrator //
+///////////////////////////////
+
+/// `(dW/dF(grad x), grad v)` for a [`HyperelasticModel`] `W`, where `x` are the nodal positions
+/// in the deformed configuration, in a vector H1 space with `vdim` equal to the mesh dimension.
+///
+/// The energy is the integral of `W`.
+pub struct HyperelasticNLFIntegrator<'model> {
+    inner: UniquePtr<mfem_sys::ffi::HyperelasticNLFIntegrator<'model>>,
+}
+
+impl<'model> HyperelasticNLFIntegrator<'model> {
+    pub fn new(model: &'model dyn HyperelasticModel) -> Self {
+        let inner = mfem_sys::ffi::HyperelasticNLFIntegrator_ctor(model.as_base());
+        Self { inner }
+    }
+}
+
+impl<'model> NonlinearFormIntegrator for HyperelasticNLFIntegrator<'model> {}
+
+impl<'model> AsBase<mfem_sys::ffi::NonlinearFormIntegrator> for HyperelasticNLFIntegrator<'model> {
+    fn as_base(&self) -> &mfem_sys::ffi::NonlinearFormIntegrator {
+        mfem_sys::ffi::HyperelasticNLFIntegrator_as_NLFI(&self.inner)
+    }
+}
+
+impl<'model> IntoBase<UniquePtr<mfem_sys::ffi::NonlinearFormIntegrator>>
+    for HyperelasticNLFIntegrator<'model>
+{
+    fn into_base(self) -> UniquePtr<mfem_sys::ffi::NonlinearFormIntegrator> {
+        mfem_sys::ffi::HyperelasticNLFIntegrator_into_NLFI(self.inner)
+    }
+}
+
+//////////////
+// Operator //
+//////////////
+
+pub trait Operator: AsBase<mfem_sys::ffi::Operator> {
+    fn height(&self) -> i32 {
+        self.as_base().Height()
+    }
+
+    fn width(&self) -> i32 {
+        self.as_base().Width()
+    }
+
+    /// `y = A x`, where `x` has [`Operator::width`] and `y` has [`Operator::height`] entries.
+    fn mult<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        Self: Sized,
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::Operator_Mult(self.as_base(), x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+
+    /// `y = A^T x`, which fails if the operator doesn't implement it.
+    fn mult_transpose<X, Y>(&self, x: &X, y: &mut Y) -> Result<(), MfemError>
+    where
+        Self: Sized,
+        X: VectorRead,
+        Y: VectorLike,
+    {
+        mfem_sys::ffi::Operator_MultTranspose(self.as_base(), x.as_base(), y.as_base_mut())?;
+        Ok(())
+    }
+}
+
+/// An [`Operator`] owned by something else, e.g. the one passed to
+/// [`CustomSolver::set_operator`].
+pub struct OperatorRef<'a> {
+    inner: &'a mfem_sys::ffi::Operator,
+}
+
+impl<'a> OperatorRef<'a> {
+    /// The operator as a sparse matrix, if it is one.
+    pub fn as_sparse_matrix(&self) -> Option<SparseMatrixRef<'a>> {
+        mfem_sys::ffi::Operator_try_as_SparseMatrix(self.inner)
+            .ok()
+            .map(|inner| SparseMatrixRef { inner })
+    }
+}
+
+impl<'a> Operator for OperatorRef<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for OperatorRef<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        self.inner
+    }
+}
+
+////////////////////
+// CustomOperator //
+////////////////////
+
+/// A linear operator implemented in Rust, e.g. a matrix-free discretization, which becomes
+/// an [`Operator`] when wrapped in a [`RustOperator`].
+///
+/// Panics are caught and make the MFEM operation that applied the operator return an error.
+pub trait CustomOperator {
+    /// `y = A x`, where `x` has `width` and `y` has `height` entries, which must all be set.
+    fn mult(&self, x: &[f64], y: &mut [f64]);
+
+    /// `y = A^T x`, where `x` has `height` and `y` has `width` entries, which must all be set.
+    ///
+    /// The default implementation panics, as only some solvers need the transpose.
+    fn mult_transpose(&self, _x: &[f64], _y: &mut [f64]) {
+        panic!("mult_transpose() is not implemented");
+    }
+}
+
+//////////////////
+// RustOperator //
+//////////////////
+
+/// Adapts a [`CustomOperator`] to be used as an [`Operator`], e.g. by [`CgSolver`].
+pub struct RustOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::RustOperator<'a>>,
+}
+
+impl<'a> RustOperator<'a> {
+    /// A `height` x `width` operator.
+    pub fn new<Op>(height: usize, width: usize, op: Op) -> Self
+    where
+        Op: CustomOperator + 'a,
+    {
+        let op = std::rc::Rc::new(op);
+        let transpose_op = op.clone();
+        let mult = Box::new(mfem_sys::RustMult::new(
+            move |x, y| op.mult(x, y),
+            move |x, y| transpose_op.mult_transpose(x, y),
+        ));
+        // SAFETY: The C++ side takes ownership, and drops `mult` along with `inner`.
+        let inner = unsafe {
+            mfem_sys::ffi::RustOperator_ctor(height as i32, width as i32, Box::into_raw(mult))
+        };
+        Self { inner }
+    }
+}
+
+impl<'a> Operator for RustOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for RustOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::RustOperator_as_Operator(&self.inner)
+    }
+}
+
+////////////////////
+// OperatorHandle //
+////////////////////
+
+pub use mfem_sys::ffi::OperatorType;
+
+pub struct OperatorHandle {
+    inner: UniquePtr<mfem_sys::ffi::OperatorHandle>,
+}
+
+impl OperatorHandle {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::OperatorHandle_ctor();
+        Self { inner }
+    }
+
+    pub fn get_type(&self) -> OperatorType {
+        self.inner.Type()
+    }
+}
+
+impl Operator for OperatorHandle {}
+
+impl AsBase<mfem_sys::ffi::Operator> for OperatorHandle {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::OperatorHandle_as_ref(&self.inner)
+    }
+}
+
+//////////////////
+// SparseMatrix //
+//////////////////
+
+pub struct SparseMatrix {
+    inner: UniquePtr<mfem_sys::ffi::SparseMatrix>,
+}
+
+/// Takes over the matrix if the handle owns it, and copies it otherwise.
+impl TryFrom<OperatorHandle> for SparseMatrix {
+    type Error = MfemError;
+
+    fn try_from(value: OperatorHandle) -> Result<Self, Self::Error> {
+        let op_type = value.get_type();
+        let inner = mfem_sys::ffi::OperatorHandle_into_SparseMatrix(value.inner).map_err(|_| {
+            MfemError::OperatorHandleTypeMismatch(OperatorType::MFEM_SPARSEMAT, op_type)
+        })?;
+        Ok(Self { inner })
     }
 }
 
-impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::LinearFormIntegrator>>
-    for DomainLFIntegrator<'coeff>
-{
-    fn into_base(self) -> UniquePtr<mfem_sys::ffi::LinearFormIntegrator> {
-        mfem_sys::ffi::DomainLFIntegrator_into_LFI(self.inner)
+pub struct SparseMatrixRef<'a> {
+    inner: &'a mfem_sys::ffi::SparseMatrix,
+}
+
+impl<'a> TryFrom<&'a OperatorHandle> for SparseMatrixRef<'a> {
+    // TODO(mkovaxx)
+    type Error = MfemError;
+
+    fn try_from(value: &'a OperatorHandle) -> Result<Self, Self::Error> {
+        let inner =
+            mfem_sys::ffi::OperatorHandle_try_as_SparseMatrix(&value.inner).map_err(|_| {
+                MfemError::OperatorHandleTypeMismatch(
+                    OperatorType::MFEM_SPARSEMAT,
+                    value.get_type(),
+                )
+            })?;
+        Ok(Self { inner })
+    }
+}
+
+/// The compressed sparse row (CSR) arrays of a finalized matrix, without copying.
+///
+/// The entries of row `i` are in `row_offsets()[i]..row_offsets()[i + 1]`
+/// of `col_indices()` and `values()`.
+impl<'a> SparseMatrixRef<'a> {
+    pub fn row_offsets(&self) -> Result<&'a [i32], MfemError> {
+        Ok(mfem_sys::ffi::SparseMatrix_GetI(self.inner)?)
+    }
+
+    pub fn col_indices(&self) -> Result<&'a [i32], MfemError> {
+        Ok(mfem_sys::ffi::SparseMatrix_GetJ(self.inner)?)
+    }
+
+    pub fn values(&self) -> Result<&'a [f64], MfemError> {
+        Ok(mfem_sys::ffi::SparseMatrix_GetData(self.inner)?)
+    }
+}
+
+impl<'a> Operator for SparseMatrixRef<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for SparseMatrixRef<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::SparseMatrix_as_Operator(self.inner)
+    }
+}
+
+///////////////////
+// BlockOperator //
+///////////////////
+
+/// An operator made of blocks of other operators, e.g. `[[M, B^T], [B, 0]]` for a
+/// saddle-point system, which acts on [`BlockVector`]s with the same offsets.
+///
+/// The blocks are borrowed, and the missing ones are zero.
+pub struct BlockOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::BlockOperator<'a>>,
+    // NOTE: Declared after `inner` so that they're dropped after it, as it refers to them.
+    row_offsets: UniquePtr<mfem_sys::ffi::ArrayInt>,
+    col_offsets: UniquePtr<mfem_sys::ffi::ArrayInt>,
+}
+
+impl<'a> BlockOperator<'a> {
+    /// A square operator with the same block offsets for the rows and the columns.
+    pub fn new(offsets: &[i32]) -> Result<Self, MfemError> {
+        Self::with_row_col_offsets(offsets, offsets)
+    }
+
+    pub fn with_row_col_offsets(
+        row_offsets: &[i32],
+        col_offsets: &[i32],
+    ) -> Result<Self, MfemError> {
+        let row_offsets = block_offsets(row_offsets)?;
+        let col_offsets = block_offsets(col_offsets)?;
+        let inner = mfem_sys::ffi::BlockOperator_ctor_row_col_offsets(&row_offsets, &col_offsets);
+        Ok(Self {
+            inner,
+            row_offsets,
+            col_offsets,
+        })
+    }
+
+    pub fn num_row_blocks(&self) -> usize {
+        self.inner.NumRowBlocks() as usize
+    }
+
+    pub fn num_col_blocks(&self) -> usize {
+        self.inner.NumColBlocks() as usize
+    }
+
+    pub fn row_offsets(&self) -> ArrayIntRef<'_> {
+        ArrayIntRef {
+            inner: &self.row_offsets,
+        }
+    }
+
+    pub fn col_offsets(&self) -> ArrayIntRef<'_> {
+        ArrayIntRef {
+            inner: &self.col_offsets,
+        }
+    }
+
+    /// Set block `(i, j)` to `c` times `op`, whose size must match the offsets.
+    pub fn set_block<Op>(&mut self, i: usize, j: usize, op: &'a Op, c: f64) -> Result<(), MfemError>
+    where
+        Op: Operator,
+    {
+        mfem_sys::ffi::BlockOperator_SetBlock(
+            self.inner.pin_mut(),
+            i as i32,
+            j as i32,
+            op.as_base(),
+            c,
+        )?;
+        Ok(())
+    }
+}
+
+impl<'a> Operator for BlockOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for BlockOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::BlockOperator_as_Operator(&self.inner)
+    }
+}
+
+/////////////////////
+// ProductOperator //
+/////////////////////
+
+/// The product `A B` of two borrowed operators.
+pub struct ProductOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::ProductOperator<'a>>,
+}
+
+impl<'a> ProductOperator<'a> {
+    pub fn new<A, B>(a: &'a A, b: &'a B) -> Result<Self, MfemError>
+    where
+        A: Operator,
+        B: Operator,
+    {
+        let inner = mfem_sys::ffi::ProductOperator_ctor(a.as_base(), b.as_base())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'a> Operator for ProductOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for ProductOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::ProductOperator_as_Operator(&self.inner)
+    }
+}
+
+///////////////////////
+// TransposeOperator //
+///////////////////////
+
+/// The transpose `A^T` of a borrowed operator, which must implement
+/// [`Operator::mult_transpose`].
+pub struct TransposeOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::TransposeOperator<'a>>,
+}
+
+impl<'a> TransposeOperator<'a> {
+    pub fn new<A>(a: &'a A) -> Self
+    where
+        A: Operator,
+    {
+        let inner = mfem_sys::ffi::TransposeOperator_ctor(a.as_base());
+        Self { inner }
+    }
+}
+
+impl<'a> Operator for TransposeOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for TransposeOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::TransposeOperator_as_Operator(&self.inner)
+    }
+}
+
+/////////////////
+// SumOperator //
+/////////////////
+
+/// The linear combination `alpha A + beta B` of two borrowed operators.
+pub struct SumOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::SumOperator<'a>>,
+}
+
+impl<'a> SumOperator<'a> {
+    pub fn new<A, B>(a: &'a A, alpha: f64, b: &'a B, beta: f64) -> Result<Self, MfemError>
+    where
+        A: Operator,
+        B: Operator,
+    {
+        let inner = mfem_sys::ffi::SumOperator_ctor(a.as_base(), alpha, b.as_base(), beta)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'a> Operator for SumOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for SumOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::SumOperator_as_Operator(&self.inner)
+    }
+}
+
+////////////////////
+// ScaledOperator //
+////////////////////
+
+/// The borrowed operator `A` scaled to `alpha A`.
+pub struct ScaledOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::ScaledOperator<'a>>,
+}
+
+impl<'a> ScaledOperator<'a> {
+    pub fn new<A>(a: &'a A, alpha: f64) -> Self
+    where
+        A: Operator,
+    {
+        let inner = mfem_sys::ffi::ScaledOperator_ctor(a.as_base(), alpha);
+        Self { inner }
+    }
+}
+
+impl<'a> Operator for ScaledOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for ScaledOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::ScaledOperator_as_Operator(&self.inner)
+    }
+}
+
+/////////////////
+// RapOperator //
+/////////////////
+
+/// The projected operator `R A P` of borrowed operators, where `R` is given by its
+/// transpose `Rt`, e.g. for a Galerkin coarse operator with `Rt = P`.
+pub struct RapOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::RAPOperator<'a>>,
+}
+
+impl<'a> RapOperator<'a> {
+    pub fn new<Rt, A, P>(rt: &'a Rt, a: &'a A, p: &'a P) -> Result<Self, MfemError>
+    where
+        Rt: Operator,
+        A: Operator,
+        P: Operator,
+    {
+        let inner = mfem_sys::ffi::RAPOperator_ctor(rt.as_base(), a.as_base(), p.as_base())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'a> Operator for RapOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for RapOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::RAPOperator_as_Operator(&self.inner)
+    }
+}
+
+///////////////////////////
+// TripleProductOperator //
+///////////////////////////
+
+/// The product `A B C` of three borrowed operators.
+pub struct TripleProductOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::TripleProductOperator<'a>>,
+}
+
+impl<'a> TripleProductOperator<'a> {
+    pub fn new<A, B, C>(a: &'a A, b: &'a B, c: &'a C) -> Result<Self, MfemError>
+    where
+        A: Operator,
+        B: Operator,
+        C: Operator,
+    {
+        let inner =
+            mfem_sys::ffi::TripleProductOperator_ctor(a.as_base(), b.as_base(), c.as_base())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'a> Operator for TripleProductOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for TripleProductOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::TripleProductOperator_as_Operator(&self.inner)
+    }
+}
+
+pub use mfem_sys::ffi::DiagonalPolicy;
+
+/////////////////////////
+// ConstrainedOperator //
+/////////////////////////
+
+/// A borrowed square operator `A` with the rows and columns of the constrained
+/// (e.g. essential) degrees of freedom eliminated, whose diagonal entries are set
+/// according to a [`DiagonalPolicy`].
+pub struct ConstrainedOperator<'a> {
+    inner: UniquePtr<mfem_sys::ffi::ConstrainedOperator<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    constraint_list: UniquePtr<mfem_sys::ffi::ArrayInt>,
+}
+
+impl<'a> ConstrainedOperator<'a> {
+    /// The list is copied, so it can be dropped afterwards.
+    pub fn new<A>(
+        a: &'a A,
+        constraint_list: &ArrayInt,
+        diag_policy: DiagonalPolicy,
+    ) -> Result<Self, MfemError>
+    where
+        A: Operator,
+    {
+        let constraint_list = mfem_sys::ffi::ArrayInt_ctor_slice(
+            ArrayIntRef {
+                inner: &constraint_list.inner,
+            }
+            .as_slice(),
+        );
+        let inner =
+            mfem_sys::ffi::ConstrainedOperator_ctor(a.as_base(), &constraint_list, diag_policy)?;
+        Ok(Self {
+            inner,
+            constraint_list,
+        })
+    }
+
+    pub fn constraint_list(&self) -> ArrayIntRef<'_> {
+        ArrayIntRef {
+            inner: &self.constraint_list,
+        }
+    }
+
+    /// Adjust the right-hand side `b` of `A x = b` for the constrained values in `x`:
+    /// `b -= A x_c` on the other rows, and the constrained rows of `b` are set so that the
+    /// solution keeps the values of `x` there.
+    pub fn eliminate_rhs<X, B>(&self, x: &X, b: &mut B) -> Result<(), MfemError>
+    where
+        X: VectorRead,
+        B: VectorLike,
+    {
+        mfem_sys::ffi::ConstrainedOperator_EliminateRHS(&self.inner, x.as_base(), b.as_base_mut())?;
+        Ok(())
+    }
+}
+
+impl<'a> Operator for ConstrainedOperator<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for ConstrainedOperator<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::ConstrainedOperator_as_Operator(&self.inner)
+    }
+}
+
+////////////
+// Solver //
+////////////
+
+pub trait Solver: AsBaseMut<mfem_sys::ffi::Solver> {
+    // TODO(mkovaxx)
+}
+
+//////////////////
+// CustomSolver //
+//////////////////
+
+/// A solver or preconditioner implemented in Rust, which becomes a [`Solver`]
+/// when wrapped in a [`RustSolver`], e.g. for [`CgSolver::preconditioner`].
+///
+/// Panics are caught and make the MFEM operation that called the solver return an error.
+pub trait CustomSolver {
+    /// Called with the operator `A` to invert, e.g. by the `set_operator()` of the solver that
+    /// this preconditions, or with each new gradient by a [`NewtonSolver`].
+    ///
+    /// `op` is only borrowed for the call, so copy what's needed, e.g. the entries of
+    /// [`OperatorRef::as_sparse_matrix`].
+    fn set_operator(&mut self, op: &OperatorRef);
+
+    /// `y = A^-1 x`, or an approximation of it, where `x` has `height` and `y` has `width`
+    /// entries of `A`, which must all be set.
+    fn mult(&self, x: &[f64], y: &mut [f64]);
+}
+
+////////////////
+// RustSolver //
+////////////////
+
+/// Adapts a [`CustomSolver`] to be used as a [`Solver`].
+pub struct RustSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::RustSolver<'a>>,
+}
+
+impl<'a> RustSolver<'a> {
+    pub fn new<S>(solver: S) -> Self
+    where
+        S: CustomSolver + 'a,
+    {
+        let solver = std::rc::Rc::new(std::cell::RefCell::new(solver));
+        let mult_solver = solver.clone();
+        let solve = Box::new(mfem_sys::RustSolve::new(
+            move |op| solver.borrow_mut().set_operator(&OperatorRef { inner: op }),
+            move |x, y| mult_solver.borrow().mult(x, y),
+        ));
+        // SAFETY: The C++ side takes ownership, and drops `solve` along with `inner`.
+        let inner = unsafe { mfem_sys::ffi::RustSolver_ctor(Box::into_raw(solve)) };
+        Self { inner }
+    }
+}
+
+impl<'a> Solver for RustSolver<'a> {}
+
+impl<'a> AsBaseMut<mfem_sys::ffi::Solver> for RustSolver<'a> {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Solver> {
+        mfem_sys::ffi::RustSolver_as_mut_Solver(self.inner.pin_mut())
+    }
+}
+
+impl<'a> Operator for RustSolver<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for RustSolver<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::RustSolver_as_Operator(&self.inner)
+    }
+}
+
+////////////////
+// GSSmoother //
+////////////////
+
+pub struct GsSmoother<'mat> {
+    inner: UniquePtr<mfem_sys::ffi::GSSmoother<'mat>>,
+}
+
+impl<'mat> GsSmoother<'mat> {
+    pub fn new(a: &SparseMatrixRef<'mat>, t: i32, it: i32) -> Result<Self, MfemError> {
+        let inner = mfem_sys::ffi::GSSmoother_ctor(a.inner, t, it)?;
+        Ok(Self { inner })
+    }
+
+    /// For use as the preconditioner of an [`IterativeSolver`], which sets the matrix.
+    /// Applying it before the matrix is set is an error.
+    pub fn without_matrix(t: i32, it: i32) -> Self {
+        let inner = mfem_sys::ffi::GSSmoother_ctor_type(t, it);
+        Self { inner }
+    }
+}
+
+impl<'mat> Solver for GsSmoother<'mat> {}
+
+impl<'mat> AsBaseMut<mfem_sys::ffi::Solver> for GsSmoother<'mat> {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Solver> {
+        mfem_sys::ffi::GSSmoother_as_mut_Solver(self.inner.pin_mut())
+    }
+}
+
+impl<'mat> Operator for GsSmoother<'mat> {}
+
+impl<'mat> AsBase<mfem_sys::ffi::Operator> for GsSmoother<'mat> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::GSSmoother_as_Operator(&self.inner)
     }
 }
 
-//////////////////
-// BilinearForm //
-//////////////////
+/////////////////////////////////
+// BlockDiagonalPreconditioner //
+/////////////////////////////////
 
-pub struct BilinearForm<'fes> {
-    inner: UniquePtr<mfem_sys::ffi::BilinearForm<'fes>>,
+/// A preconditioner made of diagonal blocks, e.g. approximate inverses of the diagonal
+/// blocks of a [`BlockOperator`] with the same offsets.
+///
+/// The blocks are borrowed, and the missing ones are the identity.
+pub struct BlockDiagonalPreconditioner<'a> {
+    inner: UniquePtr<mfem_sys::ffi::BlockDiagonalPreconditioner<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    offsets: UniquePtr<mfem_sys::ffi::ArrayInt>,
 }
 
-impl<'fes> BilinearForm<'fes> {
-    pub fn new(fespace: &'fes FiniteElementSpace) -> Self {
-        let inner = mfem_sys::ffi::BilinearForm_ctor_fes(&fespace.inner);
-        Self { inner }
+impl<'a> BlockDiagonalPreconditioner<'a> {
+    pub fn new(offsets: &[i32]) -> Result<Self, MfemError> {
+        let offsets = block_offsets(offsets)?;
+        let inner = mfem_sys::ffi::BlockDiagonalPreconditioner_ctor_offsets(&offsets);
+        Ok(Self { inner, offsets })
     }
 
-    pub fn add_domain_integrator<Bfi>(&mut self, bfi: Bfi)
-    where
-        Bfi: BilinearFormIntegrator,
-    {
-        mfem_sys::ffi::BilinearForm_AddDomainIntegrator(self.inner.pin_mut(), bfi.into_base());
+    pub fn num_blocks(&self) -> usize {
+        self.inner.NumBlocks() as usize
     }
 
-    pub fn assemble(&mut self, skip_zeros: bool) {
-        self.inner
-            .pin_mut()
-            .Assemble(if skip_zeros { 1 } else { 0 })
+    pub fn offsets(&self) -> ArrayIntRef<'_> {
+        ArrayIntRef {
+            inner: &self.offsets,
+        }
     }
 
-    pub fn form_linear_system<X, B>(
-        &self,
-        ess_tdof_list: &ArrayInt,
-        x: &X,
-        b: &B,
-        a_mat: &mut OperatorHandle,
-        x_vec: &mut Vector,
-        b_vec: &mut Vector,
-    ) where
-        X: VectorLike,
-        B: VectorLike,
+    /// Set diagonal block `i` to `op`, whose size must match the offsets.
+    pub fn set_diagonal_block<Op>(&mut self, i: usize, op: &'a Op) -> Result<(), MfemError>
+    where
+        Op: Operator,
     {
-        mfem_sys::ffi::BilinearForm_FormLinearSystem(
-            &self.inner,
-            &ess_tdof_list.inner,
-            &x.as_base(),
-            &b.as_base(),
-            a_mat.inner.pin_mut(),
-            x_vec.inner.pin_mut(),
-            b_vec.inner.pin_mut(),
-        );
+        mfem_sys::ffi::BlockDiagonalPreconditioner_SetDiagonalBlock(
+            self.inner.pin_mut(),
+            i as i32,
+            op.as_base(),
+        )?;
+        Ok(())
+    }
+}
+
+impl<'a> Operator for BlockDiagonalPreconditioner<'a> {}
+
+impl<'a> AsBase<mfem_sys::ffi::Operator> for BlockDiagonalPreconditioner<'a> {
+    fn as_base(&self) -> &mfem_sys::ffi::Operator {
+        mfem_sys::ffi::BlockDiagonalPreconditioner_as_Operator(&self.inner)
     }
+}
 
-    pub fn recover_fem_solution<B, X>(&mut self, x_vec: &Vector, b_vec: &B, x: &mut X)
-    where
-        B: VectorLike,
-        X: VectorLike,
-    {
-        self.inner
-            .pin_mut()
-            .RecoverFEMSolution(&x_vec.inner, &b_vec.as_base(), x.as_base_mut());
+impl<'a> Solver for BlockDiagonalPreconditioner<'a> {}
+
+impl<'a> AsBaseMut<mfem_sys::ffi::Solver> for BlockDiagonalPreconditioner<'a> {
+    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Solver> {
+        mfem_sys::ffi::BlockDiagonalPreconditioner_as_mut_Solver(self.inner.pin_mut())
     }
 }
 
 ////////////////////////////
-// BilinearFormIntegrator //
+// IterativeSolverMonitor //
 ////////////////////////////
 
-pub trait BilinearFormIntegrator:
-    AsBase<mfem_sys::ffi::BilinearFormIntegrator>
-    + IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
-{
-    // TODO(mkovaxx)
+/// Observes the iterations of an [`IterativeSolver`], e.g. to record the residual history,
+/// and can stop it early. See `monitor()` on the solvers.
+pub trait IterativeSolverMonitor {
+    /// Called with the residual `r` and its `norm` at each `iteration`,
+    /// and once more when the solver is done, with `is_final` set.
+    ///
+    /// Returning [`ControlFlow::Break`] stops the solver, which then returns
+    /// [`MfemError::Cancelled`]. When `is_final` is set, the solver is done anyway.
+    fn monitor_residual(
+        &mut self,
+        iteration: i32,
+        norm: f64,
+        r: &VectorRef,
+        is_final: bool,
+    ) -> ControlFlow<()>;
 }
 
-/////////////////////////
-// DiffusionIntegrator //
-/////////////////////////
+/// Settings and convergence statistics shared by the iterative solvers.
+pub trait IterativeSolver:
+    Solver + AsBase<mfem_sys::ffi::IterativeSolver> + AsBaseMut<mfem_sys::ffi::IterativeSolver>
+{
+    /// Stop when the residual norm is below `rel_tol` times the initial one, defaults to 0.
+    fn rel_tol(&mut self, rel_tol: f64) -> &mut Self {
+        iterative_solver_mut(self).SetRelTol(rel_tol);
+        self
+    }
 
-pub struct DiffusionIntegrator<'coeff> {
-    inner: UniquePtr<mfem_sys::ffi::DiffusionIntegrator<'coeff>>,
-}
+    /// Stop when the residual norm is below `abs_tol`, defaults to 0.
+    fn abs_tol(&mut self, abs_tol: f64) -> &mut Self {
+        iterative_solver_mut(self).SetAbsTol(abs_tol);
+        self
+    }
 
-impl<'coeff> DiffusionIntegrator<'coeff> {
-    pub fn new(coeff: &'coeff dyn Coefficient) -> Self {
-        let inner = mfem_sys::ffi::DiffusionIntegrator_ctor(coeff.as_base());
-        Self { inner }
+    /// Defaults to 10.
+    fn max_iter(&mut self, max_iter: i32) -> &mut Self {
+        iterative_solver_mut(self).SetMaxIter(max_iter);
+        self
     }
-}
 
-impl<'coeff> BilinearFormIntegrator for DiffusionIntegrator<'coeff> {}
+    /// `-1` prints nothing, `0` only warnings, `1` every iteration, `2` the final iteration,
+    /// `3` the convergence rate. Defaults to `-1`.
+    fn print_level(&mut self, print_level: i32) -> &mut Self {
+        mfem_sys::ffi::IterativeSolver_SetPrintLevel(iterative_solver_mut(self), print_level);
+        self
+    }
 
-impl<'coeff> AsBase<mfem_sys::ffi::BilinearFormIntegrator> for DiffusionIntegrator<'coeff> {
-    fn as_base(&self) -> &mfem_sys::ffi::BilinearFormIntegrator {
-        mfem_sys::ffi::DiffusionIntegrator_as_BFI(&self.inner)
+    /// Whether `x` holds the initial guess in `mult`, otherwise it starts from zero.
+    fn iterative_mode(&mut self, iterative_mode: bool) -> &mut Self {
+        mfem_sys::ffi::IterativeSolver_SetIterativeMode(iterative_solver_mut(self), iterative_mode);
+        self
+    }
+
+    /// Whether the last `mult` reached the tolerance.
+    fn converged(&self) -> bool {
+        AsBase::<mfem_sys::ffi::IterativeSolver>::as_base(self).GetConverged()
+    }
+
+    fn num_iterations(&self) -> i32 {
+        AsBase::<mfem_sys::ffi::IterativeSolver>::as_base(self).GetNumIterations()
+    }
+
+    /// The residual norm before the last `mult`.
+    fn initial_norm(&self) -> f64 {
+        AsBase::<mfem_sys::ffi::IterativeSolver>::as_base(self).GetInitialNorm()
+    }
+
+    /// The residual norm after the last `mult`.
+    fn final_norm(&self) -> f64 {
+        AsBase::<mfem_sys::ffi::IterativeSolver>::as_base(self).GetFinalNorm()
+    }
+
+    /// The convergence statistics of the last `mult`.
+    fn report(&self) -> SolveReport {
+        SolveReport {
+            converged: self.converged(),
+            iterations: self.num_iterations(),
+            initial_norm: self.initial_norm(),
+            final_norm: self.final_norm(),
+        }
     }
 }
 
-impl<'coeff> IntoBase<UniquePtr<mfem_sys::ffi::BilinearFormIntegrator>>
-    for DiffusionIntegrator<'coeff>
+/// How an [`IterativeSolver`] did in a solve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveReport {
+    pub converged: bool,
+    pub iterations: i32,
+    pub initial_norm: f64,
+    pub final_norm: f64,
+}
+
+// `Solver` also provides an `as_base_mut()`, so the call needs to be disambiguated.
+fn iterative_solver_mut<S>(solver: &mut S) -> std::pin::Pin<&mut mfem_sys::ffi::IterativeSolver>
+where
+    S: IterativeSolver + ?Sized,
 {
-    fn into_base(self) -> UniquePtr<mfem_sys::ffi::BilinearFormIntegrator> {
-        mfem_sys::ffi::DiffusionIntegrator_into_BFI(self.inner)
-    }
+    AsBaseMut::<mfem_sys::ffi::IterativeSolver>::as_base_mut(solver)
+}
+
+// Implements the traits of an iterative solver,
+// and `set_operator()`, `mult()` and `solve()` for solving with it.
+macro_rules! impl_iterative_solver {
+    ($name:ident, $as_base:ident, $as_base_mut:ident, $as_solver:ident) => {
+        impl<'a> $name<'a> {
+            /// Solve with `op`, borrowed while the solver is in use.
+            ///
+            /// This also sets the operator of the preconditioner, if there is one already.
+            pub fn set_operator<Op>(&mut self, op: &'a Op) -> Result<(), MfemError>
+            where
+                Op: Operator,
+            {
+                mfem_sys::ffi::IterativeSolver_SetOperator(
+                    iterative_solver_mut(self),
+                    op.as_base(),
+                )?;
+                Ok(())
+            }
+
+            /// Call `monitor` at each iteration, borrowed while the solver is in use.
+            ///
+            /// This replaces the previous monitor, if any.
+            pub fn monitor<M>(&mut self, monitor: &'a mut M) -> &mut Self
+            where
+                M: IterativeSolverMonitor + ?Sized,
+            {
+                let monitor = Box::new(mfem_sys::RustSolverMonitor::new(
+                    move |it, norm, r, is_final| {
+                        monitor
+                            .monitor_residual(it, norm, &VectorRef { inner: r }, is_final)
+                            .is_break()
+                    },
+                ));
+                // SAFETY: The C++ side takes ownership, and drops `monitor` along with `inner`.
+                let mut inner = unsafe {
+                    mfem_sys::ffi::RustIterativeSolverMonitor_ctor(Box::into_raw(monitor))
+                };
+                mfem_sys::ffi::IterativeSolver_SetMonitor(
+                    iterative_solver_mut(self),
+                    inner.pin_mut(),
+                );
+                self.monitor = Some(inner);
+                self
+            }
+
+            /// Solve `op(x) = b` for `x`.
+            pub fn mult<B, X>(&self, b: &B, x: &mut X) -> Result<(), MfemError>
+            where
+                B: VectorRead,
+                X: VectorLike,
+            {
+                mfem_sys::ffi::IterativeSolver_Mult(
+                    AsBase::<mfem_sys::ffi::IterativeSolver>::as_base(self),
+                    b.as_base(),
+                    x.as_base_mut(),
+                )?;
+                Ok(())
+            }
+
+            /// Solve `op(x) = b` for `x`, and report how it went.
+            ///
+            /// Returns [`MfemError::NotConverged`] if the tolerance wasn't reached in `max_iter`
+            /// iterations, in which case `x` holds the last iterate.
+            pub fn solve<B, X>(&self, b: &B, x: &mut X) -> Result<SolveReport, MfemError>
+            where
+                B: VectorRead,
+                X: VectorLike,
+            {
+                self.mult(b, x)?;
+                let report = self.report();
+                if report.converged {
+                    Ok(report)
+                } else {
+                    Err(MfemError::NotConverged(report))
+                }
+            }
+        }
+
+        impl<'a> IterativeSolver for $name<'a> {}
+
+        impl<'a> Solver for $name<'a> {}
+
+        impl<'a> AsBase<mfem_sys::ffi::IterativeSolver> for $name<'a> {
+            fn as_base(&self) -> &mfem_sys::ffi::IterativeSolver {
+                mfem_sys::ffi::$as_base(&self.inner)
+            }
+        }
+
+        impl<'a> AsBaseMut<mfem_sys::ffi::IterativeSolver> for $name<'a> {
+            fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::IterativeSolver> {
+                mfem_sys::ffi::$as_base_mut(self.inner.pin_mut())
+            }
+        }
+
+        impl<'a> AsBaseMut<mfem_sys::ffi::Solver> for $name<'a> {
+            fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Solver> {
+                mfem_sys::ffi::$as_solver(self.inner.pin_mut())
+            }
+        }
+    };
+}
+
+// Implements `preconditioner()` on a Krylov solver.
+macro_rules! impl_krylov_solver {
+    ($name:ident) => {
+        impl<'a> $name<'a> {
+            /// Call this before `set_operator()`, which passes the operator on to `prec`.
+            pub fn preconditioner<S>(&mut self, prec: &'a mut S) -> &mut Self
+            where
+                S: Solver,
+            {
+                mfem_sys::ffi::IterativeSolver_SetPreconditioner(
+                    iterative_solver_mut(self),
+                    prec.as_base_mut(),
+                );
+                self
+            }
+        }
+    };
 }
 
 //////////////
-// Operator //
+// CGSolver //
 //////////////
 
-pub trait Operator: AsBase<mfem_sys::ffi::Operator> {
-    fn height(&self) -> i32 {
-        self.as_base().Height()
+/// Conjugate gradients, for symmetric positive definite operators and preconditioners.
+pub struct CgSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::CGSolver<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    monitor: Option<UniquePtr<mfem_sys::ffi::RustIterativeSolverMonitor<'a>>>,
+}
+
+impl<'a> CgSolver<'a> {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::CGSolver_ctor();
+        Self {
+            inner,
+            monitor: None,
+        }
     }
 }
 
-////////////////////
-// OperatorHandle //
-////////////////////
+impl_iterative_solver!(
+    CgSolver,
+    CGSolver_as_IterativeSolver,
+    CGSolver_as_mut_IterativeSolver,
+    CGSolver_as_mut_Solver
+);
+impl_krylov_solver!(CgSolver);
 
-pub use mfem_sys::ffi::OperatorType;
+/////////////////
+// GMRESSolver //
+/////////////////
 
-pub struct OperatorHandle {
-    inner: UniquePtr<mfem_sys::ffi::OperatorHandle>,
+/// Restarted GMRES, for general operators.
+pub struct GmresSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::GMRESSolver<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    monitor: Option<UniquePtr<mfem_sys::ffi::RustIterativeSolverMonitor<'a>>>,
 }
 
-impl OperatorHandle {
+impl<'a> GmresSolver<'a> {
     pub fn new() -> Self {
-        let inner = mfem_sys::ffi::OperatorHandle_ctor();
-        Self { inner }
+        let inner = mfem_sys::ffi::GMRESSolver_ctor();
+        Self {
+            inner,
+            monitor: None,
+        }
     }
 
-    pub fn get_type(&self) -> OperatorType {
-        self.inner.Type()
+    /// Restart after this many iterations, defaults to 50.
+    pub fn kdim(&mut self, kdim: i32) -> &mut Self {
+        self.inner.pin_mut().SetKDim(kdim);
+        self
     }
 }
 
-impl Operator for OperatorHandle {}
-
-impl AsBase<mfem_sys::ffi::Operator> for OperatorHandle {
-    fn as_base(&self) -> &mfem_sys::ffi::Operator {
-        mfem_sys::ffi::OperatorHandle_as_ref(&self.inner)
-    }
-}
+impl_iterative_solver!(
+    GmresSolver,
+    GMRESSolver_as_IterativeSolver,
+    GMRESSolver_as_mut_IterativeSolver,
+    GMRESSolver_as_mut_Solver
+);
+impl_krylov_solver!(GmresSolver);
 
 //////////////////
-// SparseMatrix //
+// FGMRESSolver //
 //////////////////
 
-pub struct SparseMatrix {
-    inner: UniquePtr<mfem_sys::ffi::SparseMatrix>,
+/// Flexible restarted GMRES, for general operators and preconditioners that change
+/// between iterations, e.g. inner iterative solvers.
+pub struct FgmresSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::FGMRESSolver<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    monitor: Option<UniquePtr<mfem_sys::ffi::RustIterativeSolverMonitor<'a>>>,
 }
 
-impl<'a> TryFrom<OperatorHandle> for SparseMatrix {
-    type Error = MfemError;
+impl<'a> FgmresSolver<'a> {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::FGMRESSolver_ctor();
+        Self {
+            inner,
+            monitor: None,
+        }
+    }
 
-    fn try_from(value: OperatorHandle) -> Result<Self, Self::Error> {
-        todo!()
+    /// Restart after this many iterations, defaults to 50.
+    pub fn kdim(&mut self, kdim: i32) -> &mut Self {
+        self.inner.pin_mut().SetKDim(kdim);
+        self
     }
 }
 
-pub struct SparseMatrixRef<'a> {
-    inner: &'a mfem_sys::ffi::SparseMatrix,
-}
+impl_iterative_solver!(
+    FgmresSolver,
+    FGMRESSolver_as_IterativeSolver,
+    FGMRESSolver_as_mut_IterativeSolver,
+    FGMRESSolver_as_mut_Solver
+);
+impl_krylov_solver!(FgmresSolver);
 
-impl<'a> TryFrom<&'a OperatorHandle> for SparseMatrixRef<'a> {
-    // TODO(mkovaxx)
-    type Error = MfemError;
+////////////////////
+// BiCGSTABSolver //
+////////////////////
 
-    fn try_from(value: &'a OperatorHandle) -> Result<Self, Self::Error> {
-        let inner =
-            mfem_sys::ffi::OperatorHandle_try_as_SparseMatrix(&value.inner).map_err(|_| {
-                MfemError::OperatorHandleTypeMismatch(
-                    OperatorType::MFEM_SPARSEMAT,
-                    value.get_type(),
-                )
-            })?;
-        Ok(Self { inner })
-    }
+/// BiCGSTAB, for general operators.
+pub struct BicgstabSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::BiCGSTABSolver<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    monitor: Option<UniquePtr<mfem_sys::ffi::RustIterativeSolverMonitor<'a>>>,
 }
 
-////////////
-// Solver //
-////////////
-
-pub trait Solver: AsBaseMut<mfem_sys::ffi::Solver> {
-    // TODO(mkovaxx)
+impl<'a> BicgstabSolver<'a> {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::BiCGSTABSolver_ctor();
+        Self {
+            inner,
+            monitor: None,
+        }
+    }
 }
 
-////////////////
-// GSSmoother //
-////////////////
+impl_iterative_solver!(
+    BicgstabSolver,
+    BiCGSTABSolver_as_IterativeSolver,
+    BiCGSTABSolver_as_mut_IterativeSolver,
+    BiCGSTABSolver_as_mut_Solver
+);
+impl_krylov_solver!(BicgstabSolver);
 
-pub struct GsSmoother<'mat> {
-    inner: UniquePtr<mfem_sys::ffi::GSSmoother<'mat>>,
+//////////////////
+// MINRESSolver //
+//////////////////
+
+/// MINRES, for symmetric operators and symmetric positive definite preconditioners.
+pub struct MinresSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::MINRESSolver<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    monitor: Option<UniquePtr<mfem_sys::ffi::RustIterativeSolverMonitor<'a>>>,
 }
 
-impl<'mat> GsSmoother<'mat> {
-    pub fn new(a: &SparseMatrixRef<'mat>, t: i32, it: i32) -> Self {
-        let inner = mfem_sys::ffi::GSSmoother_ctor(a.inner, t, it);
-        Self { inner }
+impl<'a> MinresSolver<'a> {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::MINRESSolver_ctor();
+        Self {
+            inner,
+            monitor: None,
+        }
     }
 }
 
-impl<'mat> Solver for GsSmoother<'mat> {}
+impl_iterative_solver!(
+    MinresSolver,
+    MINRESSolver_as_IterativeSolver,
+    MINRESSolver_as_mut_IterativeSolver,
+    MINRESSolver_as_mut_Solver
+);
+impl_krylov_solver!(MinresSolver);
 
-impl<'mat> AsBaseMut<mfem_sys::ffi::Solver> for GsSmoother<'mat> {
-    fn as_base_mut(&mut self) -> std::pin::Pin<&mut mfem_sys::ffi::Solver> {
-        mfem_sys::ffi::GSSmoother_as_mut_Solver(self.inner.pin_mut())
+//////////////////
+// NewtonSolver //
+//////////////////
+
+/// Newton's method for a nonlinear operator `F(x) = b`, e.g. a [`NonlinearForm`].
+///
+/// Each step solves a linear system with the gradient of `F`, using the linear solver,
+/// which in turn can have a preconditioner of its own.
+pub struct NewtonSolver<'a> {
+    inner: UniquePtr<mfem_sys::ffi::NewtonSolver<'a>>,
+    // NOTE: Declared after `inner` so that it's dropped after it, as it refers to it.
+    monitor: Option<UniquePtr<mfem_sys::ffi::RustIterativeSolverMonitor<'a>>>,
+}
+
+impl<'a> NewtonSolver<'a> {
+    pub fn new() -> Self {
+        let inner = mfem_sys::ffi::NewtonSolver_ctor();
+        Self {
+            inner,
+            monitor: None,
+        }
+    }
+
+    /// Solves for the Newton steps, its operator is set to the gradient at each step.
+    pub fn linear_solver<S>(&mut self, solver: &'a mut S) -> &mut Self
+    where
+        S: Solver,
+    {
+        mfem_sys::ffi::IterativeSolver_SetPreconditioner(
+            iterative_solver_mut(self),
+            solver.as_base_mut(),
+        );
+        self
     }
 }
 
+impl_iterative_solver!(
+    NewtonSolver,
+    NewtonSolver_as_IterativeSolver,
+    NewtonSolver_as_mut_IterativeSolver,
+    NewtonSolver_as_mut_Solver
+);
+
 /////////
 // PCG //
 /////////
 
+/// Solve `a_mat x_vec = b_vec` with preconditioned conjugate gradients.
+///
+/// Note that `rtolerance` and `atolerance` are the squares of the tolerances.
+///
+/// Replaced by [`CgSolver`], which reports whether it converged, and can be monitored.
+#[deprecated(note = "use `CgSolver` instead")]
 pub fn solve_with_pcg<Op, So>(
     a_mat: &Op,
     solver: &mut So,
@@ -658,7 +5604,8 @@ pub fn solve_with_pcg<Op, So>(
     max_num_iter: i32,
     rtolerance: f64,
     atolerance: f64,
-) where
+) -> Result<(), MfemError>
+where
     Op: Operator,
     So: Solver,
 {
@@ -671,7 +5618,8 @@ pub fn solve_with_pcg<Op, So>(
         max_num_iter,
         rtolerance,
         atolerance,
-    );
+    )?;
+    Ok(())
 }
 
 ///////////
@@ -682,4 +5630,398 @@ pub fn solve_with_pcg<Op, So>(
 pub enum MfemError {
     #[error("OperatorHandle type mismatch: expected {0:?} got {1:?}")]
     OperatorHandleTypeMismatch(OperatorType, OperatorType),
+    #[error("Invalid mesh: {0}")]
+    InvalidMesh(String),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("Size mismatch: {0}")]
+    SizeMismatch(String),
+    #[error(
+        "Not converged in {} iterations: residual norm {:e} (initially {:e})",
+        .0.iterations,
+        .0.final_norm,
+        .0.initial_norm,
+    )]
+    NotConverged(SolveReport),
+    /// An [`IterativeSolverMonitor`] stopped the solver.
+    #[error("Cancelled: {0}")]
+    Cancelled(String),
+    /// Any other error reported by MFEM, typically via `mfem_error()`.
+    #[error("MFEM error: {0}")]
+    Exception(String),
+}
+
+// The tags are added by `rust::behavior::trycatch()` in `wrapper.hpp`.
+impl From<cxx::Exception> for MfemError {
+    fn from(e: cxx::Exception) -> Self {
+        let msg = e.what();
+        if let Some(msg) = msg.strip_prefix("IoError: ") {
+            Self::Io(std::io::Error::other(msg.to_owned()))
+        } else if let Some(msg) = msg.strip_prefix("InvalidArgument: ") {
+            Self::InvalidArgument(msg.to_owned())
+        } else if let Some(msg) = msg.strip_prefix("SizeMismatch: ") {
+            Self::SizeMismatch(msg.to_owned())
+        } else if let Some(msg) = msg.strip_prefix("Cancelled: ") {
+            Self::Cancelled(msg.to_owned())
+        } else {
+            Self::Exception(msg.trim().to_owned())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mesh_builder_unit_square() {
+        let mesh = MeshBuilder::new(2, 2)
+            .add_vertex(&[0.0, 0.0])
+            .add_vertex(&[1.0, 0.0])
+            .add_vertex(&[1.0, 1.0])
+            .add_vertex(&[0.0, 1.0])
+            .add_element(ElementType::TRIANGLE, &[0, 1, 2], 1)
+            .add_element(ElementType::TRIANGLE, &[0, 2, 3], 2)
+            .build()
+            .unwrap();
+        assert_eq!(mesh.dimension(), 2);
+        assert_eq!(mesh.space_dimension(), 2);
+        assert_eq!(mesh.get_num_vertices(), 4);
+        assert_eq!(mesh.get_num_elems(), 2);
+        // Generated from the elements, as none were added.
+        assert_eq!(mesh.get_num_bdr_elems(), 4);
+        assert_eq!(mesh.get_attributes().as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn mesh_builder_boundary_elements() {
+        let mut builder = MeshBuilder::new(1, 1);
+        for x in [0.0, 0.5, 1.0] {
+            builder.add_vertex(&[x]);
+        }
+        let mesh = builder
+            .add_element(ElementType::SEGMENT, &[0, 1], 1)
+            .add_element(ElementType::SEGMENT, &[1, 2], 1)
+            .add_boundary_element(ElementType::POINT, &[0], 1)
+            .add_boundary_element(ElementType::POINT, &[2], 2)
+            .build()
+            .unwrap();
+        assert_eq!(mesh.get_num_vertices(), 3);
+        assert_eq!(mesh.get_num_elems(), 2);
+        assert_eq!(mesh.get_num_bdr_elems(), 2);
+        assert_eq!(mesh.get_bdr_attributes().as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn mesh_builder_invalid_connectivity() {
+        let mesh = MeshBuilder::new(2, 2)
+            .add_vertex(&[0.0, 0.0])
+            .add_vertex(&[1.0, 0.0])
+            .add_vertex(&[0.0, 1.0])
+            .add_element(ElementType::TRIANGLE, &[0, 1, 3], 1)
+            .build();
+        assert!(matches!(mesh, Err(MfemError::InvalidMesh(_))));
+
+        let mesh = MeshBuilder::new(2, 2)
+            .add_vertex(&[0.0, 0.0])
+            .add_vertex(&[1.0, 0.0])
+            .add_vertex(&[0.0, 1.0])
+            .add_element(ElementType::QUADRILATERAL, &[0, 1, 2], 1)
+            .build();
+        assert!(matches!(mesh, Err(MfemError::InvalidMesh(_))));
+    }
+
+    #[test]
+    fn vector_ops() {
+        let mut x = Vector::from_slice(&[1.0, 2.0, 3.0]);
+        let y = Vector::from_slice(&[4.0, 5.0, 6.0]);
+        assert_eq!(x.len(), 3);
+        x += &y;
+        assert_eq!(x.as_slice(), &[5.0, 7.0, 9.0]);
+        x -= &y;
+        assert_eq!(x.as_slice(), &[1.0, 2.0, 3.0]);
+        x *= 2.0;
+        assert_eq!(x.as_slice(), &[2.0, 4.0, 6.0]);
+        x[0] = -2.0;
+        assert_eq!(x[0], -2.0);
+        assert_eq!(x.dot(&y).unwrap(), 48.0);
+        assert_eq!(x.norm_linf(), 6.0);
+        assert_eq!(Vector::from_slice(&[3.0, 4.0]).norm_l2(), 5.0);
+    }
+
+    #[test]
+    fn vector_dot_size_mismatch() {
+        let x = Vector::with_len(2);
+        let y = Vector::with_len(3);
+        assert!(matches!(x.dot(&y), Err(MfemError::SizeMismatch(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "Size mismatch")]
+    fn vector_add_assign_size_mismatch() {
+        let mut x = Vector::with_len(2);
+        x += &Vector::with_len(3);
+    }
+
+    #[test]
+    fn grid_function_ops() {
+        let mesh =
+            Mesh::cartesian_2d(2, 2, ElementType::QUADRILATERAL, false, [1.0, 1.0], false).unwrap();
+        let fec = H1FeCollection::new(1, 2, BasisType::GaussLobatto).unwrap();
+        let fespace = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES).unwrap();
+        let mut x = GridFunction::new(&fespace).unwrap();
+        assert_eq!(x.len(), 9);
+        x.set_all(1.0);
+        x += &Vector::from_slice(&[1.0; 9]);
+        x *= 0.5;
+        assert!(x.as_slice().iter().all(|&v| v == 1.0));
+    }
+
+    // The element mass matrix, computed like MFEM's `MassIntegrator` does.
+    struct RustMassIntegrator;
+
+    impl CustomBilinearFormIntegrator for RustMassIntegrator {
+        fn assemble_element_matrix(
+            &self,
+            el: &FiniteElement,
+            trans: &mut ElementTransformation,
+            elmat: &mut DenseMatrixMut,
+        ) {
+            let order = 2 * el.order() + trans.order_w();
+            let mut shape = Vector::new();
+            for ip in IntegrationRule::for_element(el, order).unwrap().iter() {
+                trans.set_int_point(&ip);
+                el.calc_shape(&ip, &mut shape).unwrap();
+                elmat
+                    .add_mult_a_vvt(ip.weight() * trans.weight(), &shape)
+                    .unwrap();
+            }
+        }
+    }
+
+    struct PanickingIntegrator;
+
+    impl CustomBilinearFormIntegrator for PanickingIntegrator {
+        fn assemble_element_matrix(
+            &self,
+            _el: &FiniteElement,
+            _trans: &mut ElementTransformation,
+            _elmat: &mut DenseMatrixMut,
+        ) {
+            panic!("not assembled");
+        }
+    }
+
+    #[test]
+    fn rust_bilinear_integrator_matches_builtin() {
+        let mesh =
+            Mesh::cartesian_2d(3, 2, ElementType::QUADRILATERAL, false, [1.0, 2.0], false).unwrap();
+        let fec = H1FeCollection::new(2, 2, BasisType::GaussLobatto).unwrap();
+        let fespace = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES).unwrap();
+        let one = ConstantCoefficient::new(1.0);
+
+        let mut expected = BilinearForm::new(&fespace).unwrap();
+        expected
+            .add_domain_integrator(MassIntegrator::new(&one))
+            .unwrap();
+        expected.assemble(false).unwrap();
+        expected.finalize(false).unwrap();
+
+        let mut actual = BilinearForm::new(&fespace).unwrap();
+        actual
+            .add_domain_integrator(RustBilinearFormIntegrator::new(RustMassIntegrator))
+            .unwrap();
+        actual.assemble(false).unwrap();
+        actual.finalize(false).unwrap();
+
+        let (expected, actual) = (expected.sp_mat().unwrap(), actual.sp_mat().unwrap());
+        assert_eq!(
+            actual.row_offsets().unwrap(),
+            expected.row_offsets().unwrap()
+        );
+        assert_eq!(
+            actual.col_indices().unwrap(),
+            expected.col_indices().unwrap()
+        );
+        for (a, e) in actual
+            .values()
+            .unwrap()
+            .iter()
+            .zip(expected.values().unwrap())
+        {
+            assert!((a - e).abs() < 1e-12, "{a} != {e}");
+        }
+        // The mass matrix sums to the area of the domain.
+        let total: f64 = actual.values().unwrap().iter().sum();
+        assert!((total - 2.0).abs() < 1e-12, "{total} != 2");
+    }
+
+    #[test]
+    fn rust_bilinear_integrator_panic_is_error() {
+        let mesh = Mesh::cartesian_1d(2, 1.0).unwrap();
+        let fec = H1FeCollection::new(1, 1, BasisType::GaussLobatto).unwrap();
+        let fespace = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES).unwrap();
+        let mut a = BilinearForm::new(&fespace).unwrap();
+        a.add_domain_integrator(RustBilinearFormIntegrator::new(PanickingIntegrator))
+            .unwrap();
+        assert!(a.assemble(false).is_err());
+    }
+
+    // The load vector of `f = 1`, computed like MFEM's `DomainLFIntegrator` does.
+    struct RustDomainLFIntegrator;
+
+    impl CustomLinearFormIntegrator for RustDomainLFIntegrator {
+        fn assemble_rhs_element_vect(
+            &self,
+            el: &FiniteElement,
+            trans: &mut ElementTransformation,
+            elvect: &mut [f64],
+        ) {
+            let mut shape = Vector::new();
+            for ip in IntegrationRule::for_element(el, 2 * el.order())
+                .unwrap()
+                .iter()
+            {
+                trans.set_int_point(&ip);
+                el.calc_shape(&ip, &mut shape).unwrap();
+                let w = ip.weight() * trans.weight();
+                for (v, s) in elvect.iter_mut().zip(shape.as_slice()) {
+                    *v += w * s;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rust_linear_integrator_matches_builtin() {
+        let mesh =
+            Mesh::cartesian_2d(3, 2, ElementType::TRIANGLE, false, [1.0, 2.0], false).unwrap();
+        let fec = H1FeCollection::new(2, 2, BasisType::GaussLobatto).unwrap();
+        let fespace = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES).unwrap();
+        let one = ConstantCoefficient::new(1.0);
+
+        let mut expected = LinearForm::new(&fespace).unwrap();
+        expected
+            .add_domain_integrator(DomainLFIntegrator::new(&one, 2, 0))
+            .unwrap();
+        expected.assemble().unwrap();
+
+        let mut actual = LinearForm::new(&fespace).unwrap();
+        actual
+            .add_domain_integrator(RustLinearFormIntegrator::new(RustDomainLFIntegrator))
+            .unwrap();
+        actual.assemble().unwrap();
+
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.as_slice().iter().zip(expected.as_slice()) {
+            assert!((a - e).abs() < 1e-12, "{a} != {e}");
+        }
+        // The shape functions sum to 1, so the loads sum to the area of the domain.
+        let total: f64 = actual.as_slice().iter().sum();
+        assert!((total - 2.0).abs() < 1e-12, "{total} != 2");
+    }
+
+    #[test]
+    fn krylov_solvers_reach_known_solution() {
+        let mesh =
+            Mesh::cartesian_2d(4, 4, ElementType::QUADRILATERAL, false, [1.0, 1.0], false).unwrap();
+        let fec = H1FeCollection::new(1, 2, BasisType::GaussLobatto).unwrap();
+        let fespace = FiniteElementSpace::new(&mesh, &fec, 1, OrderingType::byNODES).unwrap();
+        let one = ConstantCoefficient::new(1.0);
+
+        // The mass term makes the matrix SPD without boundary conditions.
+        let mut a = BilinearForm::new(&fespace).unwrap();
+        a.add_domain_integrator(DiffusionIntegrator::new(&one))
+            .unwrap();
+        a.add_domain_integrator(MassIntegrator::new(&one)).unwrap();
+        a.assemble(true).unwrap();
+        a.finalize(true).unwrap();
+        let a_mat = a.sp_mat().unwrap();
+
+        let n = a_mat.height() as usize;
+        let values: Vec<f64> = (0..n).map(|i| (i as f64).sin()).collect();
+        let x_exact = Vector::from_slice(&values);
+        let mut b = Vector::with_len(n);
+        a_mat.mult(&x_exact, &mut b).unwrap();
+
+        let check = |name: &str, report: SolveReport, x: &Vector| {
+            assert!(report.converged, "{name}: {report:?}");
+            assert!(
+                report.final_norm < report.initial_norm,
+                "{name}: {report:?}"
+            );
+            let mut error = Vector::from_slice(x.as_slice());
+            error -= &x_exact;
+            assert!(
+                error.norm_linf() < 1e-8,
+                "{name}: error {}",
+                error.norm_linf()
+            );
+        };
+
+        let mut cg = CgSolver::new();
+        cg.rel_tol(1e-12).max_iter(100);
+        cg.set_operator(&a_mat).unwrap();
+        let mut x = Vector::with_len(n);
+        check("CG", cg.solve(&b, &mut x).unwrap(), &x);
+
+        let mut gmres = GmresSolver::new();
+        gmres.kdim(10).rel_tol(1e-12).max_iter(100);
+        gmres.set_operator(&a_mat).unwrap();
+        let mut x = Vector::with_len(n);
+        check("GMRES", gmres.solve(&b, &mut x).unwrap(), &x);
+
+        // A few CG iterations make a variable preconditioner, which FGMRES allows.
+        let mut inner = CgSolver::new();
+        inner.max_iter(3);
+        let mut fgmres = FgmresSolver::new();
+        fgmres
+            .preconditioner(&mut inner)
+            .rel_tol(1e-12)
+            .max_iter(100);
+        fgmres.set_operator(&a_mat).unwrap();
+        let mut x = Vector::with_len(n);
+        check("FGMRES", fgmres.solve(&b, &mut x).unwrap(), &x);
+
+        let mut bicgstab = BicgstabSolver::new();
+        bicgstab.rel_tol(1e-12).max_iter(100);
+        bicgstab.set_operator(&a_mat).unwrap();
+        let mut x = Vector::with_len(n);
+        check("BiCGSTAB", bicgstab.solve(&b, &mut x).unwrap(), &x);
+
+        let mut minres = MinresSolver::new();
+        minres.rel_tol(1e-12).max_iter(100);
+        minres.set_operator(&a_mat).unwrap();
+        let mut x = Vector::with_len(n);
+        check("MINRES", minres.solve(&b, &mut x).unwrap(), &x);
+    }
+
+    #[test]
+    fn krylov_solver_not_converged() {
+        let a_mat = RustOperator::new(2, 2, Diagonal([1.0, 100.0]));
+        let b = Vector::from_slice(&[1.0, 1.0]);
+        let mut cg = CgSolver::new();
+        cg.rel_tol(1e-12).max_iter(1);
+        cg.set_operator(&a_mat).unwrap();
+        let mut x = Vector::with_len(2);
+        match cg.solve(&b, &mut x) {
+            Err(MfemError::NotConverged(report)) => {
+                assert!(!report.converged);
+                assert!(report.final_norm > 1e-12 * report.initial_norm);
+            }
+            result => panic!("expected NotConverged, got {result:?}"),
+        }
+    }
+
+    struct Diagonal([f64; 2]);
+
+    impl CustomOperator for Diagonal {
+        fn mult(&self, x: &[f64], y: &mut [f64]) {
+            for ((y, x), d) in y.iter_mut().zip(x).zip(self.0) {
+                *y = d * x;
+            }
+        }
+    }
 }